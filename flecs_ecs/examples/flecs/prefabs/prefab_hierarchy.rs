@@ -19,8 +19,8 @@ fn main() {
     // Because of the IsA relationship, the instance now has the Engine and Cockpit
     // children of the prefab. This means that the instance can look up the Engine
     // and Cockpit entities.
-    if let Some(inst_engine) = inst.try_lookup_recursive("Engine") {
-        if let Some(inst_cockpit) = inst.try_lookup_recursive("Cockpit") {
+    if let Ok(inst_engine) = inst.try_lookup_recursive("Engine") {
+        if let Ok(inst_cockpit) = inst.try_lookup_recursive("Cockpit") {
             println!("instance engine:  {:?}", inst_engine.path().unwrap());
             println!("instance cockpit: {:?}", inst_cockpit.path().unwrap());
         } else {
@@ -45,7 +45,7 @@ fn main() {
     inst_car.is_a(car);
 
     // Lookup one of the wheels
-    if let Some(inst) = inst_car.try_lookup_recursive("FrontLeft") {
+    if let Ok(inst) = inst_car.try_lookup_recursive("FrontLeft") {
         // The type shows that the child has a private copy of the TirePressure
         // component, and an IsA relationship to the Wheel prefab.
         println!("{:?}", inst.archetype());
@@ -30,7 +30,7 @@ fn main() {
     world.component::<Second>();
     world.component::<Third>();
 
-    let query = world.query::<&Position>().group_by(Group).build();
+    let query = world.query::<&Position>().group_by_target(Group).build();
 
     world
         .entity()
@@ -1,9 +1,7 @@
 use crate::z_ignore_test_common::*;
 
-use core::ffi::c_void;
+use core::cell::Cell;
 use flecs_ecs::prelude::*;
-use flecs_ecs::sys;
-use std::sync::Mutex;
 
 #[derive(Debug, Component)]
 pub struct Position {
@@ -14,8 +12,6 @@ pub struct Position {
 #[derive(Component)]
 pub struct Tag;
 
-static GROUP_COUNTER: Mutex<i32> = Mutex::new(0);
-
 struct GroupCtx {
     counter: i32,
 }
@@ -32,46 +28,6 @@ pub struct Third;
 #[derive(Component)]
 pub struct Group;
 
-#[extern_abi]
-fn callback_group_create(
-    world: *mut sys::ecs_world_t,
-    group_id: u64,
-    _group_by_ctx: *mut c_void,
-) -> *mut c_void {
-    let world_ref = unsafe { WorldRef::from_ptr(world) };
-    println!(
-        "Group created: {:?}",
-        world_ref.world().entity_from_id(group_id).name()
-    );
-
-    println!();
-
-    let mut counter = GROUP_COUNTER.lock().unwrap();
-    *counter += 1;
-
-    // Return data that will be associated with the group
-    let ctx = Box::new(GroupCtx { counter: *counter });
-
-    Box::into_raw(ctx) as *mut core::ffi::c_void // Cast to make sure function type matches
-}
-
-#[extern_abi]
-fn callback_group_delete(
-    world: *mut sys::ecs_world_t,
-    group_id: u64,
-    _ctx: *mut c_void,
-    _group_by_ctx: *mut c_void,
-) {
-    let world_ref = unsafe { WorldRef::from_ptr(world) };
-    println!(
-        "Group deleted: {:?}",
-        world_ref.world().entity_from_id(group_id).name()
-    );
-
-    // if you have any data associated with the group, you need to free it
-    // or use the callback group_by_ctx where you pass a context to the callback
-}
-
 fn main() {
     let world = World::new();
 
@@ -80,14 +36,30 @@ fn main() {
     world.component::<Second>();
     world.component::<Third>();
 
+    let group_counter = Cell::new(0);
+
     // Grouped query
     let query = world
         .query::<(&Position,)>()
-        .group_by(Group)
+        .group_by_target(Group)
         // Callback invoked when a new group is created
-        .on_group_create(Some(callback_group_create))
+        .on_group_create(move |world, group_id| {
+            println!("Group created: {:?}", world.entity_from_id(group_id).name());
+
+            println!();
+
+            group_counter.set(group_counter.get() + 1);
+
+            // Return data that will be associated with the group. The query
+            // owns it, and drops it when the group is deleted.
+            GroupCtx {
+                counter: group_counter.get(),
+            }
+        })
         // Callback invoked when a group is deleted
-        .on_group_delete(Some(callback_group_delete))
+        .on_group_delete(|world, group_id| {
+            println!("Group deleted: {:?}", world.entity_from_id(group_id).name());
+        })
         .build();
 
     // Create entities in 6 different tables with 3 group ids
@@ -141,7 +113,7 @@ fn main() {
             let group = world.entity_from_id(it.group_id());
             let pos = it.field::<Position>(0);
 
-            let ctx = unsafe { &*(query.group_context(group) as *mut GroupCtx) };
+            let ctx = query.group_context::<GroupCtx>(group).unwrap();
             println!(
                 "Group: {:?} - Table: [{:?}] - Counter: {}",
                 group.path().unwrap(),
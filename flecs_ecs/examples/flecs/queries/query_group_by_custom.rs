@@ -1,8 +1,6 @@
 use crate::z_ignore_test_common::*;
 
-use core::ffi::c_void;
 use flecs_ecs::prelude::*;
-use flecs_ecs::sys;
 
 #[derive(Debug, Component)]
 pub struct Position {
@@ -25,22 +23,17 @@ pub struct Third;
 #[derive(Component)]
 pub struct Group;
 
-#[extern_abi]
-fn callback_group_by_relationship(
-    world: *mut sys::ecs_world_t,
-    table: *mut sys::ecs_table_t,
-    id: u64,
-    _group_by_ctx: *mut c_void,
-) -> u64 {
-    // Use sys::ecs_search to find the target for the relationship in the table
-    let mut match_id: sys::ecs_id_t = Default::default();
-    let world = unsafe { WorldRef::from_ptr(world) };
-    let id = IdView::new_from_id(world, (id, flecs::Wildcard::ID)).id();
-    if unsafe { sys::ecs_search(world.world_ptr_mut(), table, *id, &mut match_id) } != -1 {
-        *IdView::new_from_id(world, match_id).second_id().id() // First, Second or Third
-    } else {
-        0
-    }
+fn callback_group_by_relationship(_world: &World, table: Table, id: Id) -> u64 {
+    // Find the (Group, *) pair in the table and use its target as group id
+    let Some(index) = table.find_type_index((*id, flecs::Wildcard::ID)) else {
+        return 0;
+    };
+
+    table
+        .archetype()
+        .get(index as usize)
+        .map(|pair| *pair.second_id().id()) // First, Second or Third
+        .unwrap_or(0)
 }
 
 fn main() {
@@ -54,7 +47,7 @@ fn main() {
     // Grouped query
     let query = world
         .query::<&Position>()
-        .group_by_fn(Group, Some(callback_group_by_relationship))
+        .group_by(Group, callback_group_by_relationship)
         .build();
 
     // Create entities in 6 different tables with 3 group ids
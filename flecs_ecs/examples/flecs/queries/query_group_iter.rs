@@ -91,7 +91,11 @@ fn main() {
         .add(Soldier)
         .add(Npc);
 
-    let mut query = world.query::<()>().with(&Npc).group_by(WorldCell).build();
+    let mut query = world
+        .query::<()>()
+        .with(&Npc)
+        .group_by_target(WorldCell)
+        .build();
 
     // Iterate all tables
     println!("All tables");
@@ -47,12 +47,15 @@ fn main() {
 
     // Group entities by their IsA target. The default group_by callback uses
     // the relationship's target as the group ID.
-    let query = world.query::<&Position>().group_by(flecs::IsA).build();
+    let query = world
+        .query::<&Position>()
+        .group_by_target(flecs::IsA)
+        .build();
 
     // Iterate all active groups, then iterate entities for each group with
     // set_group(). The group ID here is the asset entity (the target of IsA).
     let mut groups: Vec<Entity> = Vec::new();
-    query.each_group(|group_id, _group_ctx| {
+    query.each_group(|group_id, _group_ctx: Option<&()>| {
         groups.push(group_id);
     });
 
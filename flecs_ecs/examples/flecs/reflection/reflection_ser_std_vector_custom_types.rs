@@ -51,7 +51,7 @@ fn main() {
 
     // Output:
     //  "{"points":[{"x":1, "y":2}, {"x":3, "y":4}], "strings":["foo", "bar"]}"
-    //  "{"points":[{"x":4, "y":5}, {"x":6, "y":7}, {"x":0, "y":0}], "strings":["hello", "flecs", "reflection", ""]}"
+    //  "{"points":[{"x":4, "y":5}, {"x":6, "y":7}], "strings":["hello", "flecs", "reflection"]}"
 }
 
 #[cfg(feature = "flecs_nightly_tests")]
@@ -1,6 +1,6 @@
 use crate::z_ignore_test_common::*;
 
-use core::{borrow::Borrow, ffi::c_void};
+use core::borrow::Borrow;
 use flecs_ecs::prelude::*;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -45,16 +45,18 @@ fn main() {
     // following example shows how to pass a custom query into a system for a simple
     // collision detection example.
 
-    let mut query_collide = world.new_query::<(&Position, &Radius)>();
+    // The context is owned by the system and dropped together with it. Contexts can be read
+    // from worker threads, so the query is passed as a thread-shareable handle.
+    let query_collide = world.new_query::<(&Position, &Radius)>().handle();
 
     let sys = world
         .system::<(&Position, &Radius)>()
-        .set_context(&mut query_collide as *mut Query<(&Position, &Radius)> as *mut c_void)
-        .each_iter(|mut it, index, (p1, r1)| {
-            let query = unsafe { it.context::<Query<(&Position, &Radius)>>() };
+        .set_context(query_collide)
+        .each_iter(|it, index, (p1, r1)| {
+            let query = it.context::<QueryHandle<(&Position, &Radius)>>().unwrap();
             let e1 = it.entity(index);
 
-            query.each_entity(|e2, (p2, r2)| {
+            query.iter_stage(it.world()).each_entity(|e2, (p2, r2)| {
                 if e1 == *e2 {
                     // don't collide with self
                     return;
@@ -426,19 +426,26 @@ impl<'a, T> AlertBuilder<'a, T>
 where
     T: QueryTuple,
 {
-    /// Attempts to build the alert, returning `None` if alert creation fails.
+    /// Attempts to build the alert, returning an error if alert creation fails.
     ///
     /// This is the fallible counterpart of [`build()`](Builder::build): it returns
-    /// `None` instead of a handle to an invalid entity when the underlying
-    /// `ecs_alert_init` call fails, for example due to an invalid query
-    /// expression passed to `expr()` or a filter without a `$this` term.
+    /// [`FlecsError::Build`] with the error reported by flecs instead of a handle to an
+    /// invalid entity when the underlying `ecs_alert_init` call fails, for example due
+    /// to an invalid query expression passed to `expr()` or a filter without a `$this` term.
     ///
     /// # See also
     ///
     /// * [`QueryBuilder::try_build()`]
-    pub fn try_build(&mut self) -> Option<Alert<'a>> {
-        let alert = self.build();
-        if *alert.id() == 0 { None } else { Some(alert) }
+    pub fn try_build(&mut self) -> Result<Alert<'a>, FlecsError> {
+        let (alert, message) = capture_errors(|| self.build());
+        if *alert.id() == 0 {
+            Err(FlecsError::Build {
+                kind: "alert",
+                message,
+            })
+        } else {
+            Ok(alert)
+        }
     }
 }
 
@@ -3,6 +3,7 @@
 use core::ops::Deref;
 use core::ops::DerefMut;
 
+use super::AlertInstanceView;
 use crate::core::*;
 use crate::sys;
 
@@ -61,4 +62,18 @@ impl<'a> Alert<'a> {
             entity: alert_entity,
         }
     }
+
+    /// Iterate the instances raised by this alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The callback invoked for each alert instance.
+    pub fn each_instance(&self, mut func: impl FnMut(AlertInstanceView<'a>)) {
+        self.entity.each_child(|child| {
+            let child = EntityView::new_from(self.entity.world(), *child.id());
+            if let Some(instance) = AlertInstanceView::new(child) {
+                func(instance);
+            }
+        });
+    }
 }
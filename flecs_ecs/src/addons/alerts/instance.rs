@@ -0,0 +1,177 @@
+//! Read-only views over alert instances.
+
+use core::ffi::CStr;
+
+use super::*;
+use crate::prelude::*;
+use crate::sys;
+
+/// Severity of an alert instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AlertSeverity {
+    /// Raised with the [`Info`] severity.
+    Info,
+    /// Raised with the [`Warning`] severity.
+    Warning,
+    /// Raised with the [`Error`] severity.
+    Error,
+    /// Raised with the [`Critical`] severity.
+    Critical,
+}
+
+impl AlertSeverity {
+    fn from_entity(severity: u64) -> Option<Self> {
+        if severity == Info {
+            Some(Self::Info)
+        } else if severity == Warning {
+            Some(Self::Warning)
+        } else if severity == Error {
+            Some(Self::Error)
+        } else if severity == Critical {
+            Some(Self::Critical)
+        } else {
+            None
+        }
+    }
+}
+
+/// Transition reported to [`World::on_alert`] callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertTransition {
+    /// The alert condition started matching the source entity.
+    Raised,
+    /// The alert instance was removed, either because the condition no longer
+    /// matches (and the retain period expired) or because the source or alert
+    /// was deleted.
+    Cleared,
+}
+
+/// A view of an active alert instance.
+///
+/// Alert instances are created by the alerts module as children of the alert
+/// that raised them. This view exposes their state without having to read the
+/// underlying C components directly.
+#[derive(Clone, Copy)]
+pub struct AlertInstanceView<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'a> AlertInstanceView<'a> {
+    /// Wrap an alert instance entity.
+    ///
+    /// Returns `None` if the entity is not an alert instance.
+    pub fn new(entity: EntityView<'a>) -> Option<Self> {
+        if entity.has(AlertInstance) {
+            Some(Self { entity })
+        } else {
+            None
+        }
+    }
+
+    /// The alert instance entity.
+    pub fn entity(&self) -> EntityView<'a> {
+        self.entity
+    }
+
+    /// The alert that raised this instance.
+    pub fn alert(&self) -> Alert<'a> {
+        let parent = self
+            .entity
+            .parent()
+            .expect("alert instances are always a child of their alert");
+        Alert::new_from_existing(parent)
+    }
+
+    /// The entity the alert was raised for.
+    ///
+    /// During a [`AlertTransition::Cleared`] callback the source may no longer
+    /// be alive.
+    pub fn source(&self) -> EntityView<'a> {
+        let world = self.entity.world();
+        // SAFETY: the metrics module is imported by the alerts module, so the
+        // component id is initialized.
+        let id = unsafe { sys::FLECS_IDEcsMetricSourceID_ };
+        let ptr = unsafe { sys::ecs_get_id(world.world_ptr(), *self.entity.id(), id) }
+            as *const sys::EcsMetricSource;
+        let source = if ptr.is_null() {
+            0
+        } else {
+            // SAFETY: the pointer points to a live `EcsMetricSource` owned by the instance.
+            unsafe { (*ptr).entity }
+        };
+        EntityView::new_from(world, source)
+    }
+
+    /// The current severity of the alert instance.
+    ///
+    /// The severity can change over the lifetime of an instance when the alert
+    /// uses severity filters or member ranges.
+    pub fn severity(&self) -> AlertSeverity {
+        self.entity
+            .target(AlertComponent, 0)
+            .and_then(|severity| AlertSeverity::from_entity(*severity.id()))
+            .unwrap_or(AlertSeverity::Error)
+    }
+
+    /// The alert message, with template variables resolved.
+    ///
+    /// Returns `None` if the alert has no message, or if the message has not
+    /// been generated yet. Messages are generated the first time the alerts
+    /// module checks the instance, which happens after it is raised.
+    pub fn message(&self) -> Option<String> {
+        let world = self.entity.world();
+        let ptr = unsafe { sys::ecs_get_id(world.world_ptr(), *self.entity.id(), *AlertInstance) }
+            as *const sys::EcsAlertInstance;
+        if ptr.is_null() {
+            return None;
+        }
+        // SAFETY: the pointer points to a live `EcsAlertInstance` owned by the
+        // instance, whose message is either null or a nul-terminated string.
+        let message = unsafe { (*ptr).message };
+        if message.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(message) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// How long the alert condition has been active, in seconds.
+    pub fn duration(&self) -> f64 {
+        let world = self.entity.world();
+        // SAFETY: the metrics module is imported by the alerts module, so the
+        // component id is initialized.
+        let id = unsafe { sys::FLECS_IDEcsMetricValueID_ };
+        let ptr = unsafe { sys::ecs_get_id(world.world_ptr(), *self.entity.id(), id) }
+            as *const sys::EcsMetricValue;
+        if ptr.is_null() {
+            0.0
+        } else {
+            // SAFETY: the pointer points to a live `EcsMetricValue` owned by the instance.
+            unsafe { (*ptr).value }
+        }
+    }
+}
+
+impl core::fmt::Debug for AlertInstanceView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AlertInstanceView")
+            .field("entity", &self.entity.id())
+            .field("alert", &self.alert().id())
+            .field("source", &self.source().id())
+            .field("severity", &self.severity())
+            .field("message", &self.message())
+            .field("duration", &self.duration())
+            .finish()
+    }
+}
+
+impl<'a> WorldProvider<'a> for AlertInstanceView<'a> {
+    #[inline(always)]
+    fn world(&self) -> WorldRef<'a> {
+        self.entity.world()
+    }
+}
@@ -35,5 +35,7 @@ mod types;
 pub use types::*;
 mod alerts;
 pub use alerts::*;
+mod instance;
+pub use instance::*;
 mod entity_view;
 mod world;
@@ -72,4 +72,82 @@ impl World {
     {
         AlertBuilder::<Components>::new_from_desc(self, desc)
     }
+
+    /// Iterate all alert instances in the world.
+    ///
+    /// This includes instances that are no longer matching but are kept alive
+    /// by the retain period of their alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The callback invoked for each alert instance.
+    ///
+    /// # See also
+    ///
+    /// * [`World::alert_instances()`]
+    /// * [`Alert::each_instance()`]
+    pub fn each_alert_instance<'a>(&'a self, mut func: impl FnMut(AlertInstanceView<'a>)) {
+        // uncached, so that listing instances doesn't leave a cached query behind
+        self.query::<()>()
+            .with(AlertInstance)
+            .set_cache_kind(QueryCacheKind::None)
+            .build()
+            .each_entity(|e, _| {
+                if let Some(instance) = AlertInstanceView::new(EntityView::new_from(self, *e.id()))
+                {
+                    func(instance);
+                }
+            });
+    }
+
+    /// Collect all alert instances in the world.
+    ///
+    /// # See also
+    ///
+    /// * [`World::each_alert_instance()`]
+    pub fn alert_instances(&self) -> Vec<AlertInstanceView<'_>> {
+        let mut instances = Vec::new();
+        self.each_alert_instance(|instance| instances.push(instance));
+        instances
+    }
+
+    /// Register a callback that is invoked when an alert instance is raised or cleared.
+    ///
+    /// The callback is invoked with [`AlertTransition::Raised`] when the alerts
+    /// module creates a new instance, and with [`AlertTransition::Cleared`] when
+    /// the instance is removed. Note that the message of a newly raised instance
+    /// is generated by the next alerts update, so it may still be `None` when
+    /// the callback runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The callback invoked for each transition.
+    ///
+    /// # Returns
+    ///
+    /// The observer that invokes the callback.
+    pub fn on_alert(
+        &self,
+        mut func: impl FnMut(AlertInstanceView, AlertTransition) + 'static,
+    ) -> Observer<'_> {
+        // SAFETY: the metrics module is imported by the alerts module, so the
+        // component id is initialized.
+        let metric_source = unsafe { sys::FLECS_IDEcsMetricSourceID_ };
+        let mut builder = self.observer_id::<()>(flecs::OnSet::ID);
+        builder.add_event(flecs::OnRemove::ID);
+        builder
+            .with(metric_source)
+            .with(AlertInstance)
+            .filter()
+            .each_iter(move |it, row, _| {
+                let transition = if it.event() == flecs::OnRemove::ID {
+                    AlertTransition::Cleared
+                } else {
+                    AlertTransition::Raised
+                };
+                if let Some(instance) = AlertInstanceView::new(it.entity(row)) {
+                    func(instance, transition);
+                }
+            })
+    }
 }
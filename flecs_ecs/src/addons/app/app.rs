@@ -36,8 +36,9 @@ extern "C-unwind" fn frame_action_trampoline(
     let actions = unsafe { ((*desc).ctx as *mut AppActions).as_mut() };
     let Some(action) = actions.and_then(|actions| actions.frame.as_mut()) else {
         // This app has no Rust frame action (e.g. another app run in the same
-        // process): replicate flecs_default_frame_action.
-        return unsafe { !sys::ecs_progress(world, (*desc).delta_time) as c_int };
+        // process): replicate flecs_default_frame_action, through
+        // World::progress_time so that fixed updates run.
+        return unsafe { !WorldRef::from_ptr(world).progress_time((*desc).delta_time) as c_int };
     };
 
     // SAFETY: see above.
@@ -370,6 +371,22 @@ impl App {
     ///
     /// The exit code of the application.
     pub fn run(&mut self) -> i32 {
+        // The default frame action calls ecs_progress, which doesn't run fixed
+        // updates. Route frames through the trampoline, which falls back to
+        // World::progress_time. Not possible if the ctx is used by the app.
+        #[cfg(feature = "flecs_pipeline")]
+        if (self.desc.ctx.is_null() || self.actions.is_some())
+            && self
+                .world
+                .as_ref()
+                .is_some_and(crate::addons::pipeline::fixed_update_enabled)
+        {
+            self.actions_mut();
+            // fails if a frame action was installed through the C API, which
+            // then drives the frames
+            unsafe { sys::ecs_app_set_frame_action(Some(frame_action_trampoline)) };
+        }
+
         let world = self
             .world
             .take()
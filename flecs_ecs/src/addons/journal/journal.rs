@@ -0,0 +1,190 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+
+use crate::prelude::*;
+
+/// Kind of operation stored in a [`JournalEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum JournalOp {
+    /// The entity was seen for the first time.
+    New,
+    /// An id was added to the entity.
+    Add,
+    /// An id was removed from the entity.
+    Remove,
+    /// A component value was assigned. The entry stores the value as JSON.
+    Set,
+    /// All components were removed from the entity.
+    Clear,
+    /// The entity was deleted.
+    Delete,
+}
+
+/// Reference to an entity from a [`JournalId`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum JournalRef {
+    /// An entity recorded by the journal, identified by its id in the recorded world.
+    Entity(u64),
+    /// A named entity, such as a component or tag, identified by its path.
+    Path(String),
+}
+
+/// A component, tag or pair id stored in a [`JournalEntry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct JournalId {
+    /// The component, tag or relationship.
+    pub first: JournalRef,
+    /// The target, if the id is a pair.
+    pub second: Option<JournalRef>,
+}
+
+/// A single operation recorded by a [`JournalRecorder`](super::JournalRecorder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct JournalEntry {
+    /// The operation.
+    pub op: JournalOp,
+    /// The entity the operation was performed on, as its id in the recorded world.
+    pub entity: u64,
+    /// The id that was added, removed or set.
+    pub id: Option<JournalId>,
+    /// The component value as JSON, for [`JournalOp::Set`] entries.
+    ///
+    /// For entity names this is the name itself.
+    pub value: Option<String>,
+}
+
+/// A recorded list of world operations.
+///
+/// Created by [`JournalRecorder::finish`](super::JournalRecorder::finish), or from a list of
+/// entries with [`Journal::new`]. With the `serde` feature, a journal serializes as the
+/// sequence of its entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Create a journal from a list of entries.
+    pub fn new(entries: Vec<JournalEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// The recorded entries, in the order they were performed.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Consume the journal, returning its entries.
+    pub fn into_entries(self) -> Vec<JournalEntry> {
+        self.entries
+    }
+
+    /// Replay the journal into a world.
+    ///
+    /// Recorded entities are created in `world` the first time they are used, and components
+    /// and tags are resolved by path, so they must be registered with the same names as in the
+    /// recorded world.
+    ///
+    /// # Errors
+    ///
+    /// - [`FlecsError::EntityNotFound`] if a component or tag could not be found by path.
+    /// - [`FlecsError::Json`] if a component value could not be deserialized.
+    pub fn replay(&self, world: &World) -> Result<(), FlecsError> {
+        let mut entities: HashMap<u64, Entity> = HashMap::new();
+
+        for entry in &self.entries {
+            let entity = map_entity(world, &mut entities, entry.entity);
+            let id = entry
+                .id
+                .as_ref()
+                .map(|id| resolve_id(world, &mut entities, id))
+                .transpose()?;
+
+            match entry.op {
+                JournalOp::New => {}
+                JournalOp::Add => {
+                    if let Some(id) = id {
+                        entity.add(id);
+                    }
+                }
+                JournalOp::Remove => {
+                    if let Some(id) = id {
+                        entity.remove(id);
+                    }
+                }
+                JournalOp::Set => {
+                    let (Some(id), Some(value)) = (id, entry.value.as_deref()) else {
+                        continue;
+                    };
+                    if id == ecs_pair(ECS_IDENTIFIER, ECS_NAME) {
+                        entity.set_name(value);
+                    } else {
+                        entity.try_set_json(id, value, None)?;
+                    }
+                }
+                JournalOp::Clear => entity.clear(),
+                JournalOp::Delete => {
+                    if entity.is_alive() {
+                        entity.destruct();
+                    }
+                    entities.remove(&entry.entity);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn map_entity<'a>(
+    world: &'a World,
+    entities: &mut HashMap<u64, Entity>,
+    recorded: u64,
+) -> EntityView<'a> {
+    let entity = *entities
+        .entry(recorded)
+        .or_insert_with(|| world.entity().id());
+    world.entity_from_id(entity)
+}
+
+fn resolve_ref(
+    world: &World,
+    entities: &mut HashMap<u64, Entity>,
+    entity: &JournalRef,
+) -> Result<u64, FlecsError> {
+    match entity {
+        JournalRef::Entity(recorded) => Ok(*map_entity(world, entities, *recorded).id()),
+        JournalRef::Path(path) => world.try_lookup(path).map(|e| *e.id()),
+    }
+}
+
+fn resolve_id(
+    world: &World,
+    entities: &mut HashMap<u64, Entity>,
+    id: &JournalId,
+) -> Result<u64, FlecsError> {
+    let first = resolve_ref(world, entities, &id.first)?;
+    match &id.second {
+        Some(second) => Ok(ecs_pair(first, resolve_ref(world, entities, second)?)),
+        None => Ok(first),
+    }
+}
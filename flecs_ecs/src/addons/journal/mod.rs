@@ -0,0 +1,62 @@
+//! The journal records entity and component operations performed on a world,
+//! so they can be serialized and replayed into another world.
+//!
+//! A [`JournalRecorder`] observes the world while it is alive and produces a
+//! [`Journal`], a world-independent list of [`JournalEntry`] values. Component
+//! values are stored as JSON through the reflection data of the component, so
+//! only components with reflection (for example `#[flecs(meta)]` components)
+//! record their value when set.
+//!
+//! Journals are useful to reproduce bug reports deterministically, and to turn
+//! recorded sessions into regression tests. With the `serde` feature, journals
+//! implement `Serialize` and `Deserialize`, so they can be stored in any serde
+//! format.
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//!
+//! #[derive(Component, Default)]
+//! #[flecs(meta)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! let world = World::new();
+//! world.component::<Position>();
+//!
+//! let recorder = world.record_journal();
+//! world.entity_named("player").set(Position { x: 1.0, y: 2.0 });
+//! let journal = recorder.finish();
+//!
+//! let replay_world = World::new();
+//! replay_world.component::<Position>();
+//!
+//! journal.replay(&replay_world).unwrap();
+//!
+//! let player = replay_world.lookup("player");
+//! player.get::<&Position>(|pos| assert_eq!(pos.y, 2.0));
+//! ```
+//!
+//! # Limitations
+//!
+//! - Entities are only recorded once they have a component, so entities that are created and
+//!   deleted without ever having a component do not appear in the journal.
+//! - Component, module, system, observer and query entities are not recorded.
+//! - Replayed components are constructed with their `Default` implementation before their
+//!   recorded value is assigned, so components must implement `Default`.
+//! - Entities referenced from component values are serialized as paths, and are resolved by
+//!   path when the journal is replayed.
+//!
+//! # See also
+//!
+//! - [`World::record_journal`](crate::core::World::record_journal)
+//! - [`Journal::replay`]
+
+mod journal;
+pub use journal::*;
+mod recorder;
+pub use recorder::*;
+mod world;
@@ -0,0 +1,260 @@
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use hashbrown::HashSet;
+
+use super::*;
+use crate::core::*;
+use crate::sys;
+
+/// Records the entity and component operations performed on a world.
+///
+/// Created with [`World::record_journal`]. Recording stops when the recorder is finished or
+/// dropped.
+pub struct JournalRecorder<'a> {
+    world: WorldRef<'a>,
+    state: Rc<RefCell<RecorderState>>,
+    observers: [Observer<'a>; 2],
+}
+
+#[derive(Default)]
+struct RecorderState {
+    entries: Vec<JournalEntry>,
+    /// Entities that have been recorded as the subject of an operation.
+    known: HashSet<u64>,
+    /// Entities that are not recorded, such as components, systems and observers.
+    excluded: HashSet<u64>,
+    /// An entity that had all of its components removed, with the index of the first removal.
+    emptied: Option<(u64, usize)>,
+}
+
+impl<'a> JournalRecorder<'a> {
+    pub(crate) fn new(world: &'a World) -> Self {
+        let state = Rc::new(RefCell::new(RecorderState::default()));
+        let observers = [
+            Self::observe(world, ECS_WILDCARD, &state),
+            Self::observe(world, ecs_pair(ECS_WILDCARD, ECS_WILDCARD), &state),
+        ];
+        Self {
+            world: world.world(),
+            state,
+            observers,
+        }
+    }
+
+    fn observe(world: &'a World, id: u64, state: &Rc<RefCell<RecorderState>>) -> Observer<'a> {
+        let state = state.clone();
+        let mut builder = world.observer_id::<()>(flecs::OnAdd::ID);
+        builder.add_event(flecs::OnRemove::ID);
+        builder.add_event(flecs::OnSet::ID);
+        builder.with(id).each_iter(move |it, row, _| {
+            let op = if it.event() == flecs::OnAdd::ID {
+                JournalOp::Add
+            } else if it.event() == flecs::OnRemove::ID {
+                JournalOp::Remove
+            } else {
+                JournalOp::Set
+            };
+            let emptied = op == JournalOp::Remove
+                && it
+                    .other_table()
+                    .is_none_or(|table| table.archetype().count() == 0);
+            state
+                .borrow_mut()
+                .record(it.world(), it.entity(row), *it.event_id().id(), op, emptied);
+        })
+    }
+
+    /// Stop recording and return the recorded journal.
+    pub fn finish(mut self) -> Journal {
+        self.flush();
+        let entries = core::mem::take(&mut self.state.borrow_mut().entries);
+        Journal::new(entries)
+    }
+
+    /// The entries recorded so far.
+    pub fn entries(&mut self) -> Vec<JournalEntry> {
+        self.flush();
+        self.state.borrow().entries.clone()
+    }
+
+    fn flush(&mut self) {
+        self.state.borrow_mut().resolve_emptied(self.world);
+    }
+}
+
+impl Drop for JournalRecorder<'_> {
+    fn drop(&mut self) {
+        for observer in self.observers {
+            if observer.is_alive() {
+                observer.destruct();
+            }
+        }
+    }
+}
+
+impl RecorderState {
+    fn record(
+        &mut self,
+        world: WorldRef,
+        entity: EntityView,
+        id: u64,
+        op: JournalOp,
+        emptied: bool,
+    ) {
+        let e = *entity.id();
+        if self.excluded.contains(&e) {
+            return;
+        }
+
+        if op == JournalOp::Add && is_internal(world, id) {
+            self.excluded.insert(e);
+            self.known.remove(&e);
+            self.entries.retain(|entry| entry.entity != e);
+            if self.emptied.is_some_and(|(emptied, _)| emptied == e) {
+                self.emptied = None;
+            }
+            return;
+        }
+
+        if self
+            .emptied
+            .is_some_and(|(emptied, _)| emptied != e || op != JournalOp::Remove)
+        {
+            self.resolve_emptied(world);
+        }
+
+        // names are recorded as a set of the name, other identifiers are derived from it
+        if ecs_is_pair(id)
+            && ecs_first(id, world) == ECS_IDENTIFIER
+            && (op != JournalOp::Set || ecs_second(id, world) != ECS_NAME)
+        {
+            return;
+        }
+
+        let value = if op == JournalOp::Set {
+            match set_value(world, e, id) {
+                Some(value) => Some(value),
+                None => return,
+            }
+        } else {
+            None
+        };
+
+        if self.known.insert(e) {
+            self.entries.push(JournalEntry {
+                op: JournalOp::New,
+                entity: e,
+                id: None,
+                value: None,
+            });
+        }
+
+        if emptied && self.emptied.is_none() {
+            self.emptied = Some((e, self.entries.len()));
+        }
+
+        let id = self.journal_id(world, id);
+        self.entries.push(JournalEntry {
+            op,
+            entity: e,
+            id: Some(id),
+            value,
+        });
+    }
+
+    /// Turn the removals of an emptied entity into a delete or clear entry.
+    fn resolve_emptied(&mut self, world: WorldRef) {
+        let Some((e, start)) = self.emptied.take() else {
+            return;
+        };
+
+        if !world.is_alive(e) {
+            self.entries.truncate(start);
+            self.known.remove(&e);
+            self.entries.push(JournalEntry {
+                op: JournalOp::Delete,
+                entity: e,
+                id: None,
+                value: None,
+            });
+        } else if self.entries.len() - start > 1 {
+            self.entries.truncate(start);
+            self.entries.push(JournalEntry {
+                op: JournalOp::Clear,
+                entity: e,
+                id: None,
+                value: None,
+            });
+        }
+    }
+
+    fn journal_id(&self, world: WorldRef, id: u64) -> JournalId {
+        if ecs_is_pair(id) {
+            JournalId {
+                first: self.journal_ref(world, alive(world, *ecs_first(id, world))),
+                second: Some(self.journal_ref(world, alive(world, *ecs_second(id, world)))),
+            }
+        } else {
+            JournalId {
+                first: self.journal_ref(world, id),
+                second: None,
+            }
+        }
+    }
+
+    fn journal_ref(&self, world: WorldRef, entity: u64) -> JournalRef {
+        let entity_view = EntityView::new_from(world, entity);
+        if self.known.contains(&entity) || entity_view.get_name().is_none() {
+            return JournalRef::Entity(entity);
+        }
+        match entity_view.path() {
+            Some(path) => JournalRef::Path(path),
+            None => JournalRef::Entity(entity),
+        }
+    }
+}
+
+/// Returns the current generation of `entity`, or `entity` itself if it is not alive.
+fn alive(world: WorldRef, entity: u64) -> u64 {
+    match unsafe { sys::ecs_get_alive(world.world_ptr(), entity) } {
+        0 => entity,
+        alive => alive,
+    }
+}
+
+/// Ids that mark an entity as part of the world's infrastructure rather than its data.
+fn is_internal(world: WorldRef, id: u64) -> bool {
+    if ecs_is_pair(id) {
+        let first = ecs_first(id, world);
+        first == ECS_POLY || first == ECS_CONSTANT
+    } else {
+        id == ECS_COMPONENT
+            || id == ECS_MODULE
+            || id == ECS_SYSTEM
+            || id == ECS_OBSERVER
+            || id == ECS_QUERY
+    }
+}
+
+/// Serialize the value of `id` on `entity`, if it has reflection data.
+fn set_value(world: WorldRef, entity: u64, id: u64) -> Option<String> {
+    if id == ecs_pair(ECS_IDENTIFIER, ECS_NAME) {
+        return EntityView::new_from(world, entity).get_name();
+    }
+
+    let world_ptr = world.world_ptr();
+    unsafe {
+        let type_id = sys::ecs_get_typeid(world_ptr, id);
+        if type_id == 0 || !sys::ecs_has_id(world_ptr, type_id, ECS_META_TYPE_SERIALIZER) {
+            return None;
+        }
+        let ptr = sys::ecs_get_id(world_ptr, entity, id);
+        if ptr.is_null() {
+            return None;
+        }
+        world.to_json_id(type_id, ptr)
+    }
+}
@@ -0,0 +1,16 @@
+use super::*;
+use crate::core::*;
+
+impl World {
+    /// Start recording the entity and component operations performed on this world.
+    ///
+    /// Recording stops when the returned recorder is finished or dropped.
+    ///
+    /// # See also
+    ///
+    /// * [`JournalRecorder::finish()`]
+    /// * [`Journal::replay()`]
+    pub fn record_journal(&self) -> JournalRecorder<'_> {
+        JournalRecorder::new(self)
+    }
+}
@@ -8,6 +8,66 @@ impl EntityView<'_> {
     /// Set component or pair id from JSON.
     pub fn set_json(self, comp: impl IntoId, json: &str, desc: Option<&FromJsonDesc>) -> Self {
         let comp: u64 = *comp.into_id(self.world);
+        let _ = self.set_json_impl(comp, json, desc);
+        self
+    }
+
+    /// Set component or pair id from JSON, returning the parse error on failure.
+    ///
+    /// This is the fallible counterpart of [`set_json`](Self::set_json). The component is
+    /// added to the entity even if the JSON could not be parsed.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Json`] if the id is not a type, or the JSON could not be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity();
+    ///
+    /// assert!(e.try_set_json(Position::id(), r#"{"x": 1, "y": 2}"#, None).is_ok());
+    ///
+    /// let err = e.try_set_json(Position::id(), r#"{"x": "abc"}"#, None).unwrap_err();
+    /// assert!(matches!(err, FlecsError::Json { component: Some(_), .. }));
+    /// ```
+    pub fn try_set_json(
+        self,
+        comp: impl IntoId,
+        json: &str,
+        desc: Option<&FromJsonDesc>,
+    ) -> Result<Self, FlecsError> {
+        let comp: u64 = *comp.into_id(self.world);
+        let (result, message) = capture_errors(|| self.set_json_impl(comp, json, desc));
+        result.map(|()| self).map_err(|mut err| {
+            if let FlecsError::Json {
+                message: reason, ..
+            } = &mut err
+                && reason.is_empty()
+            {
+                *reason = message;
+            }
+            err
+        })
+    }
+
+    /// The message of a parse error is left empty, flecs logs it instead.
+    fn set_json_impl(
+        self,
+        comp: u64,
+        json: &str,
+        desc: Option<&FromJsonDesc>,
+    ) -> Result<(), FlecsError> {
         let world = self.world_ptr_mut();
         let id = *self.id;
         unsafe {
@@ -15,7 +75,10 @@ impl EntityView<'_> {
             if ti.is_null() {
                 //sys::ecs_err(b"id is not a type\0".as_ptr() as *const _);
                 //TODO implement ecs_err
-                return self;
+                return Err(FlecsError::Json {
+                    component: Some(id_str(world, comp)),
+                    message: String::from("id is not a type"),
+                });
             }
 
             let type_ = (*ti).component;
@@ -26,20 +89,21 @@ impl EntityView<'_> {
                 "could not add comp to entity"
             );
             let json = compact_str::format_compact!("{}\0", json);
-            if let Some(desc) = desc {
-                sys::ecs_ptr_from_json(world, type_, ptr, json.as_ptr() as *const _, desc);
+            let desc_ptr = desc
+                .map(|d| d as *const FromJsonDesc)
+                .unwrap_or(core::ptr::null());
+            let result =
+                sys::ecs_ptr_from_json(world, type_, ptr, json.as_ptr() as *const _, desc_ptr);
+            sys::ecs_modified_id(world, id, comp);
+            if result.is_null() {
+                Err(FlecsError::Json {
+                    component: Some(id_str(world, comp)),
+                    message: String::new(),
+                })
             } else {
-                sys::ecs_ptr_from_json(
-                    world,
-                    type_,
-                    ptr,
-                    json.as_ptr() as *const _,
-                    core::ptr::null(),
-                );
+                Ok(())
             }
-            sys::ecs_modified_id(world, id, comp);
         }
-        self
     }
 
     /// Serialize entity to JSON.
@@ -64,13 +128,39 @@ impl EntityView<'_> {
 
     /// Deserialize entity to JSON.
     pub fn from_json(self, json: &str) -> Self {
+        self.from_json_impl(json);
+        self
+    }
+
+    /// Deserialize entity from JSON, returning the parse error on failure.
+    ///
+    /// This is the fallible counterpart of [`from_json`](Self::from_json). Components that were
+    /// deserialized before the error was encountered remain set on the entity.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Json`] if the JSON could not be parsed.
+    pub fn try_from_json(self, json: &str) -> Result<Self, FlecsError> {
+        let (success, message) = capture_errors(|| self.from_json_impl(json));
+        if success {
+            Ok(self)
+        } else {
+            Err(FlecsError::Json {
+                component: None,
+                message,
+            })
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_impl(self, json: &str) -> bool {
         let world = self.world_ptr_mut();
         let id = *self.id;
         //TODO we should have an Json Type so we don't need to make these conversions multiple times.
         let json = compact_str::format_compact!("{}\0", json);
         unsafe {
-            sys::ecs_entity_from_json(world, id, json.as_ptr() as *const _, core::ptr::null());
+            !sys::ecs_entity_from_json(world, id, json.as_ptr() as *const _, core::ptr::null())
+                .is_null()
         }
-        self
     }
 }
@@ -240,8 +240,92 @@ impl World {
         };
     }
 
+    /// Deserialize value from JSON, returning the parse error on failure.
+    ///
+    /// This is the fallible counterpart of [`from_json`](Self::from_json).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Json`] if the JSON could not be parsed into a value of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let mut pos = Position::default();
+    ///
+    /// world.try_from_json::<Position>(&mut pos, r#"{"x": 1, "y": 2}"#, None).unwrap();
+    /// assert_eq!(pos.y, 2.0);
+    ///
+    /// assert!(world.try_from_json::<Position>(&mut pos, "{", None).is_err());
+    /// ```
+    pub fn try_from_json<T: ComponentOrPairId>(
+        &self,
+        value: &mut T::CastType,
+        json: &str,
+        desc: Option<&FromJsonDesc>,
+    ) -> Result<(), FlecsError> {
+        let id = T::CastType::get_id(self);
+        // SAFETY: `value` is derived from a valid `&mut T::CastType` reference, see `from_json`.
+        let (success, message) = capture_errors(|| unsafe {
+            self.from_json_id_internal::<u64, true>(
+                id,
+                value as *mut T::CastType as *mut core::ffi::c_void,
+                json,
+                desc,
+            )
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(FlecsError::Json {
+                component: Some(id_str(self.ptr_mut(), id)),
+                message,
+            })
+        }
+    }
+
     /// Deserialize JSON into world.
     pub fn from_json_world(&self, json: &str, desc: Option<&FromJsonDesc>) -> &Self {
+        self.from_json_world_impl(json, desc);
+        self
+    }
+
+    /// Deserialize JSON into world, returning the parse error on failure.
+    ///
+    /// This is the fallible counterpart of [`from_json_world`](Self::from_json_world). Entities
+    /// that were deserialized before the error was encountered remain in the world.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Json`] if the JSON could not be parsed.
+    pub fn try_from_json_world(
+        &self,
+        json: &str,
+        desc: Option<&FromJsonDesc>,
+    ) -> Result<&Self, FlecsError> {
+        let (success, message) = capture_errors(|| self.from_json_world_impl(json, desc));
+        if success {
+            Ok(self)
+        } else {
+            Err(FlecsError::Json {
+                component: None,
+                message,
+            })
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_world_impl(&self, json: &str, desc: Option<&FromJsonDesc>) -> bool {
         let world = self.ptr_mut();
         //TODO json object to prevent multiple conversions
         let json = compact_str::format_compact!("{}\0", json);
@@ -253,11 +337,7 @@ impl World {
         // `json` is NUL-terminated via `format_compact!("{}\0", ..)`, so its `as_ptr()` is a
         // valid C string for `ecs_world_from_json`. `desc_ptr` is either null or points to a
         // live `FromJsonDesc` borrowed for the duration of this call.
-        unsafe {
-            sys::ecs_world_from_json(world, json.as_ptr() as *const _, desc_ptr);
-        }
-
-        self
+        unsafe { !sys::ecs_world_from_json(world, json.as_ptr() as *const _, desc_ptr).is_null() }
     }
 
     /// Deserialize JSON file into world.
@@ -25,6 +25,21 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// World the cursor was created for
+    pub(crate) fn world_ptr(&self) -> *const sys::ecs_world_t {
+        self.cursor.world
+    }
+
+    /// Serializer instruction the cursor currently points at
+    pub(crate) fn op(&self) -> Option<&sys::ecs_meta_op_t> {
+        let scope = &self.cursor.scope[self.cursor.depth as usize];
+        if scope.ops.is_null() {
+            return None;
+        }
+        // SAFETY: the cursor keeps `ops_cur` within the operations of the current scope.
+        Some(unsafe { &*scope.ops.add(scope.ops_cur as usize) })
+    }
+
     /// Push value scope (such as a nested struct)
     pub fn push(&mut self) -> i32 {
         unsafe { sys::ecs_meta_push(&mut self.cursor) }
@@ -185,7 +185,7 @@ macro_rules! meta_register_vector_func {
             }
 
             fn resize_vec(data: &mut Vec<$struct_type>, elem: usize) {
-                data.resize_with(elem + 1, || $struct_type { $($name : $value),* });
+                data.resize_with(elem, || $struct_type { $($name : $value),* });
             }
 
             // Ensure element exists, return
@@ -227,7 +227,7 @@ macro_rules! meta_register_vector_func {
                 }
 
                 fn resize_vec(data: &mut Vec<$struct_type>, elem: usize) {
-                    data.resize_with(elem + 1, || $struct_type::$constructor($($args),*));
+                    data.resize_with(elem, || $struct_type::$constructor($($args),*));
                 }
 
                 // Ensure element exists, return
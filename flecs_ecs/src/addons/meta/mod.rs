@@ -93,9 +93,13 @@ mod meta_fn_types;
 mod meta_functions;
 mod meta_traits;
 mod opaque;
+#[cfg(feature = "serde")]
+mod serde;
 mod untyped_component;
 mod world;
 
+#[cfg(feature = "serde")]
+pub use self::serde::*;
 pub use builtin::*;
 pub use component_id_fetcher::*;
 pub use cursor::*;
@@ -0,0 +1,193 @@
+use super::*;
+
+/// Serializes an entity with any `serde` format.
+///
+/// The entity is written as a map with its `name`, when it has one, and a `components` map
+/// from id string to component value. Tags and pairs without data have a unit value, and
+/// components without reflection data are skipped.
+///
+/// Created with [`EntityView::serde_value`].
+pub struct SerdeEntity<'a> {
+    entity: EntityView<'a>,
+}
+
+impl Serialize for SerdeEntity<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let world = self.entity.world_ptr();
+        let reader = Reader::new(self.entity.world());
+
+        let mut components = Vec::new();
+        let ids = unsafe { sys::ecs_get_type(world, *self.entity.id()) };
+        let ids: &[u64] = if ids.is_null() || unsafe { (*ids).count } == 0 {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts((*ids).array, (*ids).count as usize) }
+        };
+
+        for &id in ids {
+            // the name is serialized separately
+            if ecs_is_pair(id) && ecs_first(id, self.entity.world()) == ECS_IDENTIFIER {
+                continue;
+            }
+
+            let type_id = unsafe { sys::ecs_get_typeid(world, id) };
+            let value = if type_id == 0 {
+                Value::Unit
+            } else if reader.is_reflected(type_id) {
+                let ptr = unsafe { sys::ecs_get_id(world, *self.entity.id(), id) };
+                unsafe { reader.read_type(type_id, ptr as *const u8) }
+                    .map_err(ser::Error::custom)?
+            } else {
+                continue;
+            };
+
+            let key = id_str(world, id);
+            components.push((key, value));
+        }
+
+        let name = self.entity.get_name();
+        let mut map = serializer.serialize_map(Some(1 + name.is_some() as usize))?;
+        if let Some(name) = name {
+            map.serialize_entry("name", &name)?;
+        }
+        map.serialize_entry("components", &Value::Map(components))?;
+        map.end()
+    }
+}
+
+/// Deserializes any `serde` format produced by [`SerdeEntity`] into an entity.
+///
+/// Components are added to the entity, or overwritten when the entity already has them.
+/// Created with [`EntityView::serde_seed`].
+pub struct SerdeEntitySeed<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'de> DeserializeSeed<'de> for SerdeEntitySeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(EntityVisitor {
+            entity: self.entity,
+        })
+    }
+}
+
+const ENTITY_FIELDS: &[&str] = &["name", "components"];
+
+struct EntityVisitor<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'de> Visitor<'de> for EntityVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a serialized entity")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "name" => {
+                    let name = map.next_value::<String>()?;
+                    self.entity.set_name(&name);
+                }
+                "components" => map.next_value_seed(ComponentsSeed {
+                    entity: self.entity,
+                })?,
+                other => return Err(de::Error::unknown_field(other, ENTITY_FIELDS)),
+            }
+        }
+        Ok(())
+    }
+}
+
+struct ComponentsSeed<'a> {
+    entity: EntityView<'a>,
+}
+
+impl<'de> DeserializeSeed<'de> for ComponentsSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ComponentsSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of component ids to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let world = self.entity.world_ptr_mut();
+        let entity = *self.entity.id();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let expr = compact_str::format_compact!("{}\0", key);
+            let id = unsafe { sys::ecs_id_from_str(world, expr.as_ptr() as *const _) };
+            if id == 0 {
+                return Err(de::Error::custom(format!("unknown component '{key}'")));
+            }
+
+            let type_info = unsafe { sys::ecs_get_type_info(world, id) };
+            if type_info.is_null() {
+                map.next_value::<()>()?;
+                unsafe { sys::ecs_add_id(world, entity, id) };
+                continue;
+            }
+
+            let type_id = unsafe { (*type_info).component };
+            let ptr = unsafe { sys::ecs_ensure_id(world, entity, id, (*type_info).size as usize) };
+            let mut cursor = unsafe { Cursor::new(self.entity.world(), type_id, ptr) };
+            map.next_value_seed(CursorSeed {
+                cursor: &mut cursor,
+            })?;
+            unsafe { sys::ecs_modified_id(world, entity, id) };
+        }
+        Ok(())
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Serialize the entity and its reflected components with any `serde` format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Health {
+    ///     value: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity_named("player").set(Health { value: 10 });
+    ///
+    /// let json = serde_json::to_value(e.serde_value()).unwrap();
+    /// assert_eq!(json["name"], "player");
+    /// assert_eq!(json["components"].as_object().unwrap().len(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::serde_seed()`]
+    /// * [`EntityView::to_json()`]
+    pub fn serde_value(self) -> SerdeEntity<'a> {
+        SerdeEntity { entity: self }
+    }
+
+    /// Deserialize an entity written by [`EntityView::serde_value`] into this entity.
+    ///
+    /// # See also
+    ///
+    /// * [`EntityView::serde_value()`]
+    /// * [`EntityView::from_json()`]
+    pub fn serde_seed(self) -> SerdeEntitySeed<'a> {
+        SerdeEntitySeed { entity: self }
+    }
+}
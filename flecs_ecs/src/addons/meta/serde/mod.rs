@@ -0,0 +1,713 @@
+//! [`serde`](::serde) adapters for reflected values.
+//!
+//! Values are serialized by walking the serializer instructions (`EcsTypeSerializer`) of their
+//! type, and deserialized by driving a [`Cursor`] over the destination. Any component with
+//! reflection data, such as a `#[flecs(meta)]` component, can therefore be written to and read
+//! from any serde format without implementing `Serialize` or `Deserialize` on the type itself.
+//!
+//! The data model mirrors the JSON addon:
+//!
+//! - structs are maps from member name to value
+//! - arrays and vectors are sequences
+//! - enums and bitmasks are the names of their constants, bitmask constants joined by `|`
+//! - entities are paths and ids use their string representation
+//!
+//! Integer and floating point members keep their width, so the output can be read back by
+//! formats that are not self-describing such as bincode. Map types and dynamic values
+//! (`flecs.meta.value`) are not supported.
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//! use serde::de::DeserializeSeed;
+//!
+//! #[derive(Component, Default)]
+//! #[flecs(meta)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! let world = World::new();
+//!
+//! let json = serde_json::to_string(&world.serde_value(&Position { x: 1.0, y: 2.0 })).unwrap();
+//! assert_eq!(json, r#"{"x":1.0,"y":2.0}"#);
+//!
+//! let mut pos = Position::default();
+//! world
+//!     .serde_seed(&mut pos)
+//!     .deserialize(&mut serde_json::Deserializer::from_str(&json))
+//!     .unwrap();
+//! assert_eq!((pos.x, pos.y), (1.0, 2.0));
+//! ```
+
+mod entity_view;
+mod world;
+
+pub use entity_view::*;
+
+use core::ffi::{CStr, c_char, c_void};
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{self, SerializeMap, SerializeSeq};
+use ::serde::{Deserializer, Serialize, Serializer};
+use flecs_ecs_derive::extern_abi;
+
+use super::Cursor;
+use crate::core::*;
+use crate::sys;
+
+extern crate alloc;
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+/// Serializes a reflected value with any `serde` format.
+///
+/// Created with [`World::serde_value`] or [`World::serde_value_id`].
+pub struct SerdeValue<'a> {
+    world: WorldRef<'a>,
+    type_id: Entity,
+    ptr: *const c_void,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> SerdeValue<'a> {
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of type `type_id` that outlives `'a`.
+    pub(crate) unsafe fn new(world: WorldRef<'a>, type_id: Entity, ptr: *const c_void) -> Self {
+        Self {
+            world,
+            type_id,
+            ptr,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Serialize for SerdeValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let reader = Reader::new(self.world);
+        unsafe { reader.read_type(*self.type_id, self.ptr as *const u8) }
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Deserializes any `serde` format into a reflected value.
+///
+/// Members that are not present in the input keep their current value. Created with
+/// [`World::serde_seed`] or [`World::serde_seed_id`].
+pub struct SerdeSeed<'a> {
+    cursor: Cursor<'a>,
+    phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> SerdeSeed<'a> {
+    pub(crate) fn new(cursor: Cursor<'a>) -> Self {
+        Self {
+            cursor,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for SerdeSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(mut self, deserializer: D) -> Result<(), D::Error> {
+        CursorSeed {
+            cursor: &mut self.cursor,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+/// Owned copy of a reflected value.
+///
+/// Opaque types only hand out their serialized values for the duration of a callback, so values
+/// are first read into this tree and serialized afterwards.
+pub(crate) enum Value {
+    Unit,
+    Bool(bool),
+    Char(char),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Str(String),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Unit => serializer.serialize_unit(),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::Char(v) => serializer.serialize_char(*v),
+            Value::U8(v) => serializer.serialize_u8(*v),
+            Value::U16(v) => serializer.serialize_u16(*v),
+            Value::U32(v) => serializer.serialize_u32(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::I8(v) => serializer.serialize_i8(*v),
+            Value::I16(v) => serializer.serialize_i16(*v),
+            Value::I32(v) => serializer.serialize_i32(*v),
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::F32(v) => serializer.serialize_f32(*v),
+            Value::F64(v) => serializer.serialize_f64(*v),
+            Value::Str(v) => serializer.serialize_str(v),
+            Value::Seq(elems) => {
+                let mut seq = serializer.serialize_seq(Some(elems.len()))?;
+                for elem in elems {
+                    seq.serialize_element(elem)?;
+                }
+                seq.end()
+            }
+            Value::Map(members) => {
+                let mut map = serializer.serialize_map(Some(members.len()))?;
+                for (name, value) in members {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// Reads values by walking the serializer instructions of their type.
+pub(crate) struct Reader<'a> {
+    world: WorldRef<'a>,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(world: WorldRef<'a>) -> Self {
+        Self { world }
+    }
+
+    /// Returns whether `type_id` has reflection data the reader can walk.
+    pub(crate) fn is_reflected(&self, type_id: u64) -> bool {
+        unsafe { sys::ecs_has_id(self.world.world_ptr(), type_id, ECS_META_TYPE_SERIALIZER) }
+    }
+
+    /// Path of an entity as used by the JSON addon.
+    fn path(&self, entity: u64) -> String {
+        entity_path(self.world.entity_from_id(entity))
+    }
+
+    /// Read the value of type `type_id` at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of type `type_id`.
+    pub(crate) unsafe fn read_type(&self, type_id: u64, ptr: *const u8) -> Result<Value, String> {
+        let ops = self.type_ops(type_id)?;
+        unsafe { self.read_op(ops, 0, ptr) }.map(|(value, _)| value)
+    }
+
+    fn type_ops(&self, type_id: u64) -> Result<&[sys::ecs_meta_op_t], String> {
+        let ts =
+            unsafe { sys::ecs_get_id(self.world.world_ptr(), type_id, ECS_META_TYPE_SERIALIZER) }
+                as *const sys::EcsTypeSerializer;
+        if ts.is_null() {
+            return Err(format!(
+                "type '{}' has no reflection data",
+                self.path(type_id)
+            ));
+        }
+
+        let ops = unsafe { &(*ts).ops };
+        if ops.count <= 0 {
+            return Err(format!(
+                "type '{}' has no serializer instructions",
+                self.path(type_id)
+            ));
+        }
+        Ok(unsafe {
+            core::slice::from_raw_parts(ops.array as *const sys::ecs_meta_op_t, ops.count as usize)
+        })
+    }
+
+    /// Read the value described by `ops[index]`, returning it with the number of instructions
+    /// it spans.
+    unsafe fn read_op(
+        &self,
+        ops: &[sys::ecs_meta_op_t],
+        index: usize,
+        base: *const u8,
+    ) -> Result<(Value, usize), String> {
+        let op = &ops[index];
+        let ptr = unsafe { base.add(op.offset as usize) };
+        let scope = || &ops[index + 1..index + op.op_count as usize - 1];
+
+        let value = unsafe {
+            match op.kind {
+                sys::ecs_meta_op_kind_t_EcsOpPushStruct => {
+                    return Ok((self.read_struct(scope(), ptr)?, op.op_count as usize));
+                }
+                sys::ecs_meta_op_kind_t_EcsOpPushArray => {
+                    let count = sys::ecs_meta_op_get_elem_count(op, ptr as *const c_void);
+                    let value = self.read_elems(scope(), ptr, op.elem_size, count)?;
+                    return Ok((value, op.op_count as usize));
+                }
+                sys::ecs_meta_op_kind_t_EcsOpPushVector => {
+                    let vec = &*(ptr as *const sys::ecs_vec_t);
+                    let value =
+                        self.read_elems(scope(), vec.array as *const u8, op.elem_size, vec.count)?;
+                    return Ok((value, op.op_count as usize));
+                }
+                sys::ecs_meta_op_kind_t_EcsOpPushMap | sys::ecs_meta_op_kind_t_EcsOpPushValue => {
+                    return Err(format!(
+                        "cannot serialize '{}': map and dynamic value types are not supported",
+                        self.path(op.type_)
+                    ));
+                }
+                sys::ecs_meta_op_kind_t_EcsOpForward => self.read_type(op.type_, ptr)?,
+                sys::ecs_meta_op_kind_t_EcsOpOpaqueStruct
+                | sys::ecs_meta_op_kind_t_EcsOpOpaqueArray
+                | sys::ecs_meta_op_kind_t_EcsOpOpaqueVector
+                | sys::ecs_meta_op_kind_t_EcsOpOpaqueValue => self.read_opaque(op, ptr)?,
+                sys::ecs_meta_op_kind_t_EcsOpEnum => Value::Str(self.read_enum(op, ptr)?),
+                sys::ecs_meta_op_kind_t_EcsOpBitmask => Value::Str(self.read_bitmask(op, ptr)?),
+                sys::ecs_meta_op_kind_t_EcsOpBool => Value::Bool(*(ptr as *const bool)),
+                sys::ecs_meta_op_kind_t_EcsOpChar => Value::Char(*ptr as char),
+                sys::ecs_meta_op_kind_t_EcsOpByte | sys::ecs_meta_op_kind_t_EcsOpU8 => {
+                    Value::U8(*ptr)
+                }
+                sys::ecs_meta_op_kind_t_EcsOpU16 => Value::U16(*(ptr as *const u16)),
+                sys::ecs_meta_op_kind_t_EcsOpU32 => Value::U32(*(ptr as *const u32)),
+                sys::ecs_meta_op_kind_t_EcsOpU64 => Value::U64(*(ptr as *const u64)),
+                sys::ecs_meta_op_kind_t_EcsOpUPtr => Value::U64(*(ptr as *const usize) as u64),
+                sys::ecs_meta_op_kind_t_EcsOpI8 => Value::I8(*(ptr as *const i8)),
+                sys::ecs_meta_op_kind_t_EcsOpI16 => Value::I16(*(ptr as *const i16)),
+                sys::ecs_meta_op_kind_t_EcsOpI32 => Value::I32(*(ptr as *const i32)),
+                sys::ecs_meta_op_kind_t_EcsOpI64 => Value::I64(*(ptr as *const i64)),
+                sys::ecs_meta_op_kind_t_EcsOpIPtr => Value::I64(*(ptr as *const isize) as i64),
+                sys::ecs_meta_op_kind_t_EcsOpF32 => Value::F32(*(ptr as *const f32)),
+                sys::ecs_meta_op_kind_t_EcsOpF64 => Value::F64(*(ptr as *const f64)),
+                sys::ecs_meta_op_kind_t_EcsOpString => {
+                    let str = *(ptr as *const *const c_char);
+                    if str.is_null() {
+                        Value::Str(String::new())
+                    } else {
+                        Value::Str(CStr::from_ptr(str).to_string_lossy().into_owned())
+                    }
+                }
+                sys::ecs_meta_op_kind_t_EcsOpEntity => Value::Str(self.path(*(ptr as *const u64))),
+                sys::ecs_meta_op_kind_t_EcsOpId => {
+                    let id = *(ptr as *const u64);
+                    if id == 0 {
+                        Value::Str("#0".to_owned())
+                    } else {
+                        Value::Str(id_str(self.world.world_ptr(), id))
+                    }
+                }
+                kind => return Err(format!("unexpected serializer instruction {kind}")),
+            }
+        };
+
+        Ok((value, 1))
+    }
+
+    unsafe fn read_struct(
+        &self,
+        ops: &[sys::ecs_meta_op_t],
+        base: *const u8,
+    ) -> Result<Value, String> {
+        let mut members = Vec::new();
+        let mut index = 0;
+        while index < ops.len() {
+            let name = ops[index].name;
+            let (value, span) = unsafe { self.read_op(ops, index, base)? };
+            if !name.is_null() {
+                let name = unsafe { CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned();
+                members.push((name, value));
+            }
+            index += span;
+        }
+        Ok(Value::Map(members))
+    }
+
+    unsafe fn read_elems(
+        &self,
+        ops: &[sys::ecs_meta_op_t],
+        array: *const u8,
+        elem_size: i32,
+        count: i32,
+    ) -> Result<Value, String> {
+        let mut elems = Vec::with_capacity(count.max(0) as usize);
+        for i in 0..count.max(0) as usize {
+            let elem = unsafe { array.add(i * elem_size as usize) };
+            elems.push(unsafe { self.read_op(ops, 0, elem)? }.0);
+        }
+        Ok(Value::Seq(elems))
+    }
+
+    unsafe fn read_opaque(&self, op: &sys::ecs_meta_op_t, ptr: *const u8) -> Result<Value, String> {
+        let Some(serialize) = (unsafe { op.is.opaque }) else {
+            return Err(format!(
+                "opaque type '{}' has no serialize callback",
+                self.path(op.type_)
+            ));
+        };
+
+        let is_struct = op.kind == sys::ecs_meta_op_kind_t_EcsOpOpaqueStruct;
+        let mut collector = OpaqueCollector {
+            reader: self,
+            member: None,
+            values: Vec::new(),
+            error: None,
+        };
+        let ser = sys::ecs_serializer_t {
+            value: Some(opaque_value),
+            member: if is_struct { Some(opaque_member) } else { None },
+            world: self.world.world_ptr(),
+            ctx: &mut collector as *mut OpaqueCollector as *mut c_void,
+        };
+
+        let result = unsafe { serialize(&ser, ptr as *const c_void) };
+        if let Some(error) = collector.error {
+            return Err(error);
+        }
+        if result != 0 {
+            return Err(format!(
+                "failed to serialize opaque type '{}'",
+                self.path(op.type_)
+            ));
+        }
+
+        let values = collector.values;
+        Ok(match op.kind {
+            sys::ecs_meta_op_kind_t_EcsOpOpaqueStruct => Value::Map(
+                values
+                    .into_iter()
+                    .map(|(name, value)| (name.unwrap_or_default(), value))
+                    .collect(),
+            ),
+            sys::ecs_meta_op_kind_t_EcsOpOpaqueValue => values
+                .into_iter()
+                .next()
+                .map_or(Value::Unit, |(_, value)| value),
+            _ => Value::Seq(values.into_iter().map(|(_, value)| value).collect()),
+        })
+    }
+
+    unsafe fn read_enum(&self, op: &sys::ecs_meta_op_t, ptr: *const u8) -> Result<String, String> {
+        let value = unsafe {
+            match op.underlying_kind {
+                sys::ecs_meta_op_kind_t_EcsOpU8 => *ptr as u64,
+                sys::ecs_meta_op_kind_t_EcsOpI8 => *(ptr as *const i8) as i64 as u64,
+                sys::ecs_meta_op_kind_t_EcsOpU16 => *(ptr as *const u16) as u64,
+                sys::ecs_meta_op_kind_t_EcsOpI16 => *(ptr as *const i16) as i64 as u64,
+                sys::ecs_meta_op_kind_t_EcsOpU32 => *(ptr as *const u32) as u64,
+                sys::ecs_meta_op_kind_t_EcsOpI32 => *(ptr as *const i32) as i64 as u64,
+                sys::ecs_meta_op_kind_t_EcsOpUPtr => *(ptr as *const usize) as u64,
+                sys::ecs_meta_op_kind_t_EcsOpIPtr => *(ptr as *const isize) as i64 as u64,
+                _ => *(ptr as *const u64),
+            }
+        };
+
+        let constant = unsafe { sys::ecs_map_get_deref_(op.is.constants, value) }
+            as *const sys::ecs_enum_constant_t;
+        if constant.is_null() {
+            return Err(format!(
+                "value {} of enum '{}' is not a valid constant",
+                value as i64,
+                self.path(op.type_)
+            ));
+        }
+        Ok(self.name(unsafe { (*constant).constant }))
+    }
+
+    unsafe fn read_bitmask(
+        &self,
+        op: &sys::ecs_meta_op_t,
+        ptr: *const u8,
+    ) -> Result<String, String> {
+        let mut value = unsafe { *(ptr as *const u32) } as u64;
+        if value == 0 {
+            return Ok("0".to_owned());
+        }
+
+        let mut constants = Vec::new();
+        let mut it = unsafe { sys::ecs_map_iter(op.is.constants) };
+        while unsafe { sys::ecs_map_next(&mut it) } {
+            let key = unsafe { *it.res };
+            let constant = unsafe { *it.res.add(1) } as *const sys::ecs_bitmask_constant_t;
+            constants.push((key, unsafe { (*constant).constant }));
+        }
+        constants.sort_unstable_by_key(|(key, _)| *key);
+
+        let mut names = Vec::new();
+        for (key, constant) in constants {
+            if key != 0 && value & key == key {
+                names.push(self.name(constant));
+                value &= !key;
+            }
+        }
+
+        if value != 0 {
+            return Err(format!(
+                "value of bitmask '{}' contains bits ({value}) that are not a constant",
+                self.path(op.type_)
+            ));
+        }
+        Ok(names.join("|"))
+    }
+
+    fn name(&self, entity: u64) -> String {
+        let name = unsafe { sys::ecs_get_name(self.world.world_ptr(), entity) };
+        if name.is_null() {
+            return format!("#{entity}");
+        }
+        unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+struct OpaqueCollector<'r, 'a> {
+    reader: &'r Reader<'a>,
+    member: Option<String>,
+    values: Vec<(Option<String>, Value)>,
+    error: Option<String>,
+}
+
+#[extern_abi]
+fn opaque_value(ser: *const sys::ecs_serializer_t, type_id: u64, value: *const c_void) -> i32 {
+    let collector = unsafe { &mut *((*ser).ctx as *mut OpaqueCollector) };
+    match unsafe { collector.reader.read_type(type_id, value as *const u8) } {
+        Ok(value) => {
+            let member = collector.member.take();
+            collector.values.push((member, value));
+            0
+        }
+        Err(error) => {
+            collector.error = Some(error);
+            -1
+        }
+    }
+}
+
+#[extern_abi]
+fn opaque_member(ser: *const sys::ecs_serializer_t, member: *const c_char) -> i32 {
+    let collector = unsafe { &mut *((*ser).ctx as *mut OpaqueCollector) };
+    let member = unsafe { CStr::from_ptr(member) };
+    collector.member = Some(member.to_string_lossy().into_owned());
+    0
+}
+
+/// Path of an entity as used by the JSON addon, `#0` for no entity.
+fn entity_path(entity: EntityView) -> String {
+    if *entity.id() == 0 {
+        return "#0".to_owned();
+    }
+    entity.path_from_with_sep(0, ".", "").unwrap_or_default()
+}
+
+/// Deserializes into the value the cursor points at.
+pub(crate) struct CursorSeed<'c, 'a> {
+    pub(crate) cursor: &'c mut Cursor<'a>,
+}
+
+impl CursorSeed<'_, '_> {
+    /// Kind of the value at the cursor, looking through forwards and opaque values.
+    fn value_kind(&self) -> Option<sys::ecs_meta_op_kind_t> {
+        let op = self.cursor.op()?;
+        let world = self.cursor.world_ptr();
+        let first_kind = |type_id: u64| {
+            let ts = unsafe { sys::ecs_get_id(world, type_id, ECS_META_TYPE_SERIALIZER) }
+                as *const sys::EcsTypeSerializer;
+            if ts.is_null() || unsafe { (*ts).ops.count } <= 0 {
+                return None;
+            }
+            Some(unsafe { (*((*ts).ops.array as *const sys::ecs_meta_op_t)).kind })
+        };
+
+        match op.kind {
+            sys::ecs_meta_op_kind_t_EcsOpForward => first_kind(op.type_),
+            sys::ecs_meta_op_kind_t_EcsOpOpaqueValue => {
+                let opaque = unsafe { sys::ecs_get_id(world, op.type_, ECS_OPAQUE) }
+                    as *const sys::EcsOpaque;
+                if opaque.is_null() {
+                    return None;
+                }
+                first_kind(unsafe { (*opaque).as_type })
+            }
+            kind => Some(kind),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for CursorSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let Some(kind) = self.value_kind() else {
+            return Err(de::Error::custom(
+                "cursor does not point to a reflected value",
+            ));
+        };
+        let visitor = CursorVisitor {
+            cursor: self.cursor,
+        };
+
+        match kind {
+            sys::ecs_meta_op_kind_t_EcsOpPushStruct | sys::ecs_meta_op_kind_t_EcsOpOpaqueStruct => {
+                deserializer.deserialize_map(visitor)
+            }
+            sys::ecs_meta_op_kind_t_EcsOpPushArray
+            | sys::ecs_meta_op_kind_t_EcsOpPushVector
+            | sys::ecs_meta_op_kind_t_EcsOpOpaqueArray
+            | sys::ecs_meta_op_kind_t_EcsOpOpaqueVector => deserializer.deserialize_seq(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpBool => deserializer.deserialize_bool(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpChar => deserializer.deserialize_char(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpByte | sys::ecs_meta_op_kind_t_EcsOpU8 => {
+                deserializer.deserialize_u8(visitor)
+            }
+            sys::ecs_meta_op_kind_t_EcsOpU16 => deserializer.deserialize_u16(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpU32 => deserializer.deserialize_u32(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpU64 | sys::ecs_meta_op_kind_t_EcsOpUPtr => {
+                deserializer.deserialize_u64(visitor)
+            }
+            sys::ecs_meta_op_kind_t_EcsOpI8 => deserializer.deserialize_i8(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpI16 => deserializer.deserialize_i16(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpI32 => deserializer.deserialize_i32(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpI64 | sys::ecs_meta_op_kind_t_EcsOpIPtr => {
+                deserializer.deserialize_i64(visitor)
+            }
+            sys::ecs_meta_op_kind_t_EcsOpF32 => deserializer.deserialize_f32(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpF64 => deserializer.deserialize_f64(visitor),
+            sys::ecs_meta_op_kind_t_EcsOpString
+            | sys::ecs_meta_op_kind_t_EcsOpEnum
+            | sys::ecs_meta_op_kind_t_EcsOpBitmask
+            | sys::ecs_meta_op_kind_t_EcsOpEntity
+            | sys::ecs_meta_op_kind_t_EcsOpId => deserializer.deserialize_str(visitor),
+            _ => Err(de::Error::custom(
+                "map and dynamic value types are not supported",
+            )),
+        }
+    }
+}
+
+struct CursorVisitor<'c, 'a> {
+    cursor: &'c mut Cursor<'a>,
+}
+
+impl CursorVisitor<'_, '_> {
+    fn check<E: de::Error>(&self, result: i32) -> Result<(), E> {
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(E::custom(format!(
+                "invalid value for '{}'",
+                entity_path(self.cursor.get_type())
+            )))
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for CursorVisitor<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value matching the reflection data of the type")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<(), E> {
+        let result = self.cursor.set_bool(v);
+        self.check(result)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<(), E> {
+        let result = self.cursor.set_int(v);
+        self.check(result)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<(), E> {
+        let result = self.cursor.set_uint(v);
+        self.check(result)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<(), E> {
+        let result = self.cursor.set_float(v);
+        self.check(result)
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<(), E> {
+        let result = self.cursor.set_char(v);
+        self.check(result)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+        let result = self.cursor.set_string(v);
+        self.check(result)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        let result = self.cursor.set_null();
+        self.check(result)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let result = self.cursor.push();
+        self.check(result)?;
+        while let Some(member) = map.next_key::<String>()? {
+            if self.cursor.member(&member) != 0 {
+                return Err(de::Error::custom(format!("unknown member '{member}'")));
+            }
+            map.next_value_seed(CursorSeed {
+                cursor: self.cursor,
+            })?;
+        }
+        let result = self.cursor.pop();
+        self.check(result)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let result = self.cursor.push();
+        self.check(result)?;
+        let mut first = true;
+        while seq
+            .next_element_seed(ElementSeed {
+                cursor: self.cursor,
+                first,
+            })?
+            .is_some()
+        {
+            first = false;
+        }
+        let result = self.cursor.pop();
+        self.check(result)
+    }
+}
+
+/// Moves the cursor to the next element of a collection before deserializing into it.
+struct ElementSeed<'c, 'a> {
+    cursor: &'c mut Cursor<'a>,
+    first: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for ElementSeed<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        if !self.first && self.cursor.next() != 0 {
+            return Err(de::Error::custom("too many elements for collection"));
+        }
+        CursorSeed {
+            cursor: self.cursor,
+        }
+        .deserialize(deserializer)
+    }
+}
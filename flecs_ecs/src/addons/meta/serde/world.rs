@@ -0,0 +1,104 @@
+use core::ffi::c_void;
+
+use super::*;
+
+impl World {
+    /// Serialize a reflected value with any `serde` format.
+    ///
+    /// The component must have reflection data, for example by deriving it with
+    /// `#[flecs(meta)]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Health {
+    ///     value: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let json = serde_json::to_string(&world.serde_value(&Health { value: 10 })).unwrap();
+    /// assert_eq!(json, r#"{"value":10}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::serde_seed()`]
+    /// * [`World::to_json()`]
+    pub fn serde_value<'a, T: ComponentId>(&'a self, value: &'a T) -> SerdeValue<'a> {
+        let type_id = self.component_id::<T>();
+        unsafe { SerdeValue::new(self.world(), type_id, value as *const T as *const c_void) }
+    }
+
+    /// Serialize an untyped reflected value with any `serde` format.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the type `type_id` that stays alive and
+    /// unchanged while the returned value is used.
+    ///
+    /// # See also
+    ///
+    /// * [`World::serde_value()`]
+    pub unsafe fn serde_value_id(
+        &self,
+        type_id: impl Into<Entity>,
+        ptr: *const c_void,
+    ) -> SerdeValue<'_> {
+        unsafe { SerdeValue::new(self.world(), type_id.into(), ptr) }
+    }
+
+    /// Deserialize any `serde` format into a reflected value.
+    ///
+    /// Use the returned seed with [`DeserializeSeed::deserialize`](::serde::de::DeserializeSeed::deserialize).
+    /// Members that are missing from the input keep their current value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use flecs_ecs::prelude::*;
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// #[derive(Component, Default)]
+    /// #[flecs(meta)]
+    /// struct Health {
+    ///     value: i32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let mut health = Health::default();
+    /// world
+    ///     .serde_seed(&mut health)
+    ///     .deserialize(&mut serde_json::Deserializer::from_str(r#"{"value":10}"#))
+    ///     .unwrap();
+    /// assert_eq!(health.value, 10);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::serde_value()`]
+    /// * [`World::from_json()`]
+    pub fn serde_seed<'a, T: ComponentId>(&'a self, value: &'a mut T) -> SerdeSeed<'a> {
+        SerdeSeed::new(self.cursor(value))
+    }
+
+    /// Deserialize any `serde` format into an untyped reflected value.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the type `type_id` that is not accessed elsewhere
+    /// while the returned seed is used.
+    ///
+    /// # See also
+    ///
+    /// * [`World::serde_seed()`]
+    pub unsafe fn serde_seed_id(
+        &self,
+        type_id: impl Into<Entity>,
+        ptr: *mut c_void,
+    ) -> SerdeSeed<'_> {
+        SerdeSeed::new(unsafe { Cursor::new(self, type_id.into(), ptr) })
+    }
+}
@@ -118,10 +118,10 @@ impl<'a> MetricBuilder<'a> {
         let ent = EntityView::new_from(self.world(), id);
         let m = ent.try_lookup(name);
 
-        if m.is_none() {
+        if m.is_err() {
             // TODO: this should be a tracing error log
             ecs_assert!(
-                m.is_some(),
+                m.is_ok(),
                 FlecsErrorCode::InvalidParameter,
                 "member '{}' not found in type '{}'",
                 name,
@@ -62,11 +62,15 @@
 //!   - Feature: `flecs_alerts`
 //!   - Used for: Detecting problematic states, validation
 //!
+//! - **[`journal`]** - Record and replay world operations
+//!   - Feature: `flecs_journal`
+//!   - Used for: Reproducing bug reports, regression tests from recorded sessions
+//!
 //! ## Remote Access
 //!
-//! - **REST API** - HTTP server for remote data access
+//! - **[`rest`]** - HTTP server for remote data access
 //!   - Feature: `flecs_rest`
-//!   - Used for: Web-based UIs, remote inspection, Flecs Explorer
+//!   - Used for: Web-based UIs, remote inspection, Flecs Explorer, custom endpoints
 //!
 //! ## Utilities
 //!
@@ -187,6 +191,11 @@ pub mod script;
 #[cfg(feature = "flecs_script")]
 pub use script::*;
 
+#[cfg(feature = "flecs_rest")]
+pub mod rest;
+#[cfg(feature = "flecs_rest")]
+pub use rest::*;
+
 #[cfg(feature = "flecs_json")]
 pub mod json;
 #[cfg(feature = "flecs_json")]
@@ -203,6 +212,11 @@ pub mod alerts;
 #[cfg(feature = "flecs_alerts")]
 pub use alerts::*;
 
+#[cfg(feature = "flecs_journal")]
+pub mod journal;
+#[cfg(feature = "flecs_journal")]
+pub use journal::*;
+
 // this is not feature gated to flecs_meta so calling `.meta()` on a component will always work despite meta being disabled.
 pub trait Meta<Component> {
     fn meta(component: flecs_ecs::core::Component<Component>);
@@ -128,7 +128,7 @@ impl World {
         let comp = self.component::<M>();
         let id = comp.id();
 
-        if let Some(existing) = self.try_lookup_recursive(name) {
+        if let Ok(existing) = self.try_lookup_recursive(name) {
             self.set_scope(existing);
             return existing;
         }
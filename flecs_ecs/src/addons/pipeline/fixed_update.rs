@@ -0,0 +1,149 @@
+//! Fixed time step updates.
+//!
+//! Systems in the [`FixedUpdate`] phase run with a fixed time step, independently of the frame
+//! rate. Every frame adds its delta time to an accumulator, and the fixed systems run once for
+//! every full step in the accumulator, before the systems of the pipeline of the world. Inside
+//! a fixed system, [`TableIter::delta_time()`] is the step.
+//!
+//! Fixed updates are enabled with [`World::set_fixed_time_step()`]. After the fixed steps of a
+//! frame, the [`FixedAlpha`] singleton holds the fraction of a step that is left in the
+//! accumulator, which rendering uses to interpolate between the last two fixed states.
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//!
+//! #[derive(Component)]
+//! struct Position {
+//!     x: f32,
+//! }
+//!
+//! #[derive(Component)]
+//! struct Velocity {
+//!     x: f32,
+//! }
+//!
+//! let world = World::new();
+//! world.set_fixed_time_step(1.0 / 60.0);
+//!
+//! world
+//!     .system::<(&mut Position, &Velocity)>()
+//!     .kind(FixedUpdate)
+//!     .each_iter(|it, _, (p, v)| {
+//!         p.x += v.x * it.delta_time();
+//!     });
+//!
+//! world.entity().set(Position { x: 0.0 }).set(Velocity { x: 60.0 });
+//!
+//! // 2.5 steps: the fixed system runs twice, and half a step is left for the next frame
+//! world.progress_time(2.5 / 60.0);
+//!
+//! let alpha = world.get::<&FixedAlpha>(|alpha| alpha.0);
+//! assert!((alpha - 0.5).abs() < 1e-3);
+//! ```
+
+use core::ffi::c_void;
+
+use crate::core::*;
+use crate::sys;
+use flecs_ecs_derive::Component;
+
+/// Default value of [`FixedTime::max_steps`].
+pub const DEFAULT_MAX_FIXED_STEPS: u32 = 8;
+
+/// Phase of the systems that run with a fixed time step.
+///
+/// Unlike the builtin phases, `FixedUpdate` is not a [`flecs::pipeline::Phase`], so its
+/// systems are not run by the pipeline of the world. They are run by the fixed update pipeline,
+/// which is the `FixedUpdate` entity itself.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct FixedUpdate;
+
+/// Configuration and state of fixed updates. Singleton, set by
+/// [`World::set_fixed_time_step()`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FixedTime {
+    /// Duration of a fixed step in seconds.
+    pub step: FTime,
+    /// Maximum number of fixed steps in a frame. When a frame needs more steps to catch up, the
+    /// remaining full steps are dropped, so that a slow frame doesn't make the next frames
+    /// slower.
+    pub max_steps: u32,
+    /// Time that has not been consumed by fixed steps yet.
+    pub accumulator: FTime,
+    /// Number of fixed steps that ran in the last frame.
+    pub steps: u32,
+}
+
+impl FixedTime {
+    /// Create a configuration with the provided step, and [`DEFAULT_MAX_FIXED_STEPS`].
+    pub fn new(step: FTime) -> Self {
+        Self {
+            step,
+            max_steps: DEFAULT_MAX_FIXED_STEPS,
+            accumulator: 0.0,
+            steps: 0,
+        }
+    }
+
+    /// Adds the delta time of a frame, and consumes the steps that run in the frame.
+    fn advance(&mut self, delta_time: FTime) {
+        self.accumulator += delta_time;
+        let available = (self.accumulator / self.step) as u32;
+        self.accumulator -= available as FTime * self.step;
+        self.steps = available.min(self.max_steps);
+    }
+
+    /// Fraction of a step left in the accumulator.
+    pub fn alpha(&self) -> FTime {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
+
+/// Fraction of a fixed step left in the accumulator after the fixed steps of a frame, in
+/// `[0, 1]`. Singleton, updated every frame while fixed updates are enabled.
+///
+/// Rendering interpolates between the state before and after the last fixed step with
+/// `previous + (current - previous) * alpha`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct FixedAlpha(pub FTime);
+
+/// Whether fixed updates are enabled, without registering [`FixedTime`].
+pub(crate) fn fixed_update_enabled(world: &World) -> bool {
+    FixedTime::is_registered_with_world(world) && world.has(FixedTime::id())
+}
+
+/// Creates the fixed update pipeline, if it doesn't exist yet.
+pub(crate) fn init_fixed_update_pipeline(world: &World) {
+    let pipeline = world.component::<FixedUpdate>();
+    if pipeline.has(flecs::pipeline::Pipeline::id()) {
+        return;
+    }
+    world
+        .pipeline_type::<FixedUpdate>()
+        .with(flecs::system::System::id())
+        .with((flecs::DependsOn::ID, FixedUpdate::id()))
+        .without(flecs::Disabled::ID)
+        .build();
+}
+
+/// Runs the fixed steps of a frame. Invoked by `ecs_rust_progress` before the pipeline of the
+/// world runs.
+#[flecs_ecs_derive::extern_abi]
+pub(crate) fn run_fixed_steps(world: *mut sys::ecs_world_t, delta_time: FTime, _ctx: *mut c_void) {
+    // SAFETY: flecs passes the world that is progressing.
+    let world = unsafe { WorldRef::from_ptr(world) };
+    let Some(fixed) = world.try_get::<&mut FixedTime>(|fixed| {
+        fixed.advance(delta_time);
+        *fixed
+    }) else {
+        return;
+    };
+
+    init_fixed_update_pipeline(&world);
+    for _ in 0..fixed.steps {
+        world.run_pipeline_time(FixedUpdate::id(), fixed.step);
+    }
+    world.set(FixedAlpha(fixed.alpha()));
+}
@@ -1,7 +1,13 @@
 //! Pipelines order and schedule systems for execution.
 
+mod fixed_update;
+pub use fixed_update::*;
 mod pipeline_builder;
 pub use pipeline_builder::*;
+mod schedule;
+pub use schedule::*;
+mod system_order;
+pub(crate) use system_order::*;
 
 use core::ops::{Deref, DerefMut};
 
@@ -87,4 +93,24 @@ where
     pub fn entity(&self) -> EntityView<'a> {
         self.entity
     }
+
+    /// Returns the systems the pipeline runs, in order, and the sync points between them.
+    ///
+    /// Builds the schedule like the next world update would.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Schedule`] if the sync points could not be matched with the operations of
+    /// the pipeline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is called while a frame is in progress, such as from a system.
+    ///
+    /// # See also
+    ///
+    /// * [`World::pipeline_schedule()`]
+    pub fn schedule(&self) -> Result<PipelineSchedule<'a>, FlecsError> {
+        PipelineSchedule::new(self.entity.world(), self.entity.id)
+    }
 }
@@ -1,6 +1,6 @@
 //! Pipeline builder used to configure and build Pipelines.
 
-use super::Pipeline;
+use super::{Pipeline, order_pipeline_query};
 use crate::core::internals::*;
 use crate::core::*;
 use crate::sys;
@@ -9,6 +9,11 @@ use crate::sys;
 ///
 /// Pipelines order and schedule systems for execution.
 ///
+/// Unless the query has its own order, the pipeline orders the systems of a phase by the
+/// constraints declared with [`SystemBuilder::before`](crate::addons::system::SystemBuilder::before),
+/// [`SystemBuilder::after`](crate::addons::system::SystemBuilder::after) and
+/// [`SystemBuilder::in_set`](crate::addons::system::SystemBuilder::in_set).
+///
 /// These are typically constructed via [`World::pipeline()`].
 pub struct PipelineBuilder<'a, T>
 where
@@ -155,6 +160,9 @@ where
     type BuiltType = Pipeline<'a, T>;
 
     fn build(&mut self) -> Self::BuiltType {
+        if self.desc.query.expr.is_null() {
+            order_pipeline_query(&mut self.desc.query);
+        }
         let pipeline = Pipeline::<T>::new(self.world(), self.desc);
         for s in self.term_builder.str_ptrs_to_free.iter_mut() {
             unsafe { core::mem::ManuallyDrop::drop(s) };
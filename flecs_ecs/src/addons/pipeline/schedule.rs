@@ -0,0 +1,576 @@
+//! The schedule of a pipeline, with an export to Graphviz DOT and JSON.
+
+use core::fmt::Write;
+
+use crate::core::*;
+use crate::sys;
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+/// The systems that a pipeline runs, in order.
+///
+/// The systems are grouped in stages. Commands that systems enqueue are merged at the end of
+/// each stage, in a sync point. A new stage starts when a system needs the result of those
+/// commands, or when it runs with a different threading or staging mode than the system
+/// before it. Systems that match no entities are not part of the schedule.
+///
+/// Returned by [`Pipeline::schedule`](super::Pipeline::schedule) and
+/// [`World::pipeline_schedule`].
+#[derive(Debug, Clone)]
+pub struct PipelineSchedule<'a> {
+    world: WorldRef<'a>,
+    pipeline: Entity,
+    stages: Vec<ScheduleStage>,
+}
+
+/// A run of systems in a [`PipelineSchedule`] without a sync point in between.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleStage {
+    multi_threaded: bool,
+    immediate: bool,
+    sync_reason: Option<SyncReason>,
+    systems: Vec<ScheduledSystem>,
+}
+
+/// A system in a [`PipelineSchedule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledSystem {
+    entity: Entity,
+    phase: Option<Entity>,
+    multi_threaded: bool,
+    immediate: bool,
+    reads: Vec<Id>,
+    writes: Vec<Id>,
+}
+
+/// Why a sync point was inserted before a [`ScheduleStage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncReason {
+    /// A system accesses components that systems before it wrote with commands. This is the
+    /// first system of the stage, or a system that matches no entities and is not part of
+    /// the schedule.
+    Staged(Vec<Id>),
+    /// The first system of the stage switches between single and multithreaded.
+    MultiThreaded,
+    /// The first system of the stage runs in immediate mode, or leaves it.
+    Immediate,
+}
+
+impl<'a> PipelineSchedule<'a> {
+    /// Build the schedule of `pipeline`.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Schedule`] if `pipeline` is not a pipeline, or if the sync points could
+    /// not be matched with the operations of the pipeline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a frame is in progress.
+    pub(crate) fn new(world: impl WorldProvider<'a>, pipeline: Entity) -> Result<Self, FlecsError> {
+        let world = world.world();
+        // SAFETY: the world is valid.
+        let flags = unsafe { sys::ecs_world_get_flags(world.world_ptr()) };
+        assert!(
+            flags & (sys::EcsWorldFrameInProgress | sys::EcsWorldReadonly) == 0,
+            "cannot build a pipeline schedule while a frame is in progress"
+        );
+
+        let mut ops: *mut sys::ecs_rust_pipeline_op_t = core::ptr::null_mut();
+        let mut systems: *mut sys::ecs_entity_t = core::ptr::null_mut();
+        let mut sync_ids: *mut sys::ecs_id_t = core::ptr::null_mut();
+        let (mut op_count, mut system_count, mut sync_id_count) = (0, 0, 0);
+
+        // SAFETY: the world is valid, and the out parameters are valid for writes.
+        let result = unsafe {
+            sys::ecs_rust_pipeline_schedule(
+                world.world_ptr_mut(),
+                *pipeline,
+                &mut ops,
+                &mut op_count,
+                &mut systems,
+                &mut system_count,
+                &mut sync_ids,
+                &mut sync_id_count,
+            )
+        };
+        match result {
+            0 => {}
+            -1 => {
+                return Err(FlecsError::Schedule {
+                    pipeline,
+                    code: FlecsErrorCode::InvalidParameter,
+                    message: alloc::format!("entity {pipeline} is not a pipeline"),
+                });
+            }
+            _ => {
+                return Err(FlecsError::Schedule {
+                    pipeline,
+                    code: FlecsErrorCode::InternalError,
+                    message: "the sync points don't match the operations of the pipeline".into(),
+                });
+            }
+        }
+
+        // SAFETY: the arrays hold the number of elements that was returned, or are null if
+        // they are empty.
+        let (op_slice, system_slice, sync_id_slice) = unsafe {
+            (
+                slice_or_empty(ops, op_count),
+                slice_or_empty(systems, system_count),
+                slice_or_empty(sync_ids, sync_id_count),
+            )
+        };
+
+        let stages = op_slice
+            .iter()
+            .map(|op| {
+                let start = op.offset as usize;
+                let end = start + op.count as usize;
+                let systems = system_slice[start..end]
+                    .iter()
+                    .map(|&system| ScheduledSystem::new(world, Entity(system)))
+                    .collect();
+                ScheduleStage {
+                    multi_threaded: op.multi_threaded,
+                    immediate: op.immediate,
+                    sync_reason: sync_reason(op, sync_id_slice),
+                    systems,
+                }
+            })
+            .collect();
+
+        // SAFETY: the arrays were allocated by flecs, and are not used anymore.
+        unsafe {
+            let free = sys::ecs_os_api.free_.expect("os api is missing");
+            free(ops as *mut _);
+            free(systems as *mut _);
+            free(sync_ids as *mut _);
+        }
+
+        Ok(PipelineSchedule {
+            world,
+            pipeline,
+            stages,
+        })
+    }
+
+    /// Returns the pipeline.
+    pub fn pipeline(&self) -> EntityView<'a> {
+        self.pipeline.entity_view(self.world)
+    }
+
+    /// Returns the stages of the schedule, in the order they run.
+    pub fn stages(&self) -> &[ScheduleStage] {
+        &self.stages
+    }
+
+    /// Returns the systems of the schedule, in the order they run.
+    pub fn systems(&self) -> impl Iterator<Item = &ScheduledSystem> {
+        self.stages.iter().flat_map(|stage| stage.systems.iter())
+    }
+
+    /// Returns the number of sync points between the stages of the schedule.
+    pub fn sync_point_count(&self) -> usize {
+        self.stages.len().saturating_sub(1)
+    }
+
+    /// Export the schedule as a Graphviz DOT graph.
+    ///
+    /// Each stage is a cluster of systems connected in the order they run, labeled with the
+    /// phase and the components each system reads and writes. Stages are connected through a
+    /// node for the sync point between them, labeled with the reason it was inserted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// world.system_named::<&mut Position>("Move").each(|_| {});
+    ///
+    /// let dot = world.pipeline_schedule(world.get_pipeline()).unwrap().to_dot();
+    /// assert!(dot.starts_with("digraph"));
+    /// assert!(dot.contains("Move"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(
+            dot,
+            "digraph \"{}\" {{",
+            escape_dot(&self.name(*self.pipeline))
+        );
+        let _ = writeln!(dot, "    node [shape=box];");
+
+        let mut previous: Option<String> = None;
+        for (index, stage) in self.stages.iter().enumerate() {
+            if let Some(reason) = &stage.sync_reason {
+                let node = alloc::format!("sync_{index}");
+                let _ = writeln!(
+                    dot,
+                    "    {node} [shape=diamond, label=\"sync\\n{}\"];",
+                    escape_dot(&self.reason_label(reason))
+                );
+                if let Some(previous) = &previous {
+                    let _ = writeln!(dot, "    {previous} -> {node};");
+                }
+                previous = Some(node);
+            }
+
+            let mut label = alloc::format!("stage {index}");
+            if stage.multi_threaded {
+                label.push_str("\\nmulti_threaded");
+            }
+            if stage.immediate {
+                label.push_str("\\nimmediate");
+            }
+            let _ = writeln!(dot, "    subgraph cluster_{index} {{");
+            let _ = writeln!(dot, "        label=\"{label}\";");
+            for system in &stage.systems {
+                let mut label = escape_dot(&self.name(*system.entity));
+                if let Some(phase) = system.phase {
+                    let _ = write!(label, "\\nphase: {}", escape_dot(&self.name(*phase)));
+                }
+                if !system.reads.is_empty() {
+                    let _ = write!(
+                        label,
+                        "\\nreads: {}",
+                        escape_dot(&self.names(&system.reads))
+                    );
+                }
+                if !system.writes.is_empty() {
+                    let _ = write!(
+                        label,
+                        "\\nwrites: {}",
+                        escape_dot(&self.names(&system.writes))
+                    );
+                }
+                let _ = writeln!(dot, "        system_{} [label=\"{label}\"];", system.entity);
+            }
+            let _ = writeln!(dot, "    }}");
+
+            for system in &stage.systems {
+                let node = alloc::format!("system_{}", system.entity);
+                if let Some(previous) = &previous {
+                    let _ = writeln!(dot, "    {previous} -> {node};");
+                }
+                previous = Some(node);
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export the schedule as JSON.
+    ///
+    /// The output has the pipeline and the list of stages, each with its modes, the reason of
+    /// the sync point before it and its systems. Entities and components are written as paths,
+    /// so the output can be compared between runs of the application.
+    ///
+    /// ```json
+    /// {"pipeline":"flecs.pipeline.BuiltinPipeline","stages":[{"multi_threaded":false,
+    ///  "immediate":false,"sync":null,"systems":[{"name":"Move",
+    ///  "phase":"flecs.pipeline.OnUpdate","multi_threaded":false,"immediate":false,
+    ///  "reads":["Position"],"writes":["Position"]}]}]}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        let _ = write!(
+            json,
+            "{{\"pipeline\":\"{}\",\"stages\":[",
+            escape_json(&self.name(*self.pipeline))
+        );
+        for (index, stage) in self.stages.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "{{\"multi_threaded\":{},\"immediate\":{},\"sync\":",
+                stage.multi_threaded, stage.immediate
+            );
+            match &stage.sync_reason {
+                None => json.push_str("null"),
+                Some(SyncReason::Staged(ids)) => {
+                    json.push_str("{\"reason\":\"staged\",\"components\":");
+                    self.write_json_names(&mut json, ids);
+                    json.push('}');
+                }
+                Some(SyncReason::MultiThreaded) => {
+                    json.push_str("{\"reason\":\"multi_threaded\"}");
+                }
+                Some(SyncReason::Immediate) => json.push_str("{\"reason\":\"immediate\"}"),
+            }
+            json.push_str(",\"systems\":[");
+            for (index, system) in stage.systems.iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                let _ = write!(
+                    json,
+                    "{{\"name\":\"{}\",\"phase\":",
+                    escape_json(&self.name(*system.entity))
+                );
+                match system.phase {
+                    Some(phase) => {
+                        let _ = write!(json, "\"{}\"", escape_json(&self.name(*phase)));
+                    }
+                    None => json.push_str("null"),
+                }
+                let _ = write!(
+                    json,
+                    ",\"multi_threaded\":{},\"immediate\":{},\"reads\":",
+                    system.multi_threaded, system.immediate
+                );
+                self.write_json_names(&mut json, &system.reads);
+                json.push_str(",\"writes\":");
+                self.write_json_names(&mut json, &system.writes);
+                json.push('}');
+            }
+            json.push_str("]}");
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Returns the path of an entity, or the string representation of an id.
+    fn name(&self, id: u64) -> String {
+        id_str(self.world.world_ptr(), id)
+    }
+
+    fn names(&self, ids: &[Id]) -> String {
+        let names: Vec<String> = ids.iter().map(|id| self.name(**id)).collect();
+        names.join(", ")
+    }
+
+    fn reason_label(&self, reason: &SyncReason) -> String {
+        match reason {
+            SyncReason::Staged(ids) if ids.is_empty() => "staged".into(),
+            SyncReason::Staged(ids) => alloc::format!("staged: {}", self.names(ids)),
+            SyncReason::MultiThreaded => "multi_threaded".into(),
+            SyncReason::Immediate => "immediate".into(),
+        }
+    }
+
+    fn write_json_names(&self, json: &mut String, ids: &[Id]) {
+        json.push('[');
+        for (index, id) in ids.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "\"{}\"", escape_json(&self.name(**id)));
+        }
+        json.push(']');
+    }
+}
+
+impl ScheduleStage {
+    /// Returns the systems of the stage, in the order they run.
+    pub fn systems(&self) -> &[ScheduledSystem] {
+        &self.systems
+    }
+
+    /// Returns whether the systems of the stage run on multiple threads.
+    pub fn multi_threaded(&self) -> bool {
+        self.multi_threaded
+    }
+
+    /// Returns whether the systems of the stage run in immediate mode.
+    pub fn immediate(&self) -> bool {
+        self.immediate
+    }
+
+    /// Returns why a sync point was inserted before the stage, or `None` for the first stage.
+    pub fn sync_reason(&self) -> Option<&SyncReason> {
+        self.sync_reason.as_ref()
+    }
+}
+
+impl ScheduledSystem {
+    fn new(world: WorldRef<'_>, entity: Entity) -> Self {
+        let mut phase = None;
+        entity.entity_view(world).each_component(|id| {
+            if phase.is_none() && !id.is_pair() && id.entity_view().has(flecs::pipeline::Phase) {
+                phase = Some(Entity(*id.id()));
+            }
+        });
+
+        // SAFETY: the entity is a system of the pipeline.
+        let system = unsafe { &*sys::ecs_system_get(world.world_ptr(), *entity) };
+        let (mut reads, mut writes) = (Vec::new(), Vec::new());
+        for term in system_terms(system) {
+            let (read, write) = term_access(term);
+            let id = Id(term.id);
+            if read && !reads.contains(&id) {
+                reads.push(id);
+            }
+            if write && !writes.contains(&id) {
+                writes.push(id);
+            }
+        }
+
+        ScheduledSystem {
+            entity,
+            phase,
+            multi_threaded: system.multi_threaded,
+            immediate: system.immediate,
+            reads,
+            writes,
+        }
+    }
+
+    /// Returns the system.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns the phase of the system, if it has one.
+    pub fn phase(&self) -> Option<Entity> {
+        self.phase
+    }
+
+    /// Returns whether the system runs on multiple threads.
+    pub fn multi_threaded(&self) -> bool {
+        self.multi_threaded
+    }
+
+    /// Returns whether the system runs in immediate mode.
+    pub fn immediate(&self) -> bool {
+        self.immediate
+    }
+
+    /// Returns the components the system reads, from the access modifiers of its query.
+    ///
+    /// Terms without a modifier are read and written when they match the entities of the
+    /// system, read when they match another entity, and not accessed when they have no
+    /// source.
+    pub fn reads(&self) -> &[Id] {
+        &self.reads
+    }
+
+    /// Returns the components the system writes, from the access modifiers of its query.
+    ///
+    /// A `Not` term only counts as a write when it is marked `Out`, as it signals that the
+    /// system adds the component.
+    pub fn writes(&self) -> &[Id] {
+        &self.writes
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be null if `len` is zero, and point to `len` elements otherwise.
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: i32) -> &'a [T] {
+    if ptr.is_null() || len <= 0 {
+        &[]
+    } else {
+        // SAFETY: guaranteed by the caller.
+        unsafe { core::slice::from_raw_parts(ptr, len as usize) }
+    }
+}
+
+/// Returns why a sync point was inserted before an operation of the schedule.
+fn sync_reason(op: &sys::ecs_rust_pipeline_op_t, sync_ids: &[sys::ecs_id_t]) -> Option<SyncReason> {
+    match op.sync_reason {
+        sys::ecs_rust_sync_reason_t_EcsRustSyncStaged => {
+            let start = op.sync_id_offset as usize;
+            let end = start + op.sync_id_count as usize;
+            Some(SyncReason::Staged(
+                sync_ids[start..end].iter().map(|&id| Id(id)).collect(),
+            ))
+        }
+        sys::ecs_rust_sync_reason_t_EcsRustSyncMultiThreaded => Some(SyncReason::MultiThreaded),
+        sys::ecs_rust_sync_reason_t_EcsRustSyncImmediate => Some(SyncReason::Immediate),
+        _ => None,
+    }
+}
+
+fn system_terms(system: &sys::ecs_system_t) -> &[sys::ecs_term_t] {
+    // SAFETY: a system always has a query, which owns its terms.
+    unsafe {
+        let query = &*system.query;
+        slice_or_empty(query.terms, query.term_count as i32)
+    }
+}
+
+/// Where a term gets its data from, as interpreted by the pipeline.
+struct TermSource {
+    from_any: bool,
+    is_shared: bool,
+}
+
+impl TermSource {
+    fn new(term: &sys::ecs_term_t) -> Self {
+        // SAFETY: the term is initialized by its query.
+        let (from_any, from_this) =
+            unsafe { (sys::ecs_term_match_0(term), sys::ecs_term_match_this(term)) };
+        let is_self = term.src.id & sys::EcsSelf as u64 != 0;
+        TermSource {
+            from_any,
+            is_shared: !from_any && (!from_this || !is_self),
+        }
+    }
+
+    /// Resolves the default access modifier of a term. Returns `None` for terms without a
+    /// source, which only pass an id to the system.
+    fn resolve(&self, inout: i16) -> Option<i16> {
+        if inout as sys::ecs_inout_kind_t != sys::ecs_inout_kind_t_EcsInOutDefault {
+            Some(inout)
+        } else if self.from_any {
+            None
+        } else if self.is_shared {
+            Some(sys::ecs_inout_kind_t_EcsIn as i16)
+        } else {
+            Some(sys::ecs_inout_kind_t_EcsInOut as i16)
+        }
+    }
+}
+
+/// Returns whether a term reads and writes its component.
+fn term_access(term: &sys::ecs_term_t) -> (bool, bool) {
+    let Some(inout) = TermSource::new(term).resolve(term.inout) else {
+        return (false, false);
+    };
+    let inout = inout as sys::ecs_inout_kind_t;
+    let is_not = term.oper as sys::ecs_oper_kind_t == sys::ecs_oper_kind_t_EcsNot;
+    if is_not {
+        return (false, inout == sys::ecs_inout_kind_t_EcsOut);
+    }
+    match inout {
+        sys::ecs_inout_kind_t_EcsIn => (true, false),
+        sys::ecs_inout_kind_t_EcsOut => (false, true),
+        sys::ecs_inout_kind_t_EcsInOut => (true, true),
+        _ => (false, false),
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
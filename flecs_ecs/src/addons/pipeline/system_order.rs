@@ -0,0 +1,320 @@
+//! Ordering constraints between systems, declared with [`SystemBuilder::before`],
+//! [`SystemBuilder::after`] and [`SystemBuilder::in_set`].
+//!
+//! Pipelines run their systems phase by phase, and by entity id within a phase. Pipelines
+//! that respect the constraints order the systems of a phase with [`compare_systems`], which
+//! puts the systems in a topological order of the constraints, and keeps systems that are not
+//! constrained in entity id order.
+
+use core::cmp::{Ordering, Reverse};
+use core::ffi::{c_int, c_void};
+
+use crate::core::*;
+use crate::sys;
+
+extern crate alloc;
+use alloc::{collections::BinaryHeap, format, string::String, vec::Vec};
+
+use hashbrown::HashMap;
+
+#[cfg(doc)]
+use crate::addons::system::SystemBuilder;
+
+/// A constraint declared on a system before it is built.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum OrderConstraint {
+    Before(Entity),
+    After(Entity),
+    InSet(Entity),
+}
+
+/// The ordering constraints of a world.
+#[derive(Default)]
+pub(crate) struct SystemOrder {
+    /// `(first, second)` pairs, where each side is a system or a set.
+    edges: Vec<(Entity, Entity)>,
+    /// `(set, system)` pairs.
+    members: Vec<(Entity, Entity)>,
+    sets: Vec<Entity>,
+    /// Position of each constrained system in the topological order.
+    positions: HashMap<Entity, usize>,
+    /// For each position, the largest entity id up to and including it.
+    max_ids: Vec<Entity>,
+    builtin_pipeline_ordered: bool,
+}
+
+impl SystemOrder {
+    pub(crate) fn add_set(&mut self, set: Entity) {
+        if !self.sets.contains(&set) {
+            self.sets.push(set);
+        }
+    }
+
+    /// Adds the constraints of `system`, and recomputes the order. Nothing is added if the
+    /// constraints form a cycle, in which case the cycle is returned as an error.
+    pub(crate) fn add(
+        &mut self,
+        world: WorldRef<'_>,
+        system: Entity,
+        constraints: &[OrderConstraint],
+    ) -> Result<(), String> {
+        let (edges, members) = (self.edges.len(), self.members.len());
+        for constraint in constraints {
+            match *constraint {
+                OrderConstraint::Before(other) => self.edges.push((system, other)),
+                OrderConstraint::After(other) => self.edges.push((other, system)),
+                OrderConstraint::InSet(set) => {
+                    self.add_set(set);
+                    self.members.push((set, system));
+                }
+            }
+        }
+
+        if let Err(cycle) = self.update(world) {
+            self.edges.truncate(edges);
+            self.members.truncate(members);
+            return Err(cycle);
+        }
+        Ok(())
+    }
+
+    fn expand(&self, world: WorldRef<'_>, entity: Entity) -> Vec<Entity> {
+        if self.sets.contains(&entity) {
+            self.members
+                .iter()
+                .filter(|(set, system)| *set == entity && world.is_alive(*system))
+                .map(|(_, system)| *system)
+                .collect()
+        } else if world.is_alive(entity) {
+            alloc::vec![entity]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Sorts the constrained systems with Kahn's algorithm, taking the smallest entity id
+    /// first, so that systems keep the order they were created in where possible.
+    fn update(&mut self, world: WorldRef<'_>) -> Result<(), String> {
+        let mut successors: HashMap<Entity, Vec<Entity>> = HashMap::new();
+        let mut in_degree: HashMap<Entity, usize> = HashMap::new();
+        for &(first, second) in &self.edges {
+            let firsts = self.expand(world, first);
+            let seconds = self.expand(world, second);
+            for &first in &firsts {
+                for &second in &seconds {
+                    in_degree.entry(first).or_default();
+                    *in_degree.entry(second).or_default() += 1;
+                    successors.entry(first).or_default().push(second);
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<Entity>> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(system, _)| Reverse(*system))
+            .collect();
+        let mut order = Vec::with_capacity(in_degree.len());
+        let mut remaining = in_degree.clone();
+        while let Some(Reverse(system)) = ready.pop() {
+            order.push(system);
+            for next in successors.get(&system).into_iter().flatten() {
+                let degree = remaining.get_mut(next).expect("successor has an in-degree");
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(Reverse(*next));
+                }
+            }
+        }
+
+        if order.len() < in_degree.len() {
+            let blocked: Vec<Entity> = remaining
+                .iter()
+                .filter(|(_, degree)| **degree > 0)
+                .map(|(system, _)| *system)
+                .collect();
+            return Err(describe_cycle(world, &successors, &blocked));
+        }
+
+        self.positions = order
+            .iter()
+            .enumerate()
+            .map(|(position, system)| (*system, position))
+            .collect();
+        self.max_ids = order
+            .iter()
+            .scan(Entity(0), |max, system| {
+                *max = (*max).max(*system);
+                Some(*max)
+            })
+            .collect();
+
+        // The pipeline only sorts tables that changed, so flag the tables of the constrained
+        // systems.
+        for system in order {
+            // SAFETY: the system is alive.
+            unsafe {
+                sys::ecs_modified_id(
+                    world.world_ptr_mut(),
+                    *system,
+                    ecs_pair(ECS_POLY, ECS_SYSTEM),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares two systems of the same phase.
+    fn compare(&self, e1: Entity, e2: Entity) -> Ordering {
+        match (self.positions.get(&e1), self.positions.get(&e2)) {
+            (Some(p1), Some(p2)) => p1.cmp(p2),
+            (Some(p1), None) => self.compare_unconstrained(*p1, e2).reverse(),
+            (None, Some(p2)) => self.compare_unconstrained(*p2, e1),
+            (None, None) => e1.cmp(&e2),
+        }
+    }
+
+    /// Compares a system without constraints with the constrained system at `position`. Kahn's
+    /// algorithm would take the unconstrained system as soon as it has the smallest id of the
+    /// systems that are ready, which is before the first system with a larger id.
+    fn compare_unconstrained(&self, position: usize, system: Entity) -> Ordering {
+        if self.max_ids[position] > system {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+fn describe_cycle(
+    world: WorldRef<'_>,
+    successors: &HashMap<Entity, Vec<Entity>>,
+    blocked: &[Entity],
+) -> String {
+    // Every blocked system has a blocked predecessor, so walking back from any of them ends
+    // in a cycle.
+    let predecessor = |system: Entity| {
+        successors
+            .iter()
+            .filter(|(first, _)| blocked.contains(first))
+            .find(|(_, seconds)| seconds.contains(&system))
+            .map(|(first, _)| *first)
+            .expect("blocked system has a blocked predecessor")
+    };
+
+    let mut path = alloc::vec![*blocked.iter().min().expect("cycle is not empty")];
+    let start = loop {
+        let previous = predecessor(*path.last().unwrap());
+        if let Some(index) = path.iter().position(|system| *system == previous) {
+            break index;
+        }
+        path.push(previous);
+    };
+    let mut cycle: Vec<Entity> = path[start..].iter().rev().copied().collect();
+    // start with the oldest system, so the message doesn't depend on the walk
+    let oldest = (0..cycle.len()).min_by_key(|index| cycle[*index]).unwrap();
+    cycle.rotate_left(oldest);
+    cycle.push(cycle[0]);
+
+    let names: Vec<String> = cycle
+        .iter()
+        .map(|system| {
+            system
+                .entity_view(world)
+                .get_name()
+                .unwrap_or_else(|| format!("#{}", **system))
+        })
+        .collect();
+    format!("system ordering cycle: {}", names.join(" -> "))
+}
+
+/// Order callback of pipelines that respect ordering constraints. Ordered by the
+/// `(flecs.core.Poly, flecs.system.System)` component of the systems.
+#[flecs_ecs_derive::extern_abi]
+pub(crate) fn compare_systems(
+    e1: sys::ecs_entity_t,
+    ptr1: *const c_void,
+    e2: sys::ecs_entity_t,
+    _ptr2: *const c_void,
+) -> c_int {
+    // SAFETY: the pipeline passes the poly of a system, whose query is created with the world.
+    let world = unsafe {
+        let system = (*(ptr1 as *const sys::EcsPoly)).poly as *const sys::ecs_system_t;
+        let world = sys::ecs_get_world((*(*system).query).world as *const c_void);
+        WorldRef::from_ptr(world as *mut sys::ecs_world_t)
+    };
+    match world
+        .world_ctx()
+        .system_order
+        .borrow()
+        .compare(Entity(e1), Entity(e2))
+    {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// Adds the term and order callback that make a pipeline query respect ordering constraints,
+/// unless the query has its own order.
+pub(crate) fn order_pipeline_query(desc: &mut sys::ecs_query_desc_t) {
+    if desc.order_by != 0 || desc.order_by_callback.is_some() {
+        return;
+    }
+    let poly = ecs_pair(ECS_POLY, ECS_SYSTEM);
+    let count = desc
+        .terms
+        .iter()
+        .position(|term| term.id == 0 && term.first.id == 0 && term.first.name.is_null())
+        .expect("pipeline query has too many terms to order systems");
+    desc.terms[count] = sys::ecs_term_t {
+        id: poly,
+        inout: sys::ecs_inout_kind_t_EcsIn as i16,
+        ..Default::default()
+    };
+    desc.order_by = poly;
+    desc.order_by_callback = Some(compare_systems);
+}
+
+/// Makes the builtin pipeline respect ordering constraints, the first time a constraint is
+/// declared.
+pub(crate) fn order_builtin_pipeline(world: WorldRef<'_>) {
+    if world
+        .world_ctx()
+        .system_order
+        .borrow()
+        .builtin_pipeline_ordered
+    {
+        return;
+    }
+    assert!(
+        !world.is_readonly(),
+        "system ordering constraints cannot be declared while the world is progressing"
+    );
+
+    let pipeline = world.lookup("flecs::pipeline::BuiltinPipeline");
+    let mut desc = sys::ecs_pipeline_desc_t::default();
+    // SAFETY: the builtin pipeline has a query. Its terms are copied before the query is
+    // replaced.
+    unsafe {
+        let query = &*sys::ecs_query_get(world.world_ptr(), *pipeline.id());
+        for (index, term) in core::slice::from_raw_parts(query.terms, query.term_count as usize)
+            .iter()
+            .enumerate()
+        {
+            desc.query.terms[index] = *term;
+        }
+    }
+    order_pipeline_query(&mut desc.query);
+    // SAFETY: the world is not progressing, so the pipeline is not running.
+    let result = unsafe { sys::ecs_pipeline_update(world.world_ptr_mut(), *pipeline.id(), &desc) };
+    assert!(
+        result != 0,
+        "failed to order the systems of the builtin pipeline"
+    );
+    world
+        .world_ctx()
+        .system_order
+        .borrow_mut()
+        .builtin_pipeline_ordered = true;
+}
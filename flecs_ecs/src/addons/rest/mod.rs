@@ -0,0 +1,37 @@
+//! The REST addon serves the world over HTTP, for tools like the Flecs Explorer.
+//!
+//! Besides the builtin endpoints, applications can register their own routes with
+//! [`World::rest_route`](crate::core::World::rest_route). Requests can also be handled
+//! in-process with [`World::rest_request`](crate::core::World::rest_request), without
+//! opening a socket, which is useful for testing endpoints.
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//!
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! let world = World::new();
+//! world.entity_named("alice").add(Player);
+//! world.entity_named("bob").add(Player);
+//!
+//! world.rest_route("GET", "/game/players", |req| {
+//!     let count = req.world().query::<&Player>().build().count();
+//!     RestResponse::ok(format!("{{\"count\": {count}}}"))
+//! });
+//!
+//! let reply = world.rest_request("GET", "/game/players");
+//! assert_eq!(reply.code(), 200);
+//! assert_eq!(reply.body(), "{\"count\": 2}");
+//! ```
+//!
+//! # See also
+//!
+//! - [`RestRequest`] - A request received by a route
+//! - [`RestResponse`] - The reply of a route or an in-process request
+
+mod rest;
+mod world;
+pub use rest::*;
@@ -0,0 +1,250 @@
+use core::ffi::{CStr, c_char};
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+extern crate alloc;
+use alloc::{rc::Rc, string::String};
+
+type RouteHandler = Rc<dyn Fn(&RestRequest<'_>) -> RestResponse + 'static>;
+
+/// A route registered with [`World::rest_route`].
+pub(crate) struct RestRoute {
+    method: sys::ecs_http_method_t,
+    /// Path without the leading `/`. A trailing `*` matches any suffix.
+    path: String,
+    handler: RouteHandler,
+}
+
+impl RestRoute {
+    pub(crate) fn new(
+        method: &str,
+        path: &str,
+        handler: impl Fn(&RestRequest<'_>) -> RestResponse + 'static,
+    ) -> Self {
+        let method = parse_method(method)
+            .unwrap_or_else(|| panic!("unsupported HTTP method `{method}` for REST route"));
+        RestRoute {
+            method,
+            path: path.trim_start_matches('/').into(),
+            handler: Rc::new(handler),
+        }
+    }
+
+    fn matches(&self, method: sys::ecs_http_method_t, path: &str) -> bool {
+        if self.method != method {
+            return false;
+        }
+        match self.path.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => self.path == path,
+        }
+    }
+}
+
+pub(crate) fn parse_method(method: &str) -> Option<sys::ecs_http_method_t> {
+    let method = match method.to_ascii_uppercase().as_str() {
+        "GET" => sys::ecs_http_method_t_EcsHttpGet,
+        "POST" => sys::ecs_http_method_t_EcsHttpPost,
+        "PUT" => sys::ecs_http_method_t_EcsHttpPut,
+        "DELETE" => sys::ecs_http_method_t_EcsHttpDelete,
+        "OPTIONS" => sys::ecs_http_method_t_EcsHttpOptions,
+        _ => return None,
+    };
+    Some(method)
+}
+
+/// Invoked by the REST server for each request, before the builtin endpoints.
+pub(crate) unsafe extern "C-unwind" fn route_action(
+    world: *mut sys::ecs_world_t,
+    req: *const sys::ecs_http_request_t,
+    reply: *mut sys::ecs_http_reply_t,
+) -> bool {
+    // SAFETY: the REST server passes the world it was created for, and a valid request and
+    // reply.
+    let (world, req, reply) = unsafe { (WorldRef::from_ptr(world), &*req, &mut *reply) };
+    let request = RestRequest { world, req };
+
+    // The handler is cloned out of the route list, so that it can register routes itself.
+    let handler = world
+        .world_ctx()
+        .rest_routes
+        .borrow()
+        .iter()
+        .find(|route| route.matches(req.method, request.path()))
+        .map(|route| route.handler.clone());
+    let Some(handler) = handler else {
+        return false;
+    };
+
+    let response = handler(&request);
+    reply.code = response.code;
+    reply.content_type = response.content_type.as_ptr();
+    // SAFETY: the body buffer of the reply is initialized by the server.
+    unsafe {
+        sys::ecs_strbuf_appendstrn(
+            &mut reply.body,
+            response.body.as_ptr() as *const c_char,
+            response.body.len() as i32,
+        );
+    }
+    true
+}
+
+/// A request received by a route registered with [`World::rest_route`].
+pub struct RestRequest<'a> {
+    world: WorldRef<'a>,
+    req: &'a sys::ecs_http_request_t,
+}
+
+impl<'a> RestRequest<'a> {
+    /// Returns the world that is served.
+    pub fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+
+    /// Returns the method of the request, like `"GET"`.
+    pub fn method(&self) -> &'static str {
+        match self.req.method {
+            sys::ecs_http_method_t_EcsHttpGet => "GET",
+            sys::ecs_http_method_t_EcsHttpPost => "POST",
+            sys::ecs_http_method_t_EcsHttpPut => "PUT",
+            sys::ecs_http_method_t_EcsHttpDelete => "DELETE",
+            sys::ecs_http_method_t_EcsHttpOptions => "OPTIONS",
+            _ => "UNSUPPORTED",
+        }
+    }
+
+    /// Returns the path of the request, without the leading `/` and the query parameters.
+    pub fn path(&self) -> &'a str {
+        // SAFETY: the path is a null terminated string that lives as long as the request.
+        unsafe { to_str(self.req.path) }.unwrap_or("")
+    }
+
+    /// Returns the body of the request, if it has one.
+    pub fn body(&self) -> Option<&'a str> {
+        // SAFETY: the body is null or a null terminated string that lives as long as the request.
+        unsafe { to_str(self.req.body) }.filter(|body| !body.is_empty())
+    }
+
+    /// Returns the value of a query parameter.
+    pub fn param(&self, name: &str) -> Option<&'a str> {
+        let name = compact_str::format_compact!("{}\0", name);
+        // SAFETY: the name is null terminated, and the returned value lives as long as the
+        // request.
+        unsafe {
+            to_str(sys::ecs_http_get_param(
+                self.req,
+                name.as_ptr() as *const c_char,
+            ))
+        }
+    }
+
+    /// Returns the value of a header.
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        let name = compact_str::format_compact!("{}\0", name);
+        // SAFETY: the name is null terminated, and the returned value lives as long as the
+        // request.
+        unsafe {
+            to_str(sys::ecs_http_get_header(
+                self.req,
+                name.as_ptr() as *const c_char,
+            ))
+        }
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be null or point to a null terminated string that lives for `'a`.
+unsafe fn to_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        // SAFETY: guaranteed by the caller.
+        unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
+
+/// The reply to a REST request.
+///
+/// Returned by the handlers of routes registered with [`World::rest_route`], and by
+/// [`World::rest_request`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestResponse {
+    code: i32,
+    body: String,
+    content_type: &'static CStr,
+}
+
+impl RestResponse {
+    /// Create a reply with a status code and a JSON body.
+    pub fn new(code: i32, body: impl Into<String>) -> Self {
+        RestResponse {
+            code,
+            body: body.into(),
+            content_type: c"application/json",
+        }
+    }
+
+    /// Create a `200 OK` reply with a JSON body.
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self::new(200, body)
+    }
+
+    /// Create a `404 Not Found` reply without a body.
+    pub fn not_found() -> Self {
+        Self::new(404, "")
+    }
+
+    /// Set the content type of the reply. Defaults to `application/json`.
+    pub fn with_content_type(mut self, content_type: &'static CStr) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    /// Returns the status code.
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// Returns the body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the content type.
+    pub fn content_type(&self) -> &'static CStr {
+        self.content_type
+    }
+
+    /// Takes the reply produced by the REST server, and frees its buffers.
+    ///
+    /// # Safety
+    ///
+    /// The reply must be initialized, and its content type must be null or a static string.
+    pub(crate) unsafe fn from_reply(reply: &mut sys::ecs_http_reply_t) -> Self {
+        // SAFETY: the buffers of the reply are initialized, and the content type is static as
+        // guaranteed by the caller.
+        unsafe {
+            let body_ptr = sys::ecs_strbuf_get(&mut reply.body);
+            let body = to_str(body_ptr).map(String::from).unwrap_or_default();
+            if !body_ptr.is_null() {
+                sys::ecs_os_api.free_.expect("os api is missing")(body_ptr as *mut _);
+            }
+            sys::ecs_strbuf_reset(&mut reply.headers);
+
+            let content_type = if reply.content_type.is_null() {
+                c""
+            } else {
+                CStr::from_ptr(reply.content_type)
+            };
+
+            RestResponse {
+                code: reply.code,
+                body,
+                content_type,
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::{RestRequest, RestResponse, RestRoute, parse_method, route_action};
+use crate::core::*;
+use crate::sys;
+
+/// REST mixin implementation
+impl World {
+    /// Register a route that is served by the REST server of the world.
+    ///
+    /// Routes are matched on method and path before the builtin endpoints, in the order they
+    /// were registered. A path that ends with `*` matches any path that starts with the part
+    /// before it. The route is available to servers that are started before or after it is
+    /// registered, and to [`World::rest_request`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `method` is not one of `GET`, `POST`, `PUT`, `DELETE` or `OPTIONS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.rest_route("GET", "/game/greet", |req| {
+    ///     let name = req.param("name").unwrap_or("world");
+    ///     RestResponse::ok(format!("\"hello {name}\""))
+    /// });
+    ///
+    /// let reply = world.rest_request("GET", "/game/greet?name=flecs");
+    /// assert_eq!(reply.body(), "\"hello flecs\"");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`addons::rest`](crate::addons::rest)
+    pub fn rest_route(
+        &self,
+        method: &str,
+        path: &str,
+        handler: impl Fn(&RestRequest<'_>) -> RestResponse + 'static,
+    ) -> &Self {
+        let route = RestRoute::new(method, path, handler);
+        self.world_ctx().rest_routes.borrow_mut().push(route);
+        // SAFETY: the world is valid, and the action outlives it.
+        unsafe {
+            sys::ecs_rust_rest_enable_routes(self.world_ptr_mut(), Some(route_action));
+        }
+        self
+    }
+
+    /// Handle a REST request in-process, without starting a server or opening a socket.
+    ///
+    /// The request is served by the routes registered with [`World::rest_route`] and the
+    /// builtin endpoints, like `/entity/<path>` and `/query`. Requests that match no
+    /// endpoint reply with a `404` code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `method` is not one of `GET`, `POST`, `PUT`, `DELETE` or `OPTIONS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// world.entity_named("foo");
+    ///
+    /// let reply = world.rest_request("GET", "/entity/foo");
+    /// assert_eq!(reply.code(), 200);
+    /// assert!(reply.body().contains("\"name\":\"foo\""));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::rest_request_with_body`]
+    pub fn rest_request(&self, method: &str, path: &str) -> RestResponse {
+        self.rest_request_impl(method, path, None)
+    }
+
+    /// Handle a REST request with a body in-process.
+    ///
+    /// # See also
+    ///
+    /// * [`World::rest_request`]
+    pub fn rest_request_with_body(&self, method: &str, path: &str, body: &str) -> RestResponse {
+        self.rest_request_impl(method, path, Some(body))
+    }
+
+    fn rest_request_impl(&self, method: &str, path: &str, body: Option<&str>) -> RestResponse {
+        assert!(
+            parse_method(method).is_some(),
+            "unsupported HTTP method `{method}` for REST request"
+        );
+        let method = compact_str::format_compact!("{}\0", method.to_ascii_uppercase());
+        let path = compact_str::format_compact!("/{}\0", path.trim_start_matches('/'));
+        let body = body.map(|body| compact_str::format_compact!("{}\0", body));
+
+        let mut reply: sys::ecs_http_reply_t = unsafe { core::mem::zeroed() };
+        // SAFETY: the strings are null terminated and outlive the call, and the reply is
+        // initialized by the call.
+        unsafe {
+            sys::ecs_rust_rest_request(
+                self.world_ptr_mut(),
+                method.as_ptr() as *const _,
+                path.as_ptr() as *const _,
+                body.as_ref()
+                    .map_or(core::ptr::null(), |body| body.as_ptr() as *const _),
+                &mut reply,
+            );
+            RestResponse::from_reply(&mut reply)
+        }
+    }
+}
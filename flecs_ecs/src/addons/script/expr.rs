@@ -0,0 +1,164 @@
+use core::ffi::CStr;
+use core::fmt::{Debug, Display, Formatter};
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+use super::script_vars::read_script_value;
+use super::{Script, ScriptVars};
+
+extern crate alloc;
+use alloc::{borrow::ToOwned, string::String};
+
+/// Runs the expression `expr`, storing the result in `value`.
+///
+/// When `value` has no type, the result has the type of the expression and is allocated by flecs.
+pub(crate) fn run_expr(
+    world: WorldRef<'_>,
+    expr: &str,
+    value: &mut sys::ecs_value_t,
+    vars: Option<&ScriptVars<'_>>,
+) -> Result<(), FlecsError> {
+    let expr_c = compact_str::format_compact!("{}\0", expr);
+    let desc = sys::ecs_expr_eval_desc_t {
+        expr: expr_c.as_ptr() as *const _,
+        vars: vars.map_or(core::ptr::null(), ScriptVars::as_ptr),
+        type_: value.type_,
+        ..Default::default()
+    };
+
+    // SAFETY: the expression string outlives the call, and `value` either has no type or points
+    // to an initialized value of its type.
+    let (next, message) = capture_errors(|| unsafe {
+        sys::ecs_expr_run(
+            world.world_ptr_mut(),
+            expr_c.as_ptr() as *const _,
+            value,
+            &desc,
+        )
+    });
+
+    if next.is_null() {
+        Err(FlecsError::Expr {
+            expr: expr.to_owned(),
+            message,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// The result of evaluating an expression with [`World::eval_expr`].
+///
+/// The type of the value is the type of the expression, like [`f64`] for arithmetic on
+/// numbers or a component type for a component initializer. The value is freed when the
+/// [`DynamicValue`] is dropped.
+pub struct DynamicValue<'a> {
+    world: WorldRef<'a>,
+    value: sys::ecs_value_t,
+}
+
+impl Drop for DynamicValue<'_> {
+    fn drop(&mut self) {
+        if !self.value.ptr.is_null() {
+            // SAFETY: the value was allocated by flecs for its type.
+            unsafe {
+                sys::ecs_ptr_free(self.world.world_ptr_mut(), self.value.type_, self.value.ptr);
+            }
+        }
+    }
+}
+
+impl<'a> DynamicValue<'a> {
+    /// Takes ownership of a value allocated by flecs.
+    pub(crate) fn new(world: WorldRef<'a>, value: sys::ecs_value_t) -> Self {
+        DynamicValue { world, value }
+    }
+
+    /// Returns the type of the value.
+    pub fn type_id(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.value.type_)
+    }
+
+    /// Returns the raw flecs value.
+    pub fn as_raw(&self) -> &sys::ecs_value_t {
+        &self.value
+    }
+
+    /// Returns a copy of the value if it is of type `T`.
+    ///
+    /// String values can be read as a Rust [`String`].
+    pub fn get<T: ComponentId + Clone>(&self) -> Option<T> {
+        read_script_value(self.world, &self.value)
+    }
+
+    /// Returns the value as a float, if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        // SAFETY: the cursor points to the value, which is a number.
+        self.is_number()
+            .then(|| unsafe { sys::ecs_meta_get_float(&self.cursor()) })
+    }
+
+    /// Returns the value as a signed integer, if it is a number.
+    ///
+    /// Floats are truncated.
+    pub fn as_i64(&self) -> Option<i64> {
+        // SAFETY: the cursor points to the value, which is a number.
+        self.is_number()
+            .then(|| unsafe { sys::ecs_meta_get_int(&self.cursor()) })
+    }
+
+    /// Serializes the value to a flecs expression.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_ptr_to_expr`
+    pub fn to_expr(&self) -> String {
+        // SAFETY: the value is an initialized value of its type.
+        unsafe { Script::to_expr(self.world, Entity::new(self.value.type_), self.value.ptr) }
+    }
+
+    fn cursor(&self) -> sys::ecs_meta_cursor_t {
+        // SAFETY: the value is an initialized value of its type.
+        unsafe { sys::ecs_meta_cursor(self.world.world_ptr(), self.value.type_, self.value.ptr) }
+    }
+
+    fn is_number(&self) -> bool {
+        // SAFETY: the type is a valid entity.
+        let primitive = unsafe {
+            sys::ecs_get_id(
+                self.world.world_ptr(),
+                self.value.type_,
+                sys::FLECS_IDEcsPrimitiveID_,
+            ) as *const sys::EcsPrimitive
+        };
+        // SAFETY: the pointer is either null or points to the primitive component.
+        unsafe { primitive.as_ref() }.is_some_and(|primitive| {
+            (sys::ecs_primitive_kind_t_EcsU8..=sys::ecs_primitive_kind_t_EcsIPtr)
+                .contains(&primitive.kind)
+        })
+    }
+}
+
+impl Display for DynamicValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_expr())
+    }
+}
+
+impl Debug for DynamicValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // SAFETY: the type is a valid entity.
+        let type_name = unsafe { sys::ecs_get_name(self.world.world_ptr(), self.value.type_) };
+        let type_name = if type_name.is_null() {
+            "".into()
+        } else {
+            // SAFETY: flecs returned a valid, null terminated name.
+            unsafe { CStr::from_ptr(type_name) }.to_string_lossy()
+        };
+        f.debug_struct("DynamicValue")
+            .field("type", &type_name)
+            .field("value", &self.to_expr())
+            .finish()
+    }
+}
@@ -0,0 +1,21 @@
+use crate::addons::module::Module;
+use crate::core::World;
+use flecs_ecs_derive::Component;
+
+/// Module with the math functions and constants of flecs script, such as `sqrt`, `lerp` and
+/// `PI`.
+///
+/// After importing the module, scripts can use the functions with `using flecs.script.math`.
+///
+/// # See also
+///
+/// * [`World::script_function`] - Register custom script functions
+/// * C API: `FlecsScriptMathImport`
+#[derive(Clone, Copy, Component, Default)]
+pub struct ScriptMath;
+
+impl Module for ScriptMath {
+    fn module(world: &World) {
+        unsafe { flecs_ecs_sys::FlecsScriptMathImport(world.ptr_mut()) };
+    }
+}
@@ -9,7 +9,9 @@
 //! - **Declarative Syntax**: Define entities and components using a simple text format
 //! - **Runtime Loading**: Load and execute scripts at runtime
 //! - **Template Support**: Use templates and prefabs in scripts
-//! - **Expression Evaluation**: Evaluate expressions within scripts
+//! - **Expression Evaluation**: Evaluate expressions within scripts, or directly from Rust
+//! - **Script Functions**: Call Rust functions and methods from scripts
+//! - **Hot Reloading**: Reload scripts when their files change with a [`ScriptWatcher`]
 //!
 //! # Example
 //!
@@ -49,13 +51,27 @@
 //! - [Flecs Script Manual](https://www.flecs.dev/flecs/md_docs_2FlecsScript.html)
 //! - [Flecs Script Tutorial](https://www.flecs.dev/flecs/flecsscripttutorial.html)
 
+mod expr;
+#[cfg(feature = "flecs_script_math")]
+mod math;
 mod script_builder;
 mod script_entity_view;
+mod script_function;
+mod script_vars;
+#[cfg(all(feature = "std", feature = "flecs_system"))]
+mod script_watcher;
 mod unmanaged_script;
 mod world;
 
+pub use expr::*;
+#[cfg(feature = "flecs_script_math")]
+pub use math::*;
 pub use script_builder::*;
 pub use script_entity_view::*;
+pub use script_function::*;
+pub use script_vars::*;
+#[cfg(all(feature = "std", feature = "flecs_system"))]
+pub use script_watcher::*;
 pub use unmanaged_script::*;
 
 #[cfg(feature = "std")]
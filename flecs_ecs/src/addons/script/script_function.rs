@@ -0,0 +1,229 @@
+use core::ffi::c_void;
+use core::marker::PhantomData;
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+extern crate alloc;
+use alloc::{boxed::Box, vec::Vec};
+
+use compact_str::CompactString;
+
+type ScriptCallback<R> = Box<dyn Fn(&ScriptFunctionArgs<'_>) -> R + 'static>;
+
+/// Arguments passed by a script to a function registered with
+/// [`World::script_function`] or [`World::script_method`].
+///
+/// Arguments are converted by flecs to the types declared with
+/// [`ScriptFunctionBuilder::arg`] before the function is called. For methods, the instance the
+/// method is invoked on is passed as the first argument.
+pub struct ScriptFunctionArgs<'a> {
+    world: WorldRef<'a>,
+    function: Entity,
+    args: &'a [sys::ecs_value_t],
+}
+
+impl<'a> ScriptFunctionArgs<'a> {
+    /// The world the script runs in.
+    pub fn world(&self) -> WorldRef<'a> {
+        self.world
+    }
+
+    /// The entity of the function that is called.
+    pub fn function(&self) -> EntityView<'a> {
+        EntityView::new_from(self.world, self.function)
+    }
+
+    /// The number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Whether the function was called without arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns the argument at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds, or the argument is not of type `T`.
+    pub fn get<T: ComponentId>(&self, index: usize) -> &'a T {
+        let arg = &self.args[index];
+        let id = T::entity_id(self.world);
+        assert_eq!(
+            arg.type_,
+            id,
+            "script function argument {index} is not of type {}",
+            core::any::type_name::<T>()
+        );
+        // SAFETY: flecs converted the argument to its declared type, which was checked above.
+        unsafe { &*(arg.ptr as *const T) }
+    }
+}
+
+struct ScriptFunctionCtx<R> {
+    callback: ScriptCallback<R>,
+    /// Methods receive the instance in `argv` in addition to the `argc` arguments.
+    is_method: bool,
+}
+
+unsafe extern "C-unwind" fn script_function_callback<R: ComponentId>(
+    ctx: *const sys::ecs_function_ctx_t,
+    argc: i32,
+    argv: *const sys::ecs_value_t,
+    result: *mut sys::ecs_value_t,
+) {
+    // SAFETY: flecs passes a valid function context, `argc` values in `argv` and a result that
+    // points to a constructed value of the declared return type.
+    unsafe {
+        let ctx = &*ctx;
+        let function_ctx = &*(ctx.ctx as *const ScriptFunctionCtx<R>);
+        let count = argc as usize + function_ctx.is_method as usize;
+        let args = if count == 0 {
+            &[]
+        } else {
+            core::slice::from_raw_parts(argv, count)
+        };
+        let args = ScriptFunctionArgs {
+            world: WorldRef::from_ptr(ctx.world),
+            function: Entity::new(ctx.function),
+            args,
+        };
+        *((*result).ptr as *mut R) = (function_ctx.callback)(&args);
+    }
+}
+
+unsafe extern "C-unwind" fn free_script_function_ctx<R>(ptr: *mut c_void) {
+    // SAFETY: the binding context was created from a boxed `ScriptFunctionCtx<R>` in `build`.
+    drop(unsafe { Box::from_raw(ptr as *mut ScriptFunctionCtx<R>) });
+}
+
+/// [`ScriptFunctionBuilder`] registers a Rust function that can be called from flecs script.
+///
+/// Created with [`World::script_function`] or [`World::script_method`]. The return type must be
+/// declared with [`returns`](Self::returns) before the function can be built.
+pub struct ScriptFunctionBuilder<'a, R = ()> {
+    world: WorldRef<'a>,
+    name: CompactString,
+    parent: Entity,
+    params: Vec<(CompactString, u64)>,
+    return_type: u64,
+    _phantom: PhantomData<fn() -> R>,
+}
+
+impl<'a> ScriptFunctionBuilder<'a> {
+    /// Create a new builder for a function, or for a method of `parent` when it is not zero.
+    pub(crate) fn new(world: impl WorldProvider<'a>, name: &str, parent: Entity) -> Self {
+        ScriptFunctionBuilder {
+            world: world.world(),
+            name: compact_str::format_compact!("{}\0", name),
+            parent,
+            params: Vec::new(),
+            return_type: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, R> ScriptFunctionBuilder<'a, R> {
+    /// Add a parameter of type `T` to the function.
+    ///
+    /// # Panics
+    ///
+    /// If the function has more than 16 parameters.
+    pub fn arg<T: ComponentId>(mut self, name: &str) -> Self {
+        assert!(
+            self.params.len() < sys::FLECS_SCRIPT_FUNCTION_ARGS_MAX as usize,
+            "script function has too many parameters"
+        );
+        let id = T::entity_id(self.world);
+        self.params
+            .push((compact_str::format_compact!("{}\0", name), id));
+        self
+    }
+
+    /// Set the return type of the function.
+    pub fn returns<T: ComponentId>(self) -> ScriptFunctionBuilder<'a, T> {
+        ScriptFunctionBuilder {
+            return_type: T::entity_id(self.world),
+            world: self.world,
+            name: self.name,
+            parent: self.parent,
+            params: self.params,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, R: ComponentId> ScriptFunctionBuilder<'a, R> {
+    /// Register the function with `func` as implementation.
+    ///
+    /// # Returns
+    ///
+    /// The entity of the function.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_function_init`, `ecs_method_init`
+    pub fn build(self, func: impl Fn(&ScriptFunctionArgs<'_>) -> R + 'static) -> EntityView<'a> {
+        let world_ptr = self.world.world_ptr_mut();
+        let is_method = *self.parent != 0;
+
+        let ctx = Box::into_raw(Box::new(ScriptFunctionCtx::<R> {
+            callback: Box::new(func),
+            is_method,
+        })) as *mut c_void;
+
+        let mut desc = sys::ecs_function_desc_t {
+            name: self.name.as_ptr() as *const _,
+            parent: *self.parent,
+            return_type: self.return_type,
+            callback: Some(script_function_callback::<R>),
+            ctx,
+            ..Default::default()
+        };
+        for (param, (name, id)) in desc.params.iter_mut().zip(&self.params) {
+            param.name = name.as_ptr() as *const _;
+            param.type_ = *id;
+        }
+
+        // SAFETY: the descriptor is fully initialized, and its strings outlive the call.
+        let function = unsafe {
+            if is_method {
+                sys::ecs_method_init(world_ptr, &desc)
+            } else {
+                sys::ecs_function_init(world_ptr, &desc)
+            }
+        };
+        if function == 0 {
+            // SAFETY: the context was not handed to flecs.
+            unsafe { free_script_function_ctx::<R>(ctx) };
+            panic!("failed to register script function");
+        }
+
+        // Hand the context to the function component, so it is freed with the function.
+        // SAFETY: the function entity was just created with the component.
+        unsafe {
+            let component = if is_method {
+                sys::FLECS_IDEcsScriptMethodID_
+            } else {
+                sys::FLECS_IDEcsScriptFunctionID_
+            };
+            let data = sys::ecs_get_mut_id(world_ptr, function, component)
+                as *mut sys::ecs_script_function_t;
+            // registering a function with the same name again updates the existing component,
+            // which still owns the context of the previous registration
+            if let Some(free) = (*data).binding_ctx_free
+                && !(*data).binding_ctx.is_null()
+            {
+                free((*data).binding_ctx);
+            }
+            (*data).binding_ctx = ctx;
+            (*data).binding_ctx_free = Some(free_script_function_ctx::<R>);
+        }
+
+        EntityView::new_from(self.world, function)
+    }
+}
@@ -0,0 +1,222 @@
+use core::any::{Any, TypeId};
+use core::ffi::{CStr, c_char, c_void};
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+extern crate alloc;
+use alloc::{boxed::Box, ffi::CString, string::String, vec::Vec};
+
+/// Reads a script value as `T`.
+///
+/// Besides values of type `T`, flecs strings, like string literals in scripts, can be read as
+/// a Rust [`String`].
+pub(crate) fn read_script_value<T: ComponentId + Clone>(
+    world: WorldRef<'_>,
+    value: &sys::ecs_value_t,
+) -> Option<T> {
+    if value.ptr.is_null() {
+        return None;
+    }
+
+    if value.type_ == T::entity_id(world) {
+        // SAFETY: the value is an initialized value of type T, checked above.
+        return Some(unsafe { (*(value.ptr as *const T)).clone() });
+    }
+
+    if TypeId::of::<T>() == TypeId::of::<String>() && value.type_ == ECS_STRING_T {
+        // SAFETY: the value is a flecs string, which is either null or null-terminated.
+        let str = unsafe {
+            let str = *(value.ptr as *const *const c_char);
+            if str.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(str).to_string_lossy().into_owned()
+            }
+        };
+        return (Box::new(str) as Box<dyn Any>)
+            .downcast::<T>()
+            .ok()
+            .map(|s| *s);
+    }
+
+    None
+}
+
+/// Drops a value that was boxed by [`ScriptVars::declare`].
+type DropValue = fn(*mut c_void);
+
+fn drop_boxed<T>(ptr: *mut c_void) {
+    // SAFETY: the pointer was created from a `Box<T>` when the variable was declared.
+    drop(unsafe { Box::from_raw(ptr as *mut T) });
+}
+
+/// A set of variables that are passed to a script when it is evaluated.
+///
+/// Variables can be of any component type that implements [`Clone`], which includes [`Entity`],
+/// the primitive types and [`String`]. The same parsed [`Script`](super::Script) can be evaluated
+/// many times with different variables, for example to instantiate a level generator with
+/// different parameters.
+/// Scripts refer to the variables by their name, like other `const` variables.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+/// use flecs_ecs::addons::script::*;
+///
+/// #[derive(Component, Clone)]
+/// #[flecs(meta)]
+/// struct Size {
+///     value: f32,
+/// }
+///
+/// let world = World::new();
+/// world.component::<Size>();
+///
+/// let script = Script::parse(&world, "spawn", "e { Size: {size * 2} }", None).unwrap();
+///
+/// let mut vars = world.script_vars();
+/// vars.declare("size", 2.5f32).declare("label", String::from("big"));
+/// assert!(script.eval_with(&vars));
+///
+/// world.lookup("e").get::<&Size>(|s| assert_eq!(s.value, 5.0));
+/// assert_eq!(vars.get::<String>("label").as_deref(), Some("big"));
+/// ```
+///
+/// # See also
+///
+/// * [`Script::eval_with`](super::Script::eval_with)
+/// * C API: `ecs_script_vars_init`
+pub struct ScriptVars<'a> {
+    world: WorldRef<'a>,
+    vars: *mut sys::ecs_script_vars_t,
+    /// Names of the variables, flecs doesn't copy them.
+    names: Vec<CString>,
+    /// Values that are stored outside of flecs, by index of their name.
+    owned: Vec<(usize, DropValue)>,
+}
+
+impl Drop for ScriptVars<'_> {
+    fn drop(&mut self) {
+        for &(name, drop_value) in &self.owned {
+            // SAFETY: the variable was declared in this scope with a boxed value.
+            unsafe {
+                let var = sys::ecs_script_vars_lookup(self.vars, self.names[name].as_ptr());
+                let ptr = (*var).value.ptr;
+                // Prevent flecs from running the destructor on the boxed value.
+                (*var).value.ptr = core::ptr::null_mut();
+                drop_value(ptr);
+            }
+        }
+        // SAFETY: the scope was created with ecs_rust_script_vars_init.
+        unsafe { sys::ecs_rust_script_vars_fini(self.vars) };
+    }
+}
+
+impl<'a> ScriptVars<'a> {
+    /// Create a new, empty set of script variables.
+    ///
+    /// Each set allocates its variables separately, so sets can be created and dropped in any
+    /// order.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_vars_init`
+    pub fn new(world: impl WorldProvider<'a>) -> Self {
+        let world = world.world();
+        ScriptVars {
+            // SAFETY: the world pointer is valid for 'a.
+            vars: unsafe { sys::ecs_rust_script_vars_init(world.world_ptr_mut()) },
+            world,
+            names: Vec::new(),
+            owned: Vec::new(),
+        }
+    }
+
+    fn declare_var(&mut self, name: &str) -> *mut sys::ecs_script_var_t {
+        let name_c = CString::new(name).expect("script variable name contains a null byte");
+        let name_ptr = name_c.as_ptr();
+        self.names.push(name_c);
+        // SAFETY: the name is kept alive by `self`.
+        let var = unsafe { sys::ecs_script_vars_declare(self.vars, name_ptr) };
+        assert!(
+            !var.is_null(),
+            "script variable '{name}' is already declared"
+        );
+        var
+    }
+
+    fn lookup(&self, name: &str) -> Option<&sys::ecs_value_t> {
+        let name = compact_str::format_compact!("{}\0", name);
+        // SAFETY: the scope is valid for the lifetime of `self`.
+        let var = unsafe { sys::ecs_script_vars_lookup(self.vars, name.as_ptr() as *const _) };
+        // SAFETY: variables stay valid until the scope is freed.
+        unsafe { var.as_ref() }.map(|var| &var.value)
+    }
+
+    /// Declare a variable with an initial value.
+    ///
+    /// # Panics
+    ///
+    /// If a variable with the same name is already declared.
+    pub fn declare<T: ComponentId + Clone>(&mut self, name: &str, value: T) -> &mut Self {
+        let id = T::entity_id(self.world);
+        let var = self.declare_var(name);
+        // The value is stored outside of the flecs stack allocator, so that it doesn't have to
+        // be default constructed first. It is dropped with the variables.
+        // SAFETY: `var` was just declared and the type id belongs to T.
+        unsafe {
+            (*var).value.type_ = id;
+            (*var).value.ptr = Box::into_raw(Box::new(value)) as *mut c_void;
+            (*var).type_info = sys::ecs_get_type_info(self.world.world_ptr(), id);
+        }
+        self.owned.push((self.names.len() - 1, drop_boxed::<T>));
+        self
+    }
+
+    /// Assign a new value to a declared variable, to evaluate a script again with other
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// If the variable is not declared, or was declared with another type.
+    pub fn set<T: ComponentId + Clone>(&mut self, name: &str, value: T) -> &mut Self {
+        let id = T::entity_id(self.world);
+        let var = self
+            .lookup(name)
+            .unwrap_or_else(|| panic!("script variable '{name}' is not declared"));
+        assert_eq!(
+            var.type_,
+            id,
+            "script variable '{name}' is not of type {}",
+            core::any::type_name::<T>()
+        );
+        // SAFETY: the variable holds an initialized value of type T, checked above.
+        unsafe { *(var.ptr as *mut T) = value };
+        self
+    }
+
+    /// Read the value of a variable.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the variable is not declared or is not of type `T`.
+    pub fn get<T: ComponentId + Clone>(&self, name: &str) -> Option<T> {
+        self.lookup(name)
+            .and_then(|value| read_script_value(self.world, value))
+    }
+
+    /// Returns the raw flecs variable scope.
+    pub(crate) fn as_ptr(&self) -> *const sys::ecs_script_vars_t {
+        self.vars
+    }
+
+    /// Returns the descriptor to pass these variables to [`Script::eval`](super::Script::eval).
+    pub fn eval_desc(&self) -> sys::ecs_script_eval_desc_t {
+        sys::ecs_script_eval_desc_t {
+            vars: self.vars,
+            runtime: core::ptr::null_mut(),
+        }
+    }
+}
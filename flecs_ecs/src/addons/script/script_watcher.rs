@@ -0,0 +1,254 @@
+extern crate std;
+
+use core::ffi::CStr;
+use core::ops::Deref;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use flecs_ecs::core::*;
+use flecs_ecs::sys;
+
+use crate::addons::system::System;
+
+use super::{Script, ScriptEntityView};
+
+extern crate alloc;
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+
+type ErrorCallback = Box<dyn FnMut(ScriptEntityView<'_>, &FlecsError) + 'static>;
+
+/// State of a watcher that is owned by its system.
+struct WatcherState {
+    interval: FTime,
+    elapsed: FTime,
+    /// Last seen modification time of the file of each script.
+    mtimes: HashMap<Entity, Option<SystemTime>>,
+    on_error: Option<ErrorCallback>,
+}
+
+/// Returns the entity and file name of all scripts that were loaded from a file.
+fn file_scripts(world: WorldRef<'_>) -> Vec<(Entity, String)> {
+    let mut scripts = Vec::new();
+    // SAFETY: the iterator is created for a valid world, and the fields of each matched table
+    // are `EcsScript` components.
+    unsafe {
+        let mut it = sys::ecs_each_id(world.world_ptr(), sys::FLECS_IDEcsScriptID_);
+        while sys::ecs_each_next(&mut it) {
+            let data =
+                sys::ecs_field_w_size(&it, size_of::<sys::EcsScript>(), 0) as *const sys::EcsScript;
+            for i in 0..it.count as usize {
+                let script = &*data.add(i);
+                if script.filename.is_null() || !script.template_.is_null() {
+                    continue;
+                }
+                let filename = CStr::from_ptr(script.filename)
+                    .to_string_lossy()
+                    .into_owned();
+                scripts.push((Entity::new(*it.entities.add(i)), filename));
+            }
+        }
+    }
+    scripts
+}
+
+fn modified(filename: &str) -> Option<SystemTime> {
+    std::fs::metadata(filename)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl WatcherState {
+    /// Reloads the scripts whose file changed since the last check.
+    fn check(&mut self, world: WorldRef<'_>) {
+        let scripts = file_scripts(world);
+        self.mtimes
+            .retain(|script, _| scripts.iter().any(|(e, _)| e == script));
+
+        for (script, filename) in scripts {
+            let mtime = modified(&filename);
+            match self.mtimes.insert(script, mtime) {
+                Some(previous) if previous != mtime && mtime.is_some() => {
+                    if let Err(err) = reload(world, script, &filename) {
+                        self.report(ScriptEntityView::new_from(world, script), &err);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn report(&mut self, script: ScriptEntityView<'_>, err: &FlecsError) {
+        if let Some(on_error) = &mut self.on_error {
+            on_error(script, err);
+        } else {
+            let message = compact_str::format_compact!("{}\0", err);
+            // SAFETY: the format string and message are null terminated.
+            unsafe {
+                sys::ecs_log_(
+                    -3,
+                    core::ptr::null(),
+                    0,
+                    c"%s".as_ptr(),
+                    message.as_ptr() as *const core::ffi::c_char,
+                );
+            }
+        }
+    }
+}
+
+/// Updates a script with the new contents of its file.
+///
+/// The new code is parsed before the script is updated, so that a parse error leaves the
+/// entities of the script untouched. If the new code fails to evaluate, the previous code is
+/// evaluated again.
+fn reload(world: WorldRef<'_>, script: Entity, filename: &str) -> Result<(), FlecsError> {
+    let code = std::fs::read_to_string(filename).map_err(|err| FlecsError::Script {
+        name: filename.to_owned(),
+        message: alloc::format!("{err}"),
+    })?;
+
+    Script::try_parse(world, filename, &code, None)?;
+
+    let view = ScriptEntityView::new_from(world, script);
+    let previous = view.get::<&flecs::Script>(|script| {
+        // SAFETY: the code of a script is either null or a null terminated string.
+        (!script.code.is_null()).then(|| {
+            unsafe { CStr::from_ptr(script.code) }
+                .to_string_lossy()
+                .into_owned()
+        })
+    });
+
+    let (success, message) = capture_errors(|| view.update(world, None::<Entity>, &code));
+    if success {
+        return Ok(());
+    }
+
+    if let Some(previous) = previous {
+        view.update(world, None::<Entity>, &previous);
+    }
+
+    Err(FlecsError::Script {
+        name: filename.to_owned(),
+        message,
+    })
+}
+
+/// [`ScriptWatcherBuilder`] configures a [`ScriptWatcher`].
+///
+/// Created with [`World::script_watcher`].
+pub struct ScriptWatcherBuilder<'a> {
+    world: &'a World,
+    interval: FTime,
+    phase: Option<Entity>,
+    on_error: Option<ErrorCallback>,
+}
+
+impl<'a> ScriptWatcherBuilder<'a> {
+    /// Create a new script watcher builder.
+    pub(crate) fn new(world: &'a World) -> Self {
+        ScriptWatcherBuilder {
+            world,
+            interval: 1.0,
+            phase: None,
+            on_error: None,
+        }
+    }
+
+    /// Set how often the files are checked for changes, in seconds. Defaults to one second.
+    ///
+    /// The interval is measured in world time, so it is affected by the time scale.
+    pub fn interval(&mut self, seconds: FTime) -> &mut Self {
+        self.interval = seconds;
+        self
+    }
+
+    /// Set the phase in which the files are checked. Defaults to `OnUpdate`.
+    pub fn kind(&mut self, phase: impl IntoEntity) -> &mut Self {
+        self.phase = Some(phase.into_entity(self.world));
+        self
+    }
+
+    /// Set the callback that is invoked when a changed script fails to reload.
+    ///
+    /// When the new code fails to parse the script is not updated. When it fails to evaluate,
+    /// the previous code is evaluated again. Without a callback, errors are logged.
+    pub fn on_error(
+        &mut self,
+        func: impl FnMut(ScriptEntityView<'_>, &FlecsError) + 'static,
+    ) -> &mut Self {
+        self.on_error = Some(Box::new(func));
+        self
+    }
+
+    /// Create the watcher.
+    ///
+    /// Scripts that are loaded from a file after the watcher is created are watched as well.
+    pub fn build(&mut self) -> ScriptWatcher<'a> {
+        let world = self.world.world();
+        let mut state = WatcherState {
+            interval: self.interval,
+            elapsed: 0.0,
+            mtimes: HashMap::new(),
+            on_error: self.on_error.take(),
+        };
+        for (script, filename) in file_scripts(world) {
+            state.mtimes.insert(script, modified(&filename));
+        }
+
+        let mut builder = self.world.system::<()>();
+        if let Some(phase) = self.phase {
+            builder.kind(phase);
+        }
+        let system = builder.run(move |mut it| {
+            state.elapsed += it.delta_time();
+            if state.elapsed >= state.interval {
+                state.elapsed = 0.0;
+                state.check(it.real_world());
+            }
+            while it.next() {}
+        });
+
+        ScriptWatcher { system }
+    }
+}
+
+/// [`ScriptWatcher`] reloads scripts when the file they were loaded from changes.
+///
+/// The watcher is a system that polls the modification time of the files of all scripts
+/// created with [`ScriptBuilder::build_from_file`](super::ScriptBuilder::build_from_file), and
+/// calls [`ScriptEntityView::update`] for the scripts that changed.
+///
+/// # Example
+///
+/// ```no_run
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// world.script().build_from_file("level.flecs");
+///
+/// world
+///     .script_watcher()
+///     .interval(0.5)
+///     .kind(id::<flecs::pipeline::PreUpdate>())
+///     .on_error(|script, err| println!("failed to reload {}: {err}", script.path().unwrap()))
+///     .build();
+///
+/// loop {
+///     world.progress();
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScriptWatcher<'a> {
+    system: System<'a>,
+}
+
+impl<'a> Deref for ScriptWatcher<'a> {
+    type Target = System<'a>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.system
+    }
+}
@@ -3,6 +3,8 @@ use core::ffi::CStr;
 use flecs_ecs::core::*;
 use flecs_ecs::sys;
 
+use super::ScriptVars;
+
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -83,6 +85,30 @@ impl<'a> Script<'a> {
         }
     }
 
+    /// Parses and creates new script dynamically, returning the parse error on failure.
+    ///
+    /// This is the fallible counterpart of [`parse`](Self::parse).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] with the error reported by the parser.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_parse`
+    pub fn try_parse(
+        world: impl WorldProvider<'a>,
+        name: &str,
+        code: &str,
+        desc: Option<sys::ecs_script_eval_desc_t>,
+    ) -> Result<Script<'a>, FlecsError> {
+        let (script, message) = capture_errors(|| Self::parse(world, name, code, desc));
+        script.ok_or_else(|| FlecsError::Script {
+            name: name.to_owned(),
+            message,
+        })
+    }
+
     /// Evaluate script. This operation evaluates (runs) a parsed script.
     ///
     /// # Returns
@@ -102,6 +128,67 @@ impl<'a> Script<'a> {
         }
     }
 
+    /// Evaluate script with variables declared in Rust.
+    ///
+    /// # Returns
+    ///
+    /// True if success, false if failed.
+    ///
+    /// # See also
+    ///
+    /// * [`ScriptVars`](super::ScriptVars)
+    /// * C API: `ecs_script_eval`
+    pub fn eval_with(&self, vars: &ScriptVars<'_>) -> bool {
+        self.eval(Some(vars.eval_desc()))
+    }
+
+    /// Evaluate script, returning the evaluation error on failure.
+    ///
+    /// This is the fallible counterpart of [`eval`](Self::eval).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] with the error reported while evaluating the script.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_eval`
+    pub fn try_eval(&self, desc: Option<sys::ecs_script_eval_desc_t>) -> Result<(), FlecsError> {
+        let (success, message) = capture_errors(|| self.eval(desc));
+        if success {
+            Ok(())
+        } else {
+            Err(FlecsError::Script {
+                name: self.name(),
+                message,
+            })
+        }
+    }
+
+    /// Evaluate script with variables declared in Rust, returning the evaluation error on
+    /// failure.
+    ///
+    /// This is the fallible counterpart of [`eval_with`](Self::eval_with).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] with the error reported while evaluating the script.
+    pub fn try_eval_with(&self, vars: &ScriptVars<'_>) -> Result<(), FlecsError> {
+        self.try_eval(Some(vars.eval_desc()))
+    }
+
+    /// Returns the name of the script.
+    fn name(&self) -> String {
+        let name = unsafe { (*self.script).name };
+        if name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(name) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
     pub fn destroy(self) {
         // Drop
     }
@@ -154,6 +241,52 @@ impl<'a> Script<'a> {
         unsafe { sys::ecs_script_run_file(world_ptr, filename.as_ptr() as *const _) == 0 }
     }
 
+    /// Parse and run script, returning the parse or evaluation error on failure.
+    ///
+    /// This is the fallible counterpart of [`run_code`](Self::run_code).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] with the error reported while running the script.
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_run`
+    pub fn try_run_code(
+        world: impl WorldProvider<'a>,
+        name: &str,
+        code: &str,
+    ) -> Result<(), FlecsError> {
+        let (success, message) = capture_errors(|| Self::run_code(world, name, code));
+        if success {
+            Ok(())
+        } else {
+            Err(FlecsError::Script {
+                name: name.to_owned(),
+                message,
+            })
+        }
+    }
+
+    /// Parse and run script file, returning the error on failure.
+    ///
+    /// This is the fallible counterpart of [`run_file`](Self::run_file).
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] with the error reported while loading or running the script.
+    pub fn try_run_file(world: impl WorldProvider<'a>, filename: &str) -> Result<(), FlecsError> {
+        let (success, message) = capture_errors(|| Self::run_file(world, filename));
+        if success {
+            Ok(())
+        } else {
+            Err(FlecsError::Script {
+                name: filename.to_owned(),
+                message,
+            })
+        }
+    }
+
     /// Convert script AST to string.
     /// This operation converts the script abstract syntax tree to a string, which can be used to debug a script.
     ///
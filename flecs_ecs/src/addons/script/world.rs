@@ -1,3 +1,4 @@
+use super::expr::run_expr;
 use super::*;
 use crate::sys;
 use alloc::string::String;
@@ -30,6 +31,14 @@ impl World {
         ScriptBuilder::new_from(self, entity)
     }
 
+    /// Create a watcher that reloads scripts when the file they were loaded from changes.
+    ///
+    /// See [`ScriptWatcher`] for details.
+    #[cfg(all(feature = "std", feature = "flecs_system"))]
+    pub fn script_watcher(&self) -> ScriptWatcherBuilder<'_> {
+        ScriptWatcherBuilder::new(self)
+    }
+
     /// Parse script. This parses a script and instantiates the entities in the world.
     /// This operation is the equivalent to doing: [`parse`][flecs_ecs::addons::script::Script::parse], [`eval`][flecs_ecs::addons::script::Script::eval], [`destroy`][flecs_ecs::addons::script::Script::destroy].
     ///
@@ -64,6 +73,57 @@ impl World {
         Script::run_file(self, filename)
     }
 
+    /// Parse and run script, returning the error reported by flecs on failure.
+    ///
+    /// This is the fallible counterpart of [`run_code`](Self::run_code).
+    ///
+    /// # Arguments
+    ///
+    /// * name - The script name (typically the file).
+    ///
+    /// * code - The script.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] if the script failed to parse or evaluate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// assert!(world.try_run_code("main", "e {}").is_ok());
+    ///
+    /// let err = world.try_run_code("main", "e {").unwrap_err();
+    /// assert!(matches!(err, FlecsError::Script { .. }));
+    /// assert!(!err.message().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_script_run`
+    pub fn try_run_code(&self, name: &str, code: &str) -> Result<(), FlecsError> {
+        Script::try_run_code(self, name, code)
+    }
+
+    /// Parse and run script file, returning the error reported by flecs on failure.
+    ///
+    /// This is the fallible counterpart of [`run_file`](Self::run_file).
+    ///
+    /// # Arguments
+    ///
+    /// * filename - The script file name.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Script`] if the file could not be loaded, or the script failed to parse
+    /// or evaluate.
+    pub fn try_run_file(&self, filename: &str) -> Result<(), FlecsError> {
+        Script::try_run_file(self, filename)
+    }
+
     /// Serialize value into a String.
     /// This operation serializes a value of the provided type to a string.
     ///
@@ -92,6 +152,178 @@ impl World {
         unsafe { Script::to_expr(self, T::id(), value as *const T) }
     }
 
+    /// Parse a value of type `T` from a flecs expression.
+    ///
+    /// This is the inverse of [`to_expr`](Self::to_expr). Members that are not assigned by the
+    /// expression keep their default value.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Expr`] with the error reported by flecs if the expression failed to parse
+    /// or evaluate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default, Debug, PartialEq)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>();
+    ///
+    /// let pos = world.from_expr::<Position>("{x: 10, y: 5 * 2}").unwrap();
+    /// assert_eq!(pos, Position { x: 10.0, y: 10.0 });
+    ///
+    /// assert!(world.from_expr::<Position>("{x: }").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_expr<T: ComponentId + Default>(&self, expr: &str) -> Result<T, FlecsError> {
+        self.from_expr_impl(expr, None)
+    }
+
+    /// Parse a value of type `T` from a flecs expression that uses script variables.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Expr`] with the error reported by flecs if the expression failed to parse
+    /// or evaluate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Default, Debug, PartialEq)]
+    /// #[flecs(meta)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Position>();
+    ///
+    /// let mut vars = world.script_vars();
+    /// vars.declare("offset", 4.0f32);
+    ///
+    /// let pos = world
+    ///     .from_expr_with::<Position>("{x: 10, y: $offset * 2}", &vars)
+    ///     .unwrap();
+    /// assert_eq!(pos, Position { x: 10.0, y: 8.0 });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_expr_with<T: ComponentId + Default>(
+        &self,
+        expr: &str,
+        vars: &ScriptVars<'_>,
+    ) -> Result<T, FlecsError> {
+        self.from_expr_impl(expr, Some(vars))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_expr_impl<T: ComponentId + Default>(
+        &self,
+        expr: &str,
+        vars: Option<&ScriptVars<'_>>,
+    ) -> Result<T, FlecsError> {
+        let mut result = T::default();
+        let mut value = sys::ecs_value_t {
+            type_: T::entity_id(self),
+            ptr: &mut result as *mut T as *mut core::ffi::c_void,
+        };
+        run_expr(self.world(), expr, &mut value, vars)?;
+        Ok(result)
+    }
+
+    /// Evaluate a flecs expression.
+    ///
+    /// The result has the type of the expression, see [`DynamicValue`].
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Expr`] with the error reported by flecs if the expression failed to parse
+    /// or evaluate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let value = world.eval_expr("10 + 2 * 3.5").unwrap();
+    /// assert_eq!(value.as_f64(), Some(17.0));
+    ///
+    /// let err = world.eval_expr("10 +").unwrap_err();
+    /// assert!(!err.message().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn eval_expr(&self, expr: &str) -> Result<DynamicValue<'_>, FlecsError> {
+        self.eval_expr_impl(expr, None)
+    }
+
+    /// Evaluate a flecs expression that uses script variables.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Expr`] with the error reported by flecs if the expression failed to parse
+    /// or evaluate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut vars = world.script_vars();
+    /// vars.declare("x", 16.0f64);
+    ///
+    /// let value = world.eval_expr_with("1 + $x / 2", &vars).unwrap();
+    /// assert_eq!(value.as_f64(), Some(9.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * C API: `ecs_expr_run`
+    pub fn eval_expr_with(
+        &self,
+        expr: &str,
+        vars: &ScriptVars<'_>,
+    ) -> Result<DynamicValue<'_>, FlecsError> {
+        self.eval_expr_impl(expr, Some(vars))
+    }
+
+    fn eval_expr_impl(
+        &self,
+        expr: &str,
+        vars: Option<&ScriptVars<'_>>,
+    ) -> Result<DynamicValue<'_>, FlecsError> {
+        let mut value = sys::ecs_value_t {
+            type_: 0,
+            ptr: core::ptr::null_mut(),
+        };
+        run_expr(self.world(), expr, &mut value, vars)?;
+        Ok(DynamicValue::new(self.world(), value))
+    }
+
     /*
 
     template <typename T>
@@ -140,6 +372,19 @@ impl World {
         Script::get_const_var(self, name)
     }
 
+    /// Read the value of an exported script `const` variable as a typed Rust value.
+    ///
+    /// String constants can be read as a Rust [`String`].
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if there is no const variable with the name, or it is not of
+    /// type `T`.
+    pub fn get_const<T: ComponentId + Clone>(&self, name: &str) -> Option<T> {
+        Script::get_const_var(self, name)
+            .and_then(|value| super::script_vars::read_script_value(self.world(), &value))
+    }
+
     pub fn get_const_numeric<T: ConstNumeric>(&self, value: sys::ecs_value_t) -> T::ConstType {
         Script::get_const_numeric::<T>(self, value)
     }
@@ -152,6 +397,106 @@ impl World {
         Script::get_const_char(self, value)
     }
 
+    /// Create an empty set of variables to pass to a script when it is evaluated.
+    ///
+    /// # See also
+    ///
+    /// * [`Script::eval_with`]
+    /// * C API: `ecs_script_vars_init`
+    pub fn script_vars(&self) -> ScriptVars<'_> {
+        ScriptVars::new(self)
+    }
+
+    /// Create a builder for a function that can be called from scripts.
+    ///
+    /// Parameters are added with [`arg`](ScriptFunctionBuilder::arg), and the return type is set
+    /// with [`returns`](ScriptFunctionBuilder::returns) before the function is built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Speed {
+    ///     value: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Speed>();
+    ///
+    /// world
+    ///     .script_function("double")
+    ///     .arg::<f32>("x")
+    ///     .returns::<f32>()
+    ///     .build(|args| args.get::<f32>(0) * 2.0);
+    ///
+    /// world.run_code("main", "e { Speed: {double(1.5)} }");
+    ///
+    /// let e = world.lookup("e");
+    /// e.get::<&Speed>(|speed| assert_eq!(speed.value, 3.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::script_method`]
+    /// * C API: `ecs_function_init`
+    pub fn script_function(&self, name: &str) -> ScriptFunctionBuilder<'_> {
+        ScriptFunctionBuilder::new(self, name, Entity::null())
+    }
+
+    /// Create a builder for a method of the reflected type `T` that can be called from scripts.
+    ///
+    /// The instance the method is called on is passed as the first argument, before the
+    /// arguments added with [`arg`](ScriptFunctionBuilder::arg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone)]
+    /// #[flecs(meta)]
+    /// struct Vec2 {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// #[flecs(meta)]
+    /// struct Length {
+    ///     value: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.component::<Length>();
+    ///
+    /// world
+    ///     .script_method::<Vec2>("length")
+    ///     .returns::<f32>()
+    ///     .build(|args| {
+    ///         let v = args.get::<Vec2>(0);
+    ///         (v.x * v.x + v.y * v.y).sqrt()
+    ///     });
+    ///
+    /// world.run_code(
+    ///     "main",
+    ///     "const v = Vec2: {3, 4}\n e { Length: {v.length()} }",
+    /// );
+    ///
+    /// let e = world.lookup("e");
+    /// e.get::<&Length>(|length| assert_eq!(length.value, 5.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::script_function`]
+    /// * C API: `ecs_method_init`
+    pub fn script_method<T: ComponentId>(&self, name: &str) -> ScriptFunctionBuilder<'_> {
+        ScriptFunctionBuilder::new(self, name, Entity::new(T::entity_id(self)))
+    }
+
     /// Wraps the provided entity id in a [`ScriptEntityView`].
     ///
     /// # Panics
@@ -28,6 +28,19 @@
 //!     .run();
 //! ```
 //!
+//! Statistics can also be read directly with the safe views in this module:
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//!
+//! let world = World::new();
+//! world.progress();
+//!
+//! let snapshot = world.stats_snapshot();
+//! let entities = snapshot.world.entity_count().last();
+//! let allocated = snapshot.memory.allocator_bytes();
+//! ```
+//!
 //! # Statistics Tiers
 //!
 //! When the addon is imported as a module, statistics are tracked across multiple time scales:
@@ -43,4 +56,7 @@
 //! - [`App::enable_stats()`](crate::addons::app::App::enable_stats) - Enable statistics tracking
 
 mod stats;
+mod stats_view;
+mod world;
 pub use stats::*;
+pub use stats_view::*;
@@ -0,0 +1,756 @@
+//! Safe views over the measurement windows of the stats addon.
+//!
+//! Every statistic in flecs is stored as a ring buffer of [`STAT_WINDOW`] measurements.
+//! Gauges store a value per measurement, counters store a monotonically increasing total
+//! together with a gauge of the per-measurement increase. The views in this module decode
+//! those buffers so applications can read the latest value or aggregate over the most
+//! recent measurements without touching the C structs.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::addons::system::run_condition::skipped_count;
+use crate::core::*;
+use crate::sys;
+
+/// Number of measurements stored per statistic.
+pub const STAT_WINDOW: usize = sys::ECS_STAT_WINDOW as usize;
+
+#[inline]
+fn t_prev(t: i32) -> i32 {
+    (t - 1 + STAT_WINDOW as i32) % STAT_WINDOW as i32
+}
+
+/// Gauge statistic: a value that is measured once per sample, like an entity count.
+#[derive(Clone, Copy)]
+pub struct GaugeView<'a> {
+    gauge: &'a sys::ecs_gauge_t,
+    t: i32,
+    samples: usize,
+}
+
+impl<'a> GaugeView<'a> {
+    fn new(metric: &'a sys::ecs_metric_t, t: i32, samples: usize) -> Self {
+        // SAFETY: the gauge and the rate of a counter share the same layout, reading a
+        // metric as a gauge is always valid.
+        Self {
+            gauge: unsafe { &metric.gauge },
+            t,
+            samples,
+        }
+    }
+
+    /// Indices of the last `window` measurements, newest first.
+    fn indices(&self, window: usize) -> impl Iterator<Item = usize> {
+        let count = window.min(self.samples);
+        let t = self.t as usize;
+        (0..count).map(move |i| (t + STAT_WINDOW - i) % STAT_WINDOW)
+    }
+
+    /// Value of the most recent measurement.
+    pub fn last(&self) -> f32 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        self.gauge.avg[self.t as usize]
+    }
+
+    /// Average over the last `window` measurements.
+    ///
+    /// The window is clamped to the number of recorded measurements. Returns 0 if nothing
+    /// was recorded yet.
+    pub fn avg(&self, window: usize) -> f32 {
+        let count = window.min(self.samples);
+        if count == 0 {
+            return 0.0;
+        }
+        let sum: f32 = self.indices(window).map(|i| self.gauge.avg[i]).sum();
+        sum / count as f32
+    }
+
+    /// Smallest value measured in the last `window` measurements.
+    pub fn min(&self, window: usize) -> f32 {
+        self.indices(window)
+            .map(|i| self.gauge.min[i])
+            .reduce(f32::min)
+            .unwrap_or(0.0)
+    }
+
+    /// Largest value measured in the last `window` measurements.
+    pub fn max(&self, window: usize) -> f32 {
+        self.indices(window)
+            .map(|i| self.gauge.max[i])
+            .reduce(f32::max)
+            .unwrap_or(0.0)
+    }
+
+    /// Recorded measurements, from oldest to newest.
+    pub fn history(&self) -> impl Iterator<Item = f32> + 'a {
+        let gauge = self.gauge;
+        let mut indices: Vec<usize> = self.indices(STAT_WINDOW).collect();
+        indices.reverse();
+        indices.into_iter().map(move |i| gauge.avg[i])
+    }
+
+    /// Number of recorded measurements, at most [`STAT_WINDOW`].
+    pub fn sample_count(&self) -> usize {
+        self.samples
+    }
+}
+
+impl core::fmt::Debug for GaugeView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GaugeView")
+            .field("last", &self.last())
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+/// Counter statistic: a monotonically increasing total, like the number of frames.
+#[derive(Clone, Copy)]
+pub struct CounterView<'a> {
+    metric: &'a sys::ecs_metric_t,
+    t: i32,
+    samples: usize,
+}
+
+impl<'a> CounterView<'a> {
+    fn new(metric: &'a sys::ecs_metric_t, t: i32, samples: usize) -> Self {
+        Self { metric, t, samples }
+    }
+
+    /// Total value at the most recent measurement.
+    pub fn total(&self) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        // SAFETY: the metric was recorded as a counter by flecs.
+        unsafe { self.metric.counter.value[self.t as usize] }
+    }
+
+    /// Increase of the counter per measurement.
+    pub fn rate(&self) -> GaugeView<'a> {
+        GaugeView::new(self.metric, self.t, self.samples)
+    }
+}
+
+impl core::fmt::Debug for CounterView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CounterView")
+            .field("total", &self.total())
+            .field("samples", &self.samples)
+            .finish()
+    }
+}
+
+macro_rules! gauge_accessors {
+    ($($(#[$meta:meta])* $name:ident => $($field:ident).+;)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self) -> GaugeView<'_> {
+                GaugeView::new(&self.stats.$($field).+, self.t(), self.samples)
+            }
+        )*
+    };
+}
+
+macro_rules! counter_accessors {
+    ($($(#[$meta:meta])* $name:ident => $($field:ident).+;)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self) -> CounterView<'_> {
+                CounterView::new(&self.stats.$($field).+, self.t(), self.samples)
+            }
+        )*
+    };
+}
+
+/// Record `value` in the counter `metric` at `t`, like flecs records its own counters.
+fn counter_record(metric: &mut sys::ecs_metric_t, t: i32, value: f64) {
+    // SAFETY: the metric is only ever used as a counter.
+    let counter = unsafe { &mut metric.counter };
+    let (t, prev) = (t as usize, t_prev(t) as usize);
+    // counters are monotonically increasing
+    let rate = (value - counter.value[prev]).max(0.0) as f32;
+    counter.value[t] = value;
+    counter.rate.avg[t] = rate;
+    counter.rate.min[t] = rate;
+    counter.rate.max[t] = rate;
+}
+
+/// Stats structs for which all-zeroes is the initial state.
+///
+/// # Safety
+///
+/// Implementors must be plain C data that is valid when zeroed.
+unsafe trait ZeroedStats {}
+
+// SAFETY: plain C data, zeroed by flecs before measuring.
+unsafe impl ZeroedStats for sys::ecs_world_stats_t {}
+// SAFETY: plain C data, zeroed by flecs before measuring.
+unsafe impl ZeroedStats for sys::ecs_system_stats_t {}
+// SAFETY: a union of plain C arrays.
+unsafe impl ZeroedStats for sys::ecs_metric_t {}
+
+fn boxed_zeroed<T: ZeroedStats>() -> Box<T> {
+    // SAFETY: all-zeroes is a valid `T`, see `ZeroedStats`.
+    unsafe { Box::<T>::new_zeroed().assume_init() }
+}
+
+/// World statistics with a window of [`STAT_WINDOW`] measurements.
+///
+/// Call [`WorldStatsView::record()`] once per frame (or at any other interval) to take a
+/// measurement, or obtain a copy of the history collected by the [`Stats`](super::Stats)
+/// module with [`World::stats_snapshot()`].
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+/// use flecs_ecs::addons::stats::WorldStatsView;
+///
+/// let world = World::new();
+/// let mut stats = WorldStatsView::new();
+///
+/// for _ in 0..10 {
+///     world.entity();
+///     world.progress();
+///     stats.record(&world);
+/// }
+///
+/// assert!(stats.entity_count().last() > stats.entity_count().min(10));
+/// assert_eq!(stats.frame_count().rate().avg(5), 1.0);
+/// ```
+#[derive(Clone)]
+pub struct WorldStatsView {
+    stats: Box<sys::ecs_world_stats_t>,
+    samples: usize,
+}
+
+impl Default for WorldStatsView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldStatsView {
+    /// Create an empty view without measurements.
+    pub fn new() -> Self {
+        Self {
+            stats: boxed_zeroed(),
+            samples: 0,
+        }
+    }
+
+    pub(crate) fn from_raw(stats: &sys::ecs_world_stats_t, samples: usize) -> Self {
+        let mut view = Self::new();
+        *view.stats = *stats;
+        view.samples = samples.min(STAT_WINDOW);
+        view
+    }
+
+    /// Take a measurement of the world.
+    pub fn record<'a>(&mut self, world: impl WorldProvider<'a>) {
+        unsafe { sys::ecs_world_stats_get(world.world_ptr(), &mut *self.stats) };
+        self.samples = (self.samples + 1).min(STAT_WINDOW);
+    }
+
+    /// Number of recorded measurements, at most [`STAT_WINDOW`].
+    pub fn sample_count(&self) -> usize {
+        self.samples
+    }
+
+    fn t(&self) -> i32 {
+        self.stats.t
+    }
+
+    gauge_accessors! {
+        /// Number of alive entities.
+        entity_count => entities.count;
+        /// Number of not alive (recyclable) entity ids.
+        not_alive_entity_count => entities.not_alive_count;
+        /// Number of tag ids (ids without data).
+        tag_count => components.tag_count;
+        /// Number of component ids (ids with data).
+        component_count => components.component_count;
+        /// Number of pair ids.
+        pair_count => components.pair_count;
+        /// Number of registered component types.
+        type_count => components.type_count;
+        /// Number of tables.
+        table_count => tables.count;
+        /// Number of empty tables.
+        empty_table_count => tables.empty_count;
+        /// Number of queries.
+        query_count => queries.query_count;
+        /// Number of observers.
+        observer_count => queries.observer_count;
+        /// Number of systems.
+        system_count => queries.system_count;
+        /// Frames per second.
+        fps => performance.fps;
+        /// Delta time passed to systems.
+        delta_time => performance.delta_time;
+        /// Number of allocations that have not been freed.
+        outstanding_alloc_count => memory.outstanding_alloc_count;
+        /// Number of block allocations that have not been freed.
+        block_outstanding_alloc_count => memory.block_outstanding_alloc_count;
+    }
+
+    counter_accessors! {
+        /// Number of frames processed.
+        frame_count => frame.frame_count;
+        /// Number of merges of deferred commands.
+        merge_count => frame.merge_count;
+        /// Number of query rematches.
+        rematch_count => frame.rematch_count;
+        /// Number of pipeline rebuilds.
+        pipeline_build_count => frame.pipeline_build_count;
+        /// Number of systems ran.
+        systems_ran => frame.systems_ran;
+        /// Number of observers ran.
+        observers_ran => frame.observers_ran;
+        /// Time spent in frames, in seconds.
+        frame_time => performance.frame_time;
+        /// Time spent in systems, in seconds.
+        system_time => performance.system_time;
+        /// Time spent emitting events, in seconds.
+        emit_time => performance.emit_time;
+        /// Time spent merging deferred commands, in seconds.
+        merge_time => performance.merge_time;
+        /// Time spent rematching queries, in seconds.
+        rematch_time => performance.rematch_time;
+        /// Scaled world time, in seconds.
+        world_time => performance.world_time;
+        /// Unscaled world time, in seconds.
+        world_time_raw => performance.world_time_raw;
+        /// Number of created component ids.
+        component_create_count => components.create_count;
+        /// Number of deleted component ids.
+        component_delete_count => components.delete_count;
+        /// Number of created tables.
+        table_create_count => tables.create_count;
+        /// Number of deleted tables.
+        table_delete_count => tables.delete_count;
+        /// Number of add commands.
+        add_command_count => commands.add_count;
+        /// Number of remove commands.
+        remove_command_count => commands.remove_count;
+        /// Number of delete commands.
+        delete_command_count => commands.delete_count;
+        /// Number of set commands.
+        set_command_count => commands.set_count;
+        /// Number of allocations.
+        alloc_count => memory.alloc_count;
+        /// Number of frees.
+        free_count => memory.free_count;
+    }
+
+    /// The underlying C statistics.
+    pub fn raw(&self) -> &sys::ecs_world_stats_t {
+        &self.stats
+    }
+}
+
+impl core::fmt::Debug for WorldStatsView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WorldStatsView")
+            .field("samples", &self.samples)
+            .field("entity_count", &self.entity_count().last())
+            .field("table_count", &self.table_count().last())
+            .field("frame_time", &self.frame_time().rate().last())
+            .finish()
+    }
+}
+
+/// System statistics with a window of [`STAT_WINDOW`] measurements.
+#[derive(Clone)]
+pub struct SystemStatsView {
+    system: Entity,
+    stats: Box<sys::ecs_system_stats_t>,
+    skipped: Box<sys::ecs_metric_t>,
+    samples: usize,
+}
+
+impl SystemStatsView {
+    /// Create an empty view for `system` without measurements.
+    pub fn new<'a>(world: impl WorldProvider<'a>, system: impl IntoEntity) -> Self {
+        Self {
+            system: system.into_entity(world),
+            stats: boxed_zeroed(),
+            skipped: boxed_zeroed(),
+            samples: 0,
+        }
+    }
+
+    /// Take a measurement of the system.
+    ///
+    /// Returns `false` if the entity is not a system.
+    pub fn record<'a>(&mut self, world: impl WorldProvider<'a>) -> bool {
+        let recorded =
+            unsafe { sys::ecs_system_stats_get(world.world_ptr(), *self.system, &mut *self.stats) };
+        if recorded {
+            self.samples = (self.samples + 1).min(STAT_WINDOW);
+            let skipped = skipped_count(world.world(), self.system).unwrap_or(0);
+            let t = self.t();
+            counter_record(&mut self.skipped, t, skipped as f64);
+        }
+        recorded
+    }
+
+    /// The system entity.
+    pub fn system(&self) -> Entity {
+        self.system
+    }
+
+    /// Number of recorded measurements, at most [`STAT_WINDOW`].
+    pub fn sample_count(&self) -> usize {
+        self.samples
+    }
+
+    fn t(&self) -> i32 {
+        self.stats.query.t
+    }
+
+    counter_accessors! {
+        /// Time spent running the system, in seconds.
+        time_spent => time_spent;
+    }
+
+    gauge_accessors! {
+        /// Number of results matched by the system's query.
+        result_count => query.result_count;
+        /// Number of tables matched by the system's query.
+        matched_table_count => query.matched_table_count;
+        /// Number of entities matched by the system's query.
+        matched_entity_count => query.matched_entity_count;
+    }
+
+    /// Number of frames in which the system was skipped because a condition added with
+    /// [`SystemBuilder::run_if()`](crate::addons::system::SystemBuilder::run_if) was `false`.
+    pub fn skipped(&self) -> CounterView<'_> {
+        CounterView::new(&self.skipped, self.t(), self.samples)
+    }
+
+    /// Returns whether the system is a task (a system without a query that matches entities).
+    pub fn is_task(&self) -> bool {
+        self.stats.task
+    }
+
+    /// The underlying C statistics.
+    pub fn raw(&self) -> &sys::ecs_system_stats_t {
+        &self.stats
+    }
+}
+
+impl core::fmt::Debug for SystemStatsView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SystemStatsView")
+            .field("system", &self.system)
+            .field("samples", &self.samples)
+            .field("time_spent", &self.time_spent().rate().last())
+            .field("skipped", &self.skipped().total())
+            .finish()
+    }
+}
+
+/// Statistics of a merge (sync) point in a pipeline.
+#[derive(Clone, Copy)]
+pub struct SyncStatsView<'a> {
+    stats: &'a sys::ecs_sync_stats_t,
+    t: i32,
+    samples: usize,
+}
+
+impl SyncStatsView<'_> {
+    fn t(&self) -> i32 {
+        self.t
+    }
+
+    counter_accessors! {
+        /// Time spent in the systems before the sync point and the merge, in seconds.
+        time_spent => time_spent;
+        /// Number of commands enqueued before the merge.
+        commands_enqueued => commands_enqueued;
+    }
+
+    /// Number of systems that run before the sync point.
+    pub fn system_count(&self) -> i32 {
+        self.stats.system_count
+    }
+
+    /// Returns whether the systems before the sync point are multi-threaded.
+    pub fn multi_threaded(&self) -> bool {
+        self.stats.multi_threaded
+    }
+
+    /// Returns whether the systems before the sync point are immediate.
+    pub fn immediate(&self) -> bool {
+        self.stats.immediate
+    }
+}
+
+/// Pipeline statistics with a window of [`STAT_WINDOW`] measurements.
+///
+/// Besides the pipeline's own statistics, the view records a [`SystemStatsView`] for every
+/// system in the pipeline.
+pub struct PipelineStatsView {
+    pipeline: Entity,
+    stats: sys::ecs_pipeline_stats_t,
+    systems: Vec<SystemStatsView>,
+    samples: usize,
+}
+
+impl PipelineStatsView {
+    /// Create an empty view for `pipeline` without measurements.
+    pub fn new<'a>(world: impl WorldProvider<'a>, pipeline: impl IntoEntity) -> Self {
+        Self {
+            pipeline: pipeline.into_entity(world),
+            // SAFETY: zeroed pipeline stats are the initial state expected by flecs.
+            stats: unsafe { core::mem::zeroed() },
+            systems: Vec::new(),
+            samples: 0,
+        }
+    }
+
+    /// Take a measurement of the pipeline and its systems.
+    ///
+    /// Returns `false` if the entity is not a pipeline or the pipeline has no systems.
+    pub fn record<'a>(&mut self, world: impl WorldProvider<'a>) -> bool {
+        let recorded = unsafe {
+            sys::ecs_pipeline_stats_get(world.world_ptr_mut(), *self.pipeline, &mut self.stats)
+        };
+        if !recorded {
+            return false;
+        }
+        self.samples = (self.samples + 1).min(STAT_WINDOW);
+
+        let world = world.world();
+        let systems: Vec<Entity> = self.systems().collect();
+        self.systems.retain(|s| systems.contains(&s.system));
+        for system in systems {
+            let index = match self.systems.iter().position(|s| s.system == system) {
+                Some(index) => index,
+                None => {
+                    self.systems.push(SystemStatsView::new(world, system));
+                    self.systems.len() - 1
+                }
+            };
+            self.systems[index].record(world);
+        }
+        true
+    }
+
+    /// The pipeline entity.
+    pub fn pipeline(&self) -> Entity {
+        self.pipeline
+    }
+
+    /// Number of recorded measurements, at most [`STAT_WINDOW`].
+    pub fn sample_count(&self) -> usize {
+        self.samples
+    }
+
+    fn entries(&self) -> &[sys::ecs_entity_t] {
+        let count = self.stats.systems.count;
+        if count <= 0 || self.stats.systems.array.is_null() {
+            return &[];
+        }
+        unsafe {
+            core::slice::from_raw_parts(
+                self.stats.systems.array as *const sys::ecs_entity_t,
+                count as usize,
+            )
+        }
+    }
+
+    /// Active systems in the pipeline, in the order in which they run.
+    pub fn systems(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entries()
+            .iter()
+            .filter(|&&e| e != 0)
+            .map(|&e| Entity::new(e))
+    }
+
+    /// Statistics of a system in the pipeline.
+    pub fn system(&self, system: impl Into<Entity>) -> Option<&SystemStatsView> {
+        let system: Entity = system.into();
+        self.systems.iter().find(|s| s.system == system)
+    }
+
+    /// Statistics of all systems in the pipeline.
+    pub fn system_stats(&self) -> &[SystemStatsView] {
+        &self.systems
+    }
+
+    /// Statistics of the merge points of the pipeline, in the order in which they run.
+    pub fn sync_points(&self) -> impl Iterator<Item = SyncStatsView<'_>> {
+        let count = self.stats.sync_points.count.max(0) as usize;
+        let array = self.stats.sync_points.array as *const sys::ecs_sync_stats_t;
+        // `ecs_pipeline_stats_get` advances `t` after recording
+        let t = t_prev(self.stats.t);
+        let samples = self.samples;
+        (0..count).map(move |i| SyncStatsView {
+            stats: unsafe { &*array.add(i) },
+            t,
+            samples,
+        })
+    }
+
+    /// Number of enabled systems in the pipeline.
+    pub fn system_count(&self) -> usize {
+        self.systems().count()
+    }
+}
+
+impl Drop for PipelineStatsView {
+    fn drop(&mut self) {
+        unsafe { sys::ecs_pipeline_stats_fini(&mut self.stats) };
+    }
+}
+
+impl core::fmt::Debug for PipelineStatsView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PipelineStatsView")
+            .field("pipeline", &self.pipeline)
+            .field("samples", &self.samples)
+            .field("systems", &self.systems)
+            .finish()
+    }
+}
+
+/// Memory usage of the world, measured at a single point in time.
+#[derive(Clone, Copy)]
+pub struct MemoryStatsView {
+    memory: sys::EcsWorldMemory,
+}
+
+impl MemoryStatsView {
+    /// Measure the memory usage of the world.
+    pub fn get<'a>(world: impl WorldProvider<'a>) -> Self {
+        let world = world.world_ptr();
+        // SAFETY: all-zeroes is a valid value for the plain C memory statistics.
+        let mut memory: sys::EcsWorldMemory = unsafe { core::mem::zeroed() };
+        unsafe {
+            memory.entities = sys::ecs_entity_memory_get(world);
+            memory.components = sys::ecs_component_memory_get(world);
+            memory.component_index = sys::ecs_component_index_memory_get(world);
+            memory.queries = sys::ecs_queries_memory_get(world);
+            memory.tables = sys::ecs_tables_memory_get(world);
+            memory.table_histogram = sys::ecs_table_histogram_get(world);
+            memory.misc = sys::ecs_misc_memory_get(world);
+            memory.allocators = sys::ecs_allocator_memory_get(world);
+        }
+        Self { memory }
+    }
+
+    /// Number of alive entities.
+    pub fn entity_count(&self) -> i32 {
+        self.memory.entities.alive_count
+    }
+
+    /// Bytes used by the entity index.
+    pub fn entity_index_bytes(&self) -> usize {
+        self.memory.entities.bytes_entity_index as usize
+    }
+
+    /// Bytes used by entity names.
+    pub fn name_bytes(&self) -> usize {
+        self.memory.entities.bytes_names as usize
+    }
+
+    /// Bytes used by component data stored in tables.
+    pub fn table_component_bytes(&self) -> usize {
+        self.memory.components.bytes_table_components as usize
+    }
+
+    /// Bytes allocated for component data in tables but not in use.
+    pub fn table_component_unused_bytes(&self) -> usize {
+        self.memory.components.bytes_table_components_unused as usize
+    }
+
+    /// Bytes used by sparse component data.
+    pub fn sparse_component_bytes(&self) -> usize {
+        self.memory.components.bytes_sparse_components as usize
+    }
+
+    /// Number of tables.
+    pub fn table_count(&self) -> i32 {
+        self.memory.tables.count
+    }
+
+    /// Bytes used by table storage, excluding component data.
+    pub fn table_bytes(&self) -> usize {
+        let t = &self.memory.tables;
+        (t.bytes_table
+            + t.bytes_type
+            + t.bytes_entities
+            + t.bytes_overrides
+            + t.bytes_column_map
+            + t.bytes_component_map
+            + t.bytes_dirty_state
+            + t.bytes_edges) as usize
+    }
+
+    /// Number of queries.
+    pub fn query_count(&self) -> i32 {
+        self.memory.queries.count
+    }
+
+    /// Bytes used by queries, including caches.
+    pub fn query_bytes(&self) -> usize {
+        let q = &self.memory.queries;
+        (q.bytes_query
+            + q.bytes_cache
+            + q.bytes_group_by
+            + q.bytes_order_by
+            + q.bytes_plan
+            + q.bytes_terms
+            + q.bytes_misc) as usize
+    }
+
+    /// Bytes in use by the flecs allocators.
+    pub fn allocator_bytes(&self) -> usize {
+        let a = &self.memory.allocators;
+        (a.bytes_graph_edge
+            + a.bytes_component_record
+            + a.bytes_pair_record
+            + a.bytes_table_diff
+            + a.bytes_sparse_chunk
+            + a.bytes_allocator
+            + a.bytes_stack_allocator
+            + a.bytes_cmd_entry_chunk
+            + a.bytes_query_impl
+            + a.bytes_query_cache
+            + a.bytes_misc) as usize
+    }
+
+    /// The underlying C statistics.
+    pub fn raw(&self) -> &sys::EcsWorldMemory {
+        &self.memory
+    }
+}
+
+impl core::fmt::Debug for MemoryStatsView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MemoryStatsView")
+            .field("entity_index_bytes", &self.entity_index_bytes())
+            .field("table_component_bytes", &self.table_component_bytes())
+            .field("table_bytes", &self.table_bytes())
+            .field("query_bytes", &self.query_bytes())
+            .field("allocator_bytes", &self.allocator_bytes())
+            .finish()
+    }
+}
+
+/// Statistics of a world at a point in time, see [`World::stats_snapshot()`].
+#[derive(Debug)]
+pub struct StatsSnapshot {
+    /// World statistics.
+    pub world: WorldStatsView,
+    /// Statistics of the active pipeline, `None` if it has no systems.
+    pub pipeline: Option<PipelineStatsView>,
+    /// Memory usage.
+    pub memory: MemoryStatsView,
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::core::*;
+use crate::sys;
+
+/// Number of measurements per second of world time taken by the [`Stats`] module.
+const STATS_MODULE_SAMPLE_RATE: usize = 60;
+
+impl World {
+    /// Take a snapshot of the statistics of the world.
+    ///
+    /// If the [`Stats`] module is imported, the world statistics contain the history collected
+    /// by the module. The module takes 60 measurements per second of world time, combining
+    /// the frames within a measurement interval and repeating the last measurement for
+    /// intervals without a frame, so the history covers the last [`STAT_WINDOW`] intervals
+    /// rather than frames. Otherwise the world statistics contain a single measurement taken
+    /// by this call. The pipeline and memory statistics are always measured by this call.
+    ///
+    /// To build up a history without importing the module, keep a [`WorldStatsView`] and
+    /// call [`WorldStatsView::record()`] every frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// world.import::<stats::Stats>();
+    ///
+    /// world.system::<()>().run(|mut it| while it.next() {});
+    ///
+    /// for _ in 0..5 {
+    ///     world.progress_time(0.1);
+    /// }
+    ///
+    /// let snapshot = world.stats_snapshot();
+    /// assert!(snapshot.world.entity_count().last() > 0.0);
+    /// assert!(snapshot.world.sample_count() > 1);
+    /// assert!(snapshot.pipeline.is_some());
+    /// ```
+    pub fn stats_snapshot(&self) -> StatsSnapshot {
+        let world = match self.module_world_stats() {
+            Some((header, stats)) => WorldStatsView::from_raw(stats, module_sample_count(header)),
+            None => {
+                let mut view = WorldStatsView::new();
+                view.record(self);
+                view
+            }
+        };
+
+        let mut pipeline = PipelineStatsView::new(self, self.get_pipeline());
+        let pipeline = pipeline.record(self).then_some(pipeline);
+
+        StatsSnapshot {
+            world,
+            pipeline,
+            memory: MemoryStatsView::get(self),
+        }
+    }
+
+    /// World statistics collected by the stats module, if imported.
+    fn module_world_stats(&self) -> Option<(&sys::EcsStatsHeader, &sys::ecs_world_stats_t)> {
+        unsafe {
+            let component = sys::FLECS_IDEcsWorldStatsID_;
+            let period = sys::EcsPeriod1s;
+            if component == 0 || period == 0 || !sys::ecs_is_alive(self.ptr_mut(), component) {
+                return None;
+            }
+            let pair = ecs_pair(component, period);
+            let stats = sys::ecs_get_id(self.ptr_mut(), ECS_WORLD, pair) as *const WorldStats;
+            if stats.is_null() || (*stats).stats.is_null() {
+                return None;
+            }
+            Some((&(*stats).hdr, &*(*stats).stats))
+        }
+    }
+}
+
+/// Number of measurements the stats module stored in its window.
+///
+/// The module writes a measurement for every interval of world time that ends, and the frames
+/// of the interval in progress are combined into the current measurement. The frames of the
+/// first interval are combined with the zeroed initial state, so that measurement is skipped.
+fn module_sample_count(header: &sys::EcsStatsHeader) -> usize {
+    // computed like the monitor system of the module computes the position in the window
+    let intervals = (header.elapsed * STATS_MODULE_SAMPLE_RATE as FTime) as usize;
+    intervals.min(STAT_WINDOW)
+}
@@ -4,10 +4,14 @@
 //! query in combination with a callback function. In addition systems have
 //! support for time management, scheduling via pipeline and can be monitored by the stats addon.
 
+pub mod run_condition;
 mod system_builder;
 mod system_runner_fluent;
+mod unscaled_time;
+pub use run_condition::RunCondition;
 pub use system_builder::*;
 pub use system_runner_fluent::*;
+pub use unscaled_time::UnscaledTime;
 
 use core::ops::DerefMut;
 use core::{ffi::c_void, ops::Deref, ptr::NonNull};
@@ -116,14 +120,29 @@ impl<'a> System<'a> {
         }
     }
 
-    /// Set the context for the system
+    /// Set a context value that is owned by the system.
+    ///
+    /// Replaces (and drops) a context set earlier. The value is dropped when the system
+    /// is deleted.
     ///
     /// # Arguments
     ///
     /// * `context` - The context to set.
-    pub fn set_context(&mut self, context: *mut c_void) {
+    ///
+    /// # See also
+    ///
+    /// * [`System::context()`]
+    /// * [`TableIter::context()`]
+    pub fn set_context<C: Send + Sync + 'static>(&mut self, context: C) {
+        let (ctx, ctx_free) = context_into_raw(context);
+        let system = self.system_ptr();
+
+        // pass the current binding contexts along, `ecs_system_update` frees them otherwise
         let desc: sys::ecs_system_desc_t = sys::ecs_system_desc_t {
-            ctx: context,
+            ctx,
+            ctx_free,
+            callback_ctx: unsafe { (*system).callback_ctx },
+            run_ctx: unsafe { (*system).run_ctx },
             ..Default::default()
         };
 
@@ -138,12 +157,27 @@ impl<'a> System<'a> {
         system
     }
 
-    /// Get the context for the system
+    /// Get the context of the system
+    ///
+    /// Returns `None` if no context was set or if it is not of type `C`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity is not a system.
+    ///
+    /// # See also
+    ///
+    /// * [`System::set_context()`]
+    pub fn context<C: 'static>(&self) -> Option<&C> {
+        unsafe { context_from_raw::<C>((*self.system_ptr()).ctx) }
+    }
+
+    /// Get the raw context pointer of the system
     ///
     /// # Panics
     ///
     /// Panics if the entity is not a system.
-    pub fn context(&self) -> *mut c_void {
+    pub fn context_ptr(&self) -> *mut c_void {
         unsafe { (*self.system_ptr()).ctx }
     }
 
@@ -0,0 +1,348 @@
+//! Run conditions, which decide whether a system runs in the current frame.
+//!
+//! Conditions are added to a system with [`SystemBuilder::run_if()`]. They are evaluated once
+//! per frame, before the query of the system is iterated. A system with conditions only runs
+//! if all of its conditions return `true`, and is skipped without iterating its query
+//! otherwise. The number of skipped frames is reported by the stats addon.
+//!
+//! Any `FnMut(&World) -> bool` closure is a [`RunCondition`]. The functions in this module
+//! create conditions for common cases, which can be combined with [`RunCondition::and()`],
+//! [`RunCondition::or()`] and [`RunCondition::not()`].
+//!
+//! # Example
+//!
+//! ```
+//! use flecs_ecs::prelude::*;
+//! use flecs_ecs::addons::system::run_condition::{resource_changed, state_is};
+//!
+//! #[derive(Component, Clone, Copy)]
+//! #[repr(C)]
+//! enum GameState {
+//!     Menu,
+//!     Playing,
+//! }
+//!
+//! #[derive(Component)]
+//! struct Settings {
+//!     volume: f32,
+//! }
+//!
+//! let world = World::new();
+//! world.add_enum(GameState::Menu);
+//! world.set(Settings { volume: 1.0 });
+//!
+//! world
+//!     .system_named::<()>("ApplySettings")
+//!     .run_if(resource_changed::<Settings>(&world).and(state_is(GameState::Playing)))
+//!     .run(|mut it| while it.next() {});
+//! ```
+
+use core::cell::{Cell, UnsafeCell};
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::core::*;
+use crate::sys;
+
+extern crate alloc;
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+
+#[cfg(doc)]
+use crate::addons::system::SystemBuilder;
+
+/// A condition that decides whether a system runs in the current frame.
+///
+/// Implemented for every `FnMut(&World) -> bool + 'static` closure.
+pub trait RunCondition: FnMut(&World) -> bool + 'static {
+    /// Returns a condition that is `true` if both `self` and `other` are `true`.
+    ///
+    /// `other` is not evaluated if `self` is `false`.
+    fn and(mut self, mut other: impl RunCondition) -> impl RunCondition
+    where
+        Self: Sized,
+    {
+        move |world: &World| self(world) && other(world)
+    }
+
+    /// Returns a condition that is `true` if `self` or `other` is `true`.
+    ///
+    /// `other` is not evaluated if `self` is `true`.
+    fn or(mut self, mut other: impl RunCondition) -> impl RunCondition
+    where
+        Self: Sized,
+    {
+        move |world: &World| self(world) || other(world)
+    }
+
+    /// Returns a condition that is `true` if `self` is `false`.
+    fn not(mut self) -> impl RunCondition
+    where
+        Self: Sized,
+    {
+        move |world: &World| !self(world)
+    }
+}
+
+impl<F> RunCondition for F where F: FnMut(&World) -> bool + 'static {}
+
+/// Returns a condition that is `true` if the singleton `T` was set or modified since the
+/// condition was last evaluated.
+///
+/// A change is an `OnSet` event of the singleton, as emitted by [`World::set()`] and by
+/// [`World::modified()`] after writing to it. Writes through a mutable reference without a
+/// call to `modified` are not detected, and changes to `T` on other entities are ignored. The
+/// condition is also `true` the first time it is evaluated after `T` is set.
+pub fn resource_changed<T: ComponentId>(world: &World) -> impl RunCondition {
+    let id = *world.component_id::<T>();
+    let tick = resource_tick(world, id);
+    // change tick of the singleton when the condition was last evaluated
+    let mut seen: Option<u64> = None;
+    move |world: &World| {
+        // SAFETY: the world is alive.
+        let current = unsafe { sys::ecs_has_id(world.world_ptr(), id, id) }.then(|| tick.get());
+        let changed = current.is_some() && current != seen;
+        seen = current;
+        changed
+    }
+}
+
+/// Returns the change tick of the singleton `id`, which is incremented by an `OnSet` observer
+/// on the singleton. The observer is created once per world and component.
+fn resource_tick(world: &World, id: u64) -> Rc<Cell<u64>> {
+    let mut ticks = world.world_ctx().resource_ticks.borrow_mut();
+    ticks
+        .entry(id)
+        .or_insert_with(|| {
+            let tick = Rc::new(Cell::new(0));
+            let observed = tick.clone();
+            world
+                .observer::<flecs::OnSet, ()>()
+                .with(id)
+                .set_src(id)
+                .run(move |mut it| {
+                    while it.next() {
+                        observed.set(observed.get() + 1);
+                    }
+                });
+            tick
+        })
+        .clone()
+}
+
+/// Returns a condition that is `true` if the world has the enum constant `state`, as added
+/// with [`World::add_enum()`].
+pub fn state_is<T>(state: T) -> impl RunCondition
+where
+    T: ComponentId + ComponentType<Enum> + EnumComponentInfo + Copy,
+{
+    move |world: &World| world.has_enum(state)
+}
+
+/// Returns a condition that is `true` once every `seconds` of world time.
+///
+/// World time is scaled by [`World::set_time_scale()`]. If a frame takes longer than
+/// `seconds`, the condition is `true` once for that frame.
+pub fn on_timer(seconds: f32) -> impl RunCondition {
+    let interval = f64::from(seconds);
+    let mut next: Option<f64> = None;
+    move |world: &World| {
+        let now = world.info().world_time_total;
+        let deadline = *next.get_or_insert(now + interval);
+        if now < deadline {
+            return false;
+        }
+        next = Some(if now < deadline + interval {
+            deadline + interval
+        } else {
+            now + interval
+        });
+        true
+    }
+}
+
+/// Returns a condition that is `true` if `query` matches at least one entity.
+pub fn any_match<T: QueryTuple + 'static>(query: Query<T>) -> impl RunCondition {
+    move |_: &World| query.is_true()
+}
+
+/// A type-erased [`RunCondition`], as stored by a system.
+pub(crate) type BoxedRunCondition = Box<dyn FnMut(&World) -> bool>;
+
+/// Run context of a system with conditions. Wraps the run callback of the system.
+pub(crate) struct RunIf {
+    conditions: UnsafeCell<Vec<BoxedRunCondition>>,
+    run: sys::ecs_run_action_t,
+    run_ctx: *mut c_void,
+    run_ctx_free: sys::ecs_ctx_free_t,
+    /// Result of the conditions, and the frame for which they were evaluated.
+    state: Mutex<Evaluation>,
+    evaluated: Condvar,
+    skipped: AtomicU64,
+}
+
+/// Result of the conditions of a [`RunIf`] in a frame.
+struct Evaluation {
+    /// Frame for which `result` was evaluated, or -1.
+    frame: i64,
+    result: bool,
+}
+
+/// Publishes the result of the conditions to the other stages when it is dropped, so they are
+/// released with a `false` result if a condition panics.
+struct Publish<'a> {
+    run_if: &'a RunIf,
+    frame: i64,
+    result: bool,
+}
+
+impl Drop for Publish<'_> {
+    fn drop(&mut self) {
+        let mut state = self.run_if.lock_state();
+        state.frame = self.frame;
+        state.result = self.result;
+        self.run_if.evaluated.notify_all();
+    }
+}
+
+impl RunIf {
+    /// Whether the system runs in the current frame. The main stage evaluates the conditions,
+    /// the other stages of a multithreaded system wait for its result.
+    fn evaluate(&self, world: WorldRef<'_>) -> bool {
+        let frame = world.info().frame_count_total;
+        // SAFETY: `world` is the stage that runs the system.
+        if unsafe { sys::ecs_stage_get_id(world.world_ptr()) } != 0 {
+            let mut state = self.lock_state();
+            while state.frame != frame {
+                state = self
+                    .evaluated
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+            return state.result;
+        }
+
+        let mut publish = Publish {
+            run_if: self,
+            frame,
+            result: false,
+        };
+        // SAFETY: only the main stage accesses the conditions.
+        let conditions = unsafe { &mut *self.conditions.get() };
+        publish.result = conditions.iter_mut().all(|condition| condition(&world));
+        if !publish.result {
+            self.skipped.fetch_add(1, Ordering::Relaxed);
+        }
+        publish.result
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, Evaluation> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for RunIf {
+    fn drop(&mut self) {
+        if let Some(free) = self.run_ctx_free {
+            // SAFETY: the context was handed over by the system descriptor.
+            unsafe { free(self.run_ctx) };
+        }
+    }
+}
+
+/// Makes the system of `desc` run only if all `conditions` are `true`.
+pub(crate) fn wrap_run(desc: &mut sys::ecs_system_desc_t, conditions: Vec<BoxedRunCondition>) {
+    let run_if = Box::new(RunIf {
+        conditions: UnsafeCell::new(conditions),
+        run: desc.run,
+        run_ctx: desc.run_ctx,
+        run_ctx_free: desc.run_ctx_free,
+        state: Mutex::new(Evaluation {
+            frame: -1,
+            result: false,
+        }),
+        evaluated: Condvar::new(),
+        skipped: AtomicU64::new(0),
+    });
+    desc.run = Some(run_if_run);
+    desc.run_ctx = Box::into_raw(run_if) as *mut c_void;
+    desc.run_ctx_free = Some(free_run_if);
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn free_run_if(ptr: *mut c_void) {
+    // SAFETY: the context was created by `wrap_run`.
+    drop(unsafe { Box::from_raw(ptr as *mut RunIf) });
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn run_if_run(it: *mut sys::ecs_iter_t) {
+    // SAFETY: flecs passes the iterator of a system created by `wrap_run`.
+    unsafe {
+        let it = &mut *it;
+        let run_if = &*(it.run_ctx as *const RunIf);
+        let world = WorldRef::from_ptr(it.world);
+        let system = &*sys::ecs_system_get(it.real_world, it.system);
+
+        if !run_if.evaluate(world) {
+            // flecs finalizes the iterator of a query that matches nothing itself
+            if (*system.query).flags & sys::EcsQueryMatchNothing == 0 {
+                sys::ecs_iter_fini(it);
+            }
+            return;
+        }
+
+        run_wrapped(it, system, run_if.run, run_if.run_ctx);
+    }
+}
+
+/// Runs the callback that a wrapper of the run callback of `system` replaced, like flecs runs a
+/// system: with `run` and `run_ctx` if the system has a run callback, and by invoking the
+/// callback for each result of the iterator otherwise.
+///
+/// # Safety
+///
+/// `it` must be the iterator flecs passed to the wrapper, and `system` the system it runs.
+pub(super) unsafe fn run_wrapped(
+    it: &mut sys::ecs_iter_t,
+    system: &sys::ecs_system_t,
+    run: sys::ecs_run_action_t,
+    run_ctx: *mut c_void,
+) {
+    // SAFETY: guaranteed by the caller.
+    unsafe {
+        it.run_ctx = run_ctx;
+        if let Some(run) = run {
+            run(it);
+        } else if (*system.query).term_count != 0 {
+            let callback = it.callback.expect("system has no callback");
+            while sys::ecs_iter_next(it) {
+                callback(it);
+            }
+        } else {
+            let callback = it.callback.expect("system has no callback");
+            callback(it);
+            sys::ecs_iter_fini(it);
+        }
+    }
+}
+
+/// Number of frames in which the conditions of `system` skipped it, or `None` if the system
+/// has no conditions.
+pub(crate) fn skipped_count(world: WorldRef<'_>, system: Entity) -> Option<u64> {
+    // SAFETY: the run context of a system that runs `run_if_run` is a `RunIf`.
+    unsafe {
+        let system = sys::ecs_system_get(world.world_ptr(), *system);
+        let run_if: sys::ecs_run_action_t = Some(run_if_run);
+        let wrapped = !system.is_null()
+            && (*system)
+                .run
+                .zip(run_if)
+                .is_some_and(|(run, run_if)| core::ptr::fn_addr_eq(run, run_if));
+        if !wrapped {
+            return None;
+        }
+        let run_if = &*((*system).run_ctx as *const RunIf);
+        Some(run_if.skipped.load(Ordering::Relaxed))
+    }
+}
@@ -1,5 +1,7 @@
 //! `SystemBuilder` is a builder pattern for creating systems.
 
+#[cfg(feature = "flecs_pipeline")]
+use crate::addons::pipeline::{OrderConstraint, order_builtin_pipeline};
 use crate::addons::system::*;
 use crate::core::internals::*;
 use crate::core::private::{internal_ParSystemAPI, internal_SystemAPI};
@@ -13,9 +15,23 @@ where
     pub(crate) desc: sys::ecs_system_desc_t,
     term_builder: TermBuilder,
     world: WorldRef<'a>,
+    #[cfg(feature = "flecs_pipeline")]
+    order: Vec<OrderConstraint>,
+    conditions: Vec<run_condition::BoxedRunCondition>,
+    unscaled_time: bool,
     _phantom: core::marker::PhantomData<&'a T>,
 }
 
+impl<T: QueryTuple> Drop for SystemBuilder<'_, T> {
+    fn drop(&mut self) {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+        free_desc_context(
+            &mut self.desc.query.group_by_ctx,
+            &mut self.desc.query.group_by_ctx_free,
+        );
+    }
+}
+
 impl<'a, T> SystemBuilder<'a, T>
 where
     T: QueryTuple,
@@ -26,6 +42,10 @@ where
             desc: Default::default(),
             term_builder: TermBuilder::default(),
             world: world.into(),
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
+            conditions: Vec::new(),
+            unscaled_time: false,
             _phantom: core::marker::PhantomData,
         };
 
@@ -44,6 +64,10 @@ where
             desc,
             term_builder: TermBuilder::default(),
             world: world.into(),
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
+            conditions: Vec::new(),
+            unscaled_time: false,
             _phantom: core::marker::PhantomData,
         };
 
@@ -65,6 +89,10 @@ where
             desc: Default::default(),
             term_builder: TermBuilder::default(),
             world: world.into(),
+            #[cfg(feature = "flecs_pipeline")]
+            order: Vec::new(),
+            conditions: Vec::new(),
+            unscaled_time: false,
             _phantom: core::marker::PhantomData,
         };
 
@@ -108,6 +136,231 @@ where
         self.kind(enum_id)
     }
 
+    /// Run the system before another system, or before the systems of a set.
+    ///
+    /// The pipeline sorts the systems of a phase so that they respect the constraints declared
+    /// with [`before()`](Self::before), [`after()`](Self::after) and
+    /// [`in_set()`](Self::in_set). Systems without constraints keep the order they were created
+    /// in. Constraints between systems of different phases have no effect, as phases always
+    /// run in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - the system, or a set created with [`World::system_set()`]
+    ///
+    /// # Panics
+    ///
+    /// Building the system panics if the constraints form a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// let integrate = world.system_named::<()>("Integrate").run(|_| {});
+    /// world
+    ///     .system_named::<()>("Input")
+    ///     .before(integrate)
+    ///     .run(|_| {});
+    ///
+    /// let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    /// let names: Vec<_> = schedule
+    ///     .systems()
+    ///     .map(|system| system.entity().entity_view(&world).name())
+    ///     .collect();
+    /// assert_eq!(names, ["Input", "Integrate"]);
+    /// ```
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn before(&mut self, system: impl IntoEntity) -> &mut Self {
+        let system = system.into_entity(self.world);
+        self.order.push(OrderConstraint::Before(system));
+        self
+    }
+
+    /// Run the system after another system, or after the systems of a set.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - the system, or a set created with [`World::system_set()`]
+    ///
+    /// # See also
+    ///
+    /// * [`SystemBuilder::before()`]
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn after(&mut self, system: impl IntoEntity) -> &mut Self {
+        let system = system.into_entity(self.world);
+        self.order.push(OrderConstraint::After(system));
+        self
+    }
+
+    /// Add the system to a named set.
+    ///
+    /// Other systems can run before or after all systems of the set, by passing the set
+    /// returned by [`World::system_set()`] to [`before()`](Self::before) or
+    /// [`after()`](Self::after). A system can be in multiple sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - the name of the set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system_named::<()>("Render")
+    ///     .after(world.system_set("Physics"))
+    ///     .run(|_| {});
+    /// world
+    ///     .system_named::<()>("Collide")
+    ///     .in_set("Physics")
+    ///     .run(|_| {});
+    ///
+    /// let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    /// let names: Vec<_> = schedule
+    ///     .systems()
+    ///     .map(|system| system.entity().entity_view(&world).name())
+    ///     .collect();
+    /// assert_eq!(names, ["Collide", "Render"]);
+    /// ```
+    #[cfg(feature = "flecs_pipeline")]
+    pub fn in_set(&mut self, set: &str) -> &mut Self {
+        let set = self.world.system_set(set).id();
+        self.order.push(OrderConstraint::InSet(set));
+        self
+    }
+
+    /// Only run the system in frames in which `condition` returns `true`.
+    ///
+    /// Conditions are evaluated once per frame, before the query of the system is iterated,
+    /// so a skipped system doesn't pay the cost of iterating its query. If `run_if` is called
+    /// more than once, the system runs only if all conditions are `true`. Skipped frames are
+    /// counted by [`SystemStatsView::skipped()`](crate::addons::stats::SystemStatsView::skipped).
+    ///
+    /// See [`run_condition`] for conditions that can be combined with
+    /// [`RunCondition::and()`], [`RunCondition::or()`] and [`RunCondition::not()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - the condition, which is passed the world
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Paused;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system_named::<()>("Gameplay")
+    ///     .run_if(|world| !world.has(Paused))
+    ///     .run(|mut it| {
+    ///         while it.next() {
+    ///             // ...
+    ///         }
+    ///     });
+    ///
+    /// world.add(Paused);
+    /// world.progress();
+    /// ```
+    pub fn run_if(&mut self, condition: impl RunCondition) -> &mut Self {
+        self.conditions.push(Box::new(condition));
+        self
+    }
+
+    /// Make the system see the real delta time of the frame.
+    ///
+    /// [`TableIter::delta_time()`] of the system is the time that passed since the last frame,
+    /// unaffected by [`World::set_time_scale()`] and [`World::pause()`]. This is meant for
+    /// systems that keep running at normal speed while gameplay is slowed down or paused, such
+    /// as UI, menus and debug tools. The system gets the [`UnscaledTime`] tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system_named::<()>("Menu")
+    ///     .unscaled_time()
+    ///     .run(|mut it| {
+    ///         assert_eq!(it.delta_time(), 0.5);
+    ///         while it.next() {}
+    ///     });
+    ///
+    /// world.pause();
+    /// world.progress_time(0.5);
+    /// ```
+    pub fn unscaled_time(&mut self) -> &mut Self {
+        self.unscaled_time = true;
+        self
+    }
+
+    /// Creates the system, and registers its ordering constraints.
+    fn build_system(&mut self) -> Result<System<'a>, String> {
+        if self.desc.callback.is_none() && self.desc.run.is_none() {
+            panic!("you should not call this fn manually. Use `.each` , `.run` instead")
+        }
+        // wrapped before the conditions, which skip the system before its delta time is set
+        let mut created_entity = false;
+        if self.unscaled_time {
+            unscaled_time::wrap_run(&mut self.desc);
+            // tagged before the system sets its timer, which is stopped otherwise when the
+            // world is paused
+            if self.desc.entity == 0 {
+                self.desc.entity = unsafe { sys::ecs_new(self.world_ptr_mut()) };
+                created_entity = true;
+            }
+            Entity(self.desc.entity)
+                .entity_view(self.world)
+                .add(UnscaledTime);
+        }
+        if !self.conditions.is_empty() {
+            run_condition::wrap_run(&mut self.desc, core::mem::take(&mut self.conditions));
+        }
+        #[cfg(feature = "flecs_pipeline")]
+        if !self.order.is_empty() {
+            order_builtin_pipeline(self.world);
+        }
+
+        let system = System::new(self.world(), self.desc);
+        // the system owns the contexts now
+        self.desc.ctx = core::ptr::null_mut();
+        take_group_callbacks(&mut self.desc.query);
+        for s in self.term_builder.str_ptrs_to_free.iter_mut() {
+            unsafe { core::mem::ManuallyDrop::drop(s) };
+        }
+        self.term_builder.str_ptrs_to_free.clear();
+
+        if created_entity && *system.id() == 0 {
+            Entity(self.desc.entity).entity_view(self.world).destruct();
+        }
+
+        #[cfg(feature = "flecs_pipeline")]
+        if *system.id() != 0 && !self.order.is_empty() {
+            let result = self.world.world_ctx().system_order.borrow_mut().add(
+                self.world,
+                system.id(),
+                &self.order,
+            );
+            if let Err(cycle) = result {
+                system.destruct();
+                return Err(cycle);
+            }
+        }
+        Ok(system)
+    }
+
     /// Specify whether system should be ran in staged context.
     ///
     /// # Arguments
@@ -118,12 +371,12 @@ where
         self
     }
 
-    /// Attempts to build the system, returning `None` if system creation fails.
+    /// Attempts to build the system, returning an error if system creation fails.
     ///
     /// This is the fallible counterpart of [`build()`](Builder::build): it returns
-    /// `None` instead of a handle to an invalid entity when the underlying
-    /// `ecs_system_init` call fails, most commonly due to an invalid query
-    /// expression passed to `expr()`.
+    /// [`FlecsError::Build`] with the error reported by flecs instead of a handle to an
+    /// invalid entity when the underlying `ecs_system_init` call fails, most commonly due to an
+    /// invalid query expression passed to `expr()`.
     ///
     /// # Panics
     ///
@@ -133,12 +386,19 @@ where
     /// # See also
     ///
     /// * [`QueryBuilder::try_build()`]
-    pub fn try_build(&mut self) -> Option<System<'a>> {
-        let system = self.build();
+    pub fn try_build(&mut self) -> Result<System<'a>, FlecsError> {
+        let (system, message) = capture_errors(|| self.build_system());
+        let system = system.map_err(|message| FlecsError::Build {
+            kind: "system",
+            message,
+        })?;
         if *system.id() == 0 {
-            None
+            Err(FlecsError::Build {
+                kind: "system",
+                message,
+            })
         } else {
-            Some(system)
+            Ok(system)
         }
     }
 }
@@ -183,15 +443,8 @@ where
     #[doc(hidden)]
     /// Build the `system_builder` into an system
     fn build(&mut self) -> Self::BuiltType {
-        if self.desc.callback.is_none() && self.desc.run.is_none() {
-            panic!("you should not call this fn manually. Use `.each` , `.run` instead")
-        }
-        let system = System::new(self.world(), self.desc);
-        for s in self.term_builder.str_ptrs_to_free.iter_mut() {
-            unsafe { core::mem::ManuallyDrop::drop(s) };
-        }
-        self.term_builder.str_ptrs_to_free.clear();
-        system
+        self.build_system()
+            .unwrap_or_else(|cycle| panic!("{cycle}"))
     }
 }
 
@@ -213,6 +466,12 @@ pub struct SystemUpdater<'a, T: QueryTuple = ()> {
     _phantom: core::marker::PhantomData<&'a T>,
 }
 
+impl<T: QueryTuple> Drop for SystemUpdater<'_, T> {
+    fn drop(&mut self) {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+    }
+}
+
 impl<'a, T: QueryTuple> SystemUpdater<'a, T> {
     pub(crate) fn new(entity: EntityView<'a>) -> Self {
         Self {
@@ -238,6 +497,7 @@ where
         unsafe {
             sys::ecs_system_update(self.world.world_ptr_mut(), *self.entity.id(), &self.desc);
         }
+        self.desc.ctx = core::ptr::null_mut();
         System::new_from_existing(self.entity)
     }
 }
@@ -0,0 +1,73 @@
+//! Systems that run on real time, created with [`SystemBuilder::unscaled_time()`].
+
+use core::ffi::c_void;
+
+use crate::sys;
+use flecs_ecs_derive::Component;
+
+extern crate alloc;
+use alloc::boxed::Box;
+
+use super::run_condition::run_wrapped;
+
+#[cfg(doc)]
+use crate::{addons::system::SystemBuilder, core::World};
+
+/// Tag of the systems that see real delta time, added by [`SystemBuilder::unscaled_time()`].
+///
+/// The delta time of these systems is not affected by [`World::set_time_scale()`] and
+/// [`World::pause()`], and pausing the world doesn't stop their timers.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct UnscaledTime;
+
+/// Run context of a system with unscaled time. Wraps the run callback of the system.
+struct Unscaled {
+    run: sys::ecs_run_action_t,
+    run_ctx: *mut c_void,
+    run_ctx_free: sys::ecs_ctx_free_t,
+}
+
+impl Drop for Unscaled {
+    fn drop(&mut self) {
+        if let Some(free) = self.run_ctx_free {
+            // SAFETY: the context was handed over by the system descriptor.
+            unsafe { free(self.run_ctx) };
+        }
+    }
+}
+
+/// Makes the system of `desc` see the real delta time of the frame.
+pub(crate) fn wrap_run(desc: &mut sys::ecs_system_desc_t) {
+    let unscaled = Box::new(Unscaled {
+        run: desc.run,
+        run_ctx: desc.run_ctx,
+        run_ctx_free: desc.run_ctx_free,
+    });
+    desc.run = Some(unscaled_run);
+    desc.run_ctx = Box::into_raw(unscaled) as *mut c_void;
+    desc.run_ctx_free = Some(free_unscaled);
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn free_unscaled(ptr: *mut c_void) {
+    // SAFETY: the context was created by `wrap_run`.
+    drop(unsafe { Box::from_raw(ptr as *mut Unscaled) });
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn unscaled_run(it: *mut sys::ecs_iter_t) {
+    // SAFETY: flecs passes the iterator of a system created by `wrap_run`.
+    unsafe {
+        let it = &mut *it;
+        let unscaled = &*(it.run_ctx as *const Unscaled);
+        let system = &*sys::ecs_system_get(it.real_world, it.system);
+        let delta_time = (*sys::ecs_get_world_info(it.real_world)).delta_time_raw;
+
+        // the time elapsed since the last run is measured by the tick source, if any
+        if system.tick_source == 0 {
+            it.delta_system_time = delta_time;
+        }
+        it.delta_time = delta_time;
+        run_wrapped(it, system, unscaled.run, unscaled.run_ctx);
+    }
+}
@@ -4,7 +4,7 @@ use core::ops::{Deref, DerefMut};
 
 use flecs_ecs_sys::{self as sys};
 
-use crate::core::{ComponentId, Entity, EntityView, WorldProvider, WorldRef};
+use crate::core::{ComponentId, ECS_TIMER, Entity, EntityView, WorldProvider, WorldRef};
 
 use super::super::system::System;
 
@@ -144,6 +144,76 @@ pub trait TimerAPI: Sized {
     fn stop(&self) {
         unsafe { sys::ecs_stop_timer(self.world_ptr_mut(), *self.id()) };
     }
+
+    /// Get the time that passed since the timer was started, or since it last triggered.
+    ///
+    /// # Returns
+    ///
+    /// The elapsed time. If the entity is not a timer, the operation returns 0.
+    fn elapsed(&self) -> f32 {
+        timer_ptr(self).map_or(0.0, |timer| timer.time)
+    }
+
+    /// Get the time until the timer triggers next.
+    ///
+    /// A stopped interval timer keeps its remaining time, also while the world is paused with
+    /// [`World::pause()`](crate::core::World::pause).
+    ///
+    /// # Returns
+    ///
+    /// The remaining time. If the entity is not a timer, or if it is a timeout that has
+    /// triggered or was stopped, the operation returns 0.
+    fn remaining(&self) -> f32 {
+        let Some(timer) = timer_ptr(self) else {
+            return 0.0;
+        };
+        if timer.single_shot && !timer.active && !paused_timer(self) {
+            return 0.0;
+        }
+        (timer.timeout - timer.time).max(0.0)
+    }
+
+    /// Reset timer.
+    /// This operation restarts the current interval or timeout of a timer from zero, without
+    /// starting a stopped timer. If the entity is not a timer, the operation has no effect.
+    fn reset(&self) {
+        // SAFETY: the component is an `EcsTimer`.
+        let timer = unsafe {
+            sys::ecs_get_mut_id(self.world_ptr_mut(), *self.id(), ECS_TIMER) as *mut sys::EcsTimer
+        };
+        if let Some(timer) = unsafe { timer.as_mut() } {
+            timer.time = 0.0;
+            timer.overshoot = 0.0;
+        }
+    }
+}
+
+fn timer_ptr<T: TimerAPI>(timer: &T) -> Option<&sys::EcsTimer> {
+    // SAFETY: the component is an `EcsTimer`.
+    unsafe {
+        (sys::ecs_get_id(timer.world_ptr(), *timer.id(), ECS_TIMER) as *const sys::EcsTimer)
+            .as_ref()
+    }
+}
+
+/// Whether the timer was stopped by [`World::pause()`](crate::core::World::pause).
+fn paused_timer<T: TimerAPI>(timer: &T) -> bool {
+    #[cfg(feature = "flecs_pipeline")]
+    {
+        let id = timer.id();
+        timer
+            .world()
+            .world_ctx()
+            .paused
+            .borrow()
+            .as_ref()
+            .is_some_and(|paused| paused.timers.contains(&id))
+    }
+    #[cfg(not(feature = "flecs_pipeline"))]
+    {
+        let _ = timer;
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
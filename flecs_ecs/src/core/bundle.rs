@@ -0,0 +1,231 @@
+//! Bundles group components so they can be moved onto an entity at once.
+
+use alloc::vec::Vec;
+
+use crate::core::*;
+use crate::sys;
+
+/// A group of components that is inserted into, or removed from, an entity as a whole.
+///
+/// Setting a bundle with [`EntityView::set_bundle`] batches its components, so the entity
+/// moves to its final table in a single archetype transition instead of once per component.
+///
+/// Bundles are implemented with `#[derive(Bundle)]`. Every field must be a component or tag,
+/// or another bundle when annotated with `#[flecs(bundle)]`.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Clone)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone)]
+/// struct Player;
+///
+/// #[derive(Bundle, Clone)]
+/// struct PlayerBundle {
+///     position: Position,
+///     health: Health,
+///     player: Player,
+/// }
+///
+/// let world = World::new();
+///
+/// let bundle = PlayerBundle {
+///     position: Position { x: 1.0, y: 2.0 },
+///     health: Health(100),
+///     player: Player,
+/// };
+///
+/// let player = world.entity().set_bundle(bundle.clone());
+///
+/// assert!(player.has(Position::id()));
+/// assert!(player.has(Player));
+///
+/// bundle.remove_from(player);
+///
+/// assert!(!player.has(Health::id()));
+/// ```
+///
+/// # Panics
+///
+/// Setting a bundle panics if it contains the same component more than once, for example
+/// through a nested bundle.
+pub trait Bundle: Sized {
+    /// Calls `func` with the id of every component in the bundle, registering the components
+    /// with the world if needed.
+    fn for_each_id(world: WorldRef<'_>, func: &mut dyn FnMut(u64));
+
+    /// Writes the components of the bundle through `writer`, in the same order as
+    /// [`Bundle::for_each_id`] visits their ids.
+    #[doc(hidden)]
+    fn insert_into(self, writer: &mut BundleWriter<'_>);
+
+    /// Removes the components of the bundle from `entity` in a single archetype transition.
+    ///
+    /// Only the component types of the bundle are used, its values are left untouched.
+    fn remove_from(&self, entity: EntityView<'_>) {
+        let world = entity.world();
+        let world_ptr = world.world_ptr_mut();
+        // SAFETY: the world pointer is valid for the lifetime of the entity view, and every
+        // id handed to `func` is a registered component of the bundle.
+        unsafe {
+            sys::ecs_defer_begin(world_ptr);
+            Self::for_each_id(world, &mut |id| {
+                sys::ecs_remove_id(world_ptr, *entity.id(), id);
+            });
+            sys::ecs_defer_end(world_ptr);
+        }
+    }
+}
+
+/// Writes the fields of a `#[derive(Bundle)]` struct onto an entity.
+#[doc(hidden)]
+pub struct BundleWriter<'a> {
+    world: WorldRef<'a>,
+    entity: Entity,
+    /// Whether the entity owned each component before the bundle was set, `None` if the world
+    /// is deferred.
+    existing: Option<Vec<bool>>,
+    index: usize,
+}
+
+impl BundleWriter<'_> {
+    #[doc(hidden)]
+    pub fn write<T: ComponentId>(&mut self, value: T) {
+        let world_ptr = self.world.world_ptr_mut();
+        let id = T::entity_id(self.world);
+        let index = self.index;
+        self.index += 1;
+
+        let Some(existing) = &self.existing else {
+            // deferred, the commands for the entity are batched when they are flushed
+            if T::IS_TAG {
+                // SAFETY: the world pointer is valid for 'a, and T is a tag so no data is needed.
+                unsafe { sys::ecs_add_id(world_ptr, *self.entity, id) };
+            } else {
+                set_helper_unchecked(world_ptr, *self.entity, value, id);
+            }
+            return;
+        };
+
+        // Components that were not owned before, including ones inherited through `IsA`, got
+        // storage that was not constructed. Non-fragmenting components are not added in
+        // advance, and are set below.
+        // SAFETY: the world pointer is valid for 'a
+        let emplaced = !T::IS_TAG
+            && !existing[index]
+            && unsafe { sys::ecs_owns_id(world_ptr, *self.entity, id) };
+
+        if emplaced {
+            // SAFETY: the component was added without constructing it, so the storage is
+            // uninitialized and must be written without dropping its previous contents.
+            unsafe {
+                let ptr = sys::ecs_get_mut_id(world_ptr, *self.entity, id) as *mut T;
+                ptr.write(value);
+                sys::ecs_modified_id(world_ptr, *self.entity, id);
+            }
+        } else if !T::IS_TAG {
+            set_helper_unchecked(world_ptr, *self.entity, value, id);
+        }
+    }
+}
+
+/// Moves every bundle onto its entity, each entity changing table once.
+pub(crate) fn insert_bundles<B: Bundle>(
+    world: WorldRef<'_>,
+    entities: impl IntoIterator<Item = Entity>,
+    bundles: impl IntoIterator<Item = B>,
+) {
+    let world_ptr = world.world_ptr_mut();
+    let mut ids = Vec::new();
+    B::for_each_id(world, &mut |id| ids.push(id));
+
+    // a component that is emplaced twice would be written twice without dropping the first value
+    let mut sorted = ids.clone();
+    sorted.sort_unstable();
+    if let Some(duplicate) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        panic!(
+            "bundle contains component `{}` more than once",
+            id_str(world_ptr, duplicate[0])
+        );
+    }
+
+    for (entity, bundle) in entities.into_iter().zip(bundles) {
+        // SAFETY: the world pointer is valid for the lifetime of `world`, and the ids are
+        // registered components of the bundle.
+        let existing = unsafe {
+            let existing = ids
+                .iter()
+                .map(|&id| sys::ecs_owns_id(world_ptr, *entity, id))
+                .collect();
+            sys::ecs_rust_add_ids_emplace(world_ptr, *entity, ids.as_ptr(), ids.len() as i32)
+                .then_some(existing)
+        };
+
+        bundle.insert_into(&mut BundleWriter {
+            world,
+            entity,
+            existing,
+            index: 0,
+        });
+    }
+}
+
+impl<'a> EntityView<'a> {
+    /// Sets all components of a [`Bundle`] on the entity.
+    ///
+    /// The components are batched, so the entity moves to the table with all components of the
+    /// bundle in a single archetype transition. Components the entity already has are assigned.
+    /// When the world is deferred, the components are batched when the commands are flushed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Bundle)]
+    /// struct Movable {
+    ///     position: Position,
+    ///     velocity: Velocity,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let e = world.entity().set_bundle(Movable {
+    ///     position: Position { x: 0.0, y: 0.0 },
+    ///     velocity: Velocity { x: 1.0, y: 1.0 },
+    /// });
+    ///
+    /// assert!(e.has(Velocity::id()));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Bundle::remove_from`]
+    /// * [`World::entity_bulk`] and its `set_bundle_iter`
+    pub fn set_bundle<B: Bundle>(self, bundle: B) -> Self {
+        insert_bundles(self.world, [self.id], [bundle]);
+        self
+    }
+}
@@ -4,6 +4,9 @@
 //! This module provides a basic structure for hooking into the initialization
 //! of that API, which allows, for example, customizing how Flecs sends log
 //! messages.
+//!
+//! With the `flecs_rust_os_api` feature, the OS API is implemented on top of the Rust
+//! standard library instead of the C implementation, see [`rust_os_api`](super::rust_os_api).
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -24,6 +27,9 @@ static OS_API_HOOKS: LazyLock<Mutex<Option<Vec<OsApiHook>>>> =
 
 /// Initialize the Flecs OS API if not initialized already.
 ///
+/// With the `flecs_rust_os_api` feature, the Rust implementation of the OS API is installed
+/// before the hooks run, so hooks can still override individual functions.
+///
 /// If the OS API has already been initialized (e.g. by C code)
 /// hooks will still run but have no effect on the OS API state.
 ///
@@ -45,10 +51,15 @@ pub fn ensure_initialized() {
         flecs_ecs::sys::ecs_os_get_api()
     };
 
+    #[cfg(feature = "flecs_rust_os_api")]
+    super::rust_os_api::install(&mut api);
+
     for h in hooks {
         (h.0)(&mut api);
     }
 
+    crate::core::install_error_capture(&mut api);
+
     unsafe {
         flecs_ecs::sys::ecs_os_set_api(&mut api as *mut _);
     };
@@ -1,3 +1,4 @@
+use crate::core::bundle::insert_bundles;
 use crate::prelude::*;
 use crate::sys;
 
@@ -5,7 +6,9 @@ use crate::sys;
 extern crate std;
 
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
+
+type InsertBundles<'a> = Box<dyn FnOnce(WorldRef<'a>, &[Entity]) + 'a>;
 
 // TODO: Would be great to have a set that sets all the data to the same, no need for multiple entries
 /// A builder for creating multiple entities in bulk, optionally adding components and data.
@@ -18,6 +21,7 @@ pub struct BulkEntityBuilder<'a> {
     entity_ids: Vec<u64>,
     world: WorldRef<'a>,
     current_id_index: u8,
+    bundles: Option<InsertBundles<'a>>,
 }
 
 impl<'a> BulkEntityBuilder<'a> {
@@ -51,6 +55,7 @@ impl<'a> BulkEntityBuilder<'a> {
             data: [core::ptr::null_mut(); sys::FLECS_ID_DESC_MAX as usize],
             entity_ids: Vec::new(),
             current_id_index: 0,
+            bundles: None,
         }
     }
 
@@ -94,6 +99,7 @@ impl<'a> BulkEntityBuilder<'a> {
             data: [core::ptr::null_mut(); sys::FLECS_ID_DESC_MAX as usize],
             entity_ids,
             current_id_index: 0,
+            bundles: None,
         }
     }
 
@@ -219,6 +225,69 @@ impl<'a> BulkEntityBuilder<'a> {
         self
     }
 
+    /// Sets a [`Bundle`] on each of the entities to be created.
+    ///
+    /// The bundles are moved onto the entities after they are created, in the order the
+    /// entities are returned by [`Self::build`]. Each entity moves to its final table in a
+    /// single archetype transition.
+    ///
+    /// # Parameters
+    ///
+    /// - `bundles`: One bundle per entity to be created.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the number of bundles is not equal to the count of entities to be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Bundle)]
+    /// struct EnemyBundle {
+    ///     position: Position,
+    ///     enemy: Enemy,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let entities_created = world
+    ///     .entity_bulk(10)
+    ///     .set_bundle_iter((0..10).map(|i| EnemyBundle {
+    ///         position: Position { x: i, y: 0 },
+    ///         enemy: Enemy,
+    ///     }))
+    ///     .build();
+    /// ```
+    pub fn set_bundle_iter<B: Bundle + 'a>(
+        &mut self,
+        bundles: impl IntoIterator<Item = B>,
+    ) -> &mut Self {
+        let bundles: Vec<B> = bundles.into_iter().collect();
+        assert!(
+            bundles.len() == self.desc.count as usize,
+            "bundle count must be equal to count of entities"
+        );
+        let previous = self.bundles.take();
+        self.bundles = Some(Box::new(move |world, entities: &[Entity]| {
+            if let Some(previous) = previous {
+                previous(world, entities);
+            }
+            insert_bundles(world, entities.iter().copied(), bundles);
+        }));
+        self
+    }
+
     /// build & bulk create the entities and returns a vector of their IDs.
     ///
     /// # Returns
@@ -254,10 +323,14 @@ impl<'a> BulkEntityBuilder<'a> {
         let entities = unsafe { sys::ecs_bulk_init(self.world.world_ptr_mut(), &self.desc) };
         // SAFETY: `ecs_bulk_init` returns a pointer to `desc.count` valid entity ids: either
         // `desc.entities` (owned by self) or a live internal buffer of the world.
-        unsafe { core::slice::from_raw_parts(entities, self.desc.count as usize) }
+        let entities = unsafe { core::slice::from_raw_parts(entities, self.desc.count as usize) }
             .iter()
             .map(|&e| Entity::from(e))
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+        if let Some(insert) = self.bundles.take() {
+            insert(self.world, &entities);
+        }
+        entities
     }
 
     /// Builds & bulk create the entities into the specified table and returns their IDs.
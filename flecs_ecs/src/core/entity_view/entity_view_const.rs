@@ -2006,9 +2006,10 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// The entity if found, otherwise `None`.
+    /// The entity if found, otherwise [`FlecsError::EntityNotFound`].
     #[inline(always)]
-    fn try_lookup_impl(self, name: &str, recursively: bool) -> Option<EntityView<'a>> {
+    fn try_lookup_impl(self, name: &str, recursively: bool) -> Result<EntityView<'a>, FlecsError> {
+        let path = name;
         let name = compact_str::format_compact!("{}\0", name);
 
         ecs_assert!(
@@ -2028,9 +2029,11 @@ impl<'a> EntityView<'a> {
         };
 
         if id == 0 {
-            None
+            Err(FlecsError::EntityNotFound {
+                name: path.to_string(),
+            })
         } else {
-            Some(EntityView::new_from(self.world, id))
+            Ok(EntityView::new_from(self.world, id))
         }
     }
 
@@ -2047,9 +2050,9 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// The entity if found, otherwise `None`.
+    /// The entity if found, otherwise [`FlecsError::EntityNotFound`].
     #[inline(always)]
-    pub fn try_lookup_recursive(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup_recursive(&self, name: &str) -> Result<EntityView<'_>, FlecsError> {
         self.try_lookup_impl(name, true)
     }
 
@@ -2064,9 +2067,9 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// The entity if found, otherwise `None`.
+    /// The entity if found, otherwise [`FlecsError::EntityNotFound`].
     #[inline(always)]
-    pub fn try_lookup(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup(&self, name: &str) -> Result<EntityView<'_>, FlecsError> {
         self.try_lookup_impl(name, false)
     }
 
@@ -2088,7 +2091,7 @@ impl<'a> EntityView<'a> {
     #[inline(always)]
     pub fn lookup_recursive(&self, name: &str) -> EntityView<'_> {
         self.try_lookup_recursive(name)
-            .unwrap_or_else(|| EntityView::new_from(self.world, Entity(0)))
+            .unwrap_or_else(|_| EntityView::new_from(self.world, Entity(0)))
     }
 
     /// Lookup an entity by name, only in the current scope of the entity.
@@ -2104,11 +2107,11 @@ impl<'a> EntityView<'a> {
     ///
     /// # Returns
     ///
-    /// The entity, or entity with id 0 if not found. Use [`try_lookup`](Self::try_lookup) for an `Option` return.
+    /// The entity, or entity with id 0 if not found. Use [`try_lookup`](Self::try_lookup) for a `Result` return.
     #[inline(always)]
     pub fn lookup(&self, name: &str) -> EntityView<'_> {
         self.try_lookup(name)
-            .unwrap_or_else(|| EntityView::new_from(self.world, Entity(0)))
+            .unwrap_or_else(|_| EntityView::new_from(self.world, Entity(0)))
     }
 
     /// Test if an entity has an id.
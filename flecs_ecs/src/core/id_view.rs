@@ -205,7 +205,7 @@ impl<'a> IdView<'a> {
             None
         } else {
             let entity = ecs_first(self.id, self.world);
-            self.world.try_get_alive(entity)
+            self.world.try_get_alive(entity).ok()
         }
     }
 
@@ -241,7 +241,7 @@ impl<'a> IdView<'a> {
             None
         } else {
             let entity = ecs_second(self.id, self.world);
-            self.world.try_get_alive(entity)
+            self.world.try_get_alive(entity).ok()
         }
     }
 
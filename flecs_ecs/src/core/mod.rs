@@ -29,6 +29,7 @@
 
 pub mod archetype;
 pub mod builder;
+mod bundle;
 pub mod c_types;
 pub(crate) mod cloned_tuple;
 pub mod component_registration;
@@ -47,10 +48,14 @@ pub mod query;
 pub mod query_builder;
 pub mod query_iter;
 pub(crate) mod query_tuple;
+#[cfg(feature = "flecs_rust_os_api")]
+pub mod rust_os_api;
 #[cfg(feature = "flecs_safety_locks")]
 mod safety_map;
 pub mod sparse_query;
 pub mod table;
+#[cfg(feature = "flecs_pipeline")]
+pub mod task_pool;
 pub mod term;
 pub(crate) mod tuple_alias;
 pub mod utility;
@@ -60,6 +65,7 @@ pub mod world_ctx;
 pub use archetype::Archetype;
 #[doc(hidden)]
 pub use builder::*;
+pub use bundle::*;
 #[doc(hidden)]
 pub use c_types::*;
 pub use cloned_tuple::ClonedTuple;
@@ -80,7 +86,9 @@ pub use observer::Observer;
 pub use observer_builder::{ObserverBuilder, ObserverUpdater};
 pub use query::{Query, QueryHandle};
 pub use query_builder::*;
-pub use query_iter::{ChainedIter, QueryIter};
+pub use query_iter::{
+    ChainedIter, QueryIter, QueryRowIter, QueryRowMap, QueryTableIter, QueryTableMap,
+};
 #[doc(hidden)]
 pub use query_tuple::*;
 #[cfg(feature = "flecs_safety_locks")]
@@ -89,12 +97,15 @@ pub(crate) use safety_map::*;
 pub use sparse_query::SparseQuery;
 #[doc(hidden)]
 pub use table::*;
+#[cfg(feature = "flecs_pipeline")]
+pub use task_pool::{StdTaskPool, TaskPool, set_task_pool};
 #[doc(hidden)]
 pub use term::*;
 #[doc(hidden)]
 pub use utility::*;
 pub use world::AsyncStage;
 pub(crate) use world::FlecsArray;
+pub use world::Snapshot;
 pub use world::World;
 pub use world::WorldGet;
 pub(crate) use world_ctx::*;
@@ -129,10 +129,29 @@ impl<'a> Observer<'a> {
         ObserverUpdater::new(self.entity)
     }
 
-    /// Set the context for the observer
-    pub fn set_context(&mut self, context: *mut c_void) {
+    /// Set a context value that is owned by the observer.
+    ///
+    /// Replaces (and drops) a context set earlier. The value is dropped when the observer
+    /// is deleted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the observer's entity no longer exists or is not a valid observer.
+    ///
+    /// # See also
+    ///
+    /// * [`Observer::context()`]
+    /// * [`TableIter::context()`]
+    pub fn set_context<C: Send + Sync + 'static>(&mut self, context: C) {
+        let observer = self.observer_ptr();
+        let (ctx, ctx_free) = context_into_raw(context);
+
+        // pass the current binding contexts along, `ecs_observer_update` frees them otherwise
         let desc: sys::ecs_observer_desc_t = sys::ecs_observer_desc_t {
-            ctx: context,
+            ctx,
+            ctx_free,
+            callback_ctx: unsafe { (*observer).callback_ctx },
+            run_ctx: unsafe { (*observer).run_ctx },
             ..Default::default()
         };
 
@@ -141,19 +160,39 @@ impl<'a> Observer<'a> {
         }
     }
 
-    /// Get the context for the observer
-    ///
-    /// # Panics
-    ///
-    /// Panics if the observer's entity no longer exists or is not a valid observer.
-    pub fn context(&self) -> *mut c_void {
-        let observer = unsafe { sys::ecs_observer_get(self.world.world_ptr_mut(), *self.id) };
+    fn observer_ptr(&self) -> *const sys::ecs_observer_t {
+        let observer = unsafe { sys::ecs_observer_get(self.world.world_ptr(), *self.id) };
         assert!(
             !observer.is_null(),
             "observer's entity no longer exists or is not a valid observer"
         );
-        // SAFETY: `observer` was null-checked above and points to a live `ecs_observer_t`.
-        unsafe { (*observer).ctx }
+        observer
+    }
+
+    /// Get the context of the observer
+    ///
+    /// Returns `None` if no context was set or if it is not of type `C`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the observer's entity no longer exists or is not a valid observer.
+    ///
+    /// # See also
+    ///
+    /// * [`Observer::set_context()`]
+    pub fn context<C: 'static>(&self) -> Option<&C> {
+        // SAFETY: `observer_ptr` null-checks and points to a live `ecs_observer_t`.
+        unsafe { context_from_raw::<C>((*self.observer_ptr()).ctx) }
+    }
+
+    /// Get the raw context pointer of the observer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the observer's entity no longer exists or is not a valid observer.
+    pub fn context_ptr(&self) -> *mut c_void {
+        // SAFETY: `observer_ptr` null-checks and points to a live `ecs_observer_t`.
+        unsafe { (*self.observer_ptr()).ctx }
     }
 
     /// Get the query for the observer
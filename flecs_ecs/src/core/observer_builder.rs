@@ -21,6 +21,16 @@ pub struct ObserverBuilder<'a, P = (), T: QueryTuple = ()> {
     _phantom: core::marker::PhantomData<&'a (T, P)>,
 }
 
+impl<P, T: QueryTuple> Drop for ObserverBuilder<'_, P, T> {
+    fn drop(&mut self) {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+        free_desc_context(
+            &mut self.desc.query.group_by_ctx,
+            &mut self.desc.query.group_by_ctx_free,
+        );
+    }
+}
+
 impl<'a, P: ComponentId, T: QueryTuple> ObserverBuilder<'a, P, T> {
     /// Create a new observer builder
     ///
@@ -114,12 +124,12 @@ impl<'a, P, T: QueryTuple> ObserverBuilder<'a, P, T> {
         obj
     }
 
-    /// Attempts to build the observer, returning `None` if observer creation fails.
+    /// Attempts to build the observer, returning an error if observer creation fails.
     ///
     /// This is the fallible counterpart of [`build()`](Builder::build): it returns
-    /// `None` instead of a handle to an invalid entity when the underlying
-    /// `ecs_observer_init` call fails, most commonly due to an invalid query
-    /// expression passed to `expr()`.
+    /// [`FlecsError::Build`] with the error reported by flecs instead of a handle to an
+    /// invalid entity when the underlying `ecs_observer_init` call fails, most commonly due to an
+    /// invalid query expression passed to `expr()`.
     ///
     /// # Panics
     ///
@@ -129,12 +139,15 @@ impl<'a, P, T: QueryTuple> ObserverBuilder<'a, P, T> {
     /// # See also
     ///
     /// * [`QueryBuilder::try_build()`]
-    pub fn try_build(&mut self) -> Option<Observer<'a>> {
-        let observer = self.build();
+    pub fn try_build(&mut self) -> Result<Observer<'a>, FlecsError> {
+        let (observer, message) = capture_errors(|| self.build());
         if *observer.id() == 0 {
-            None
+            Err(FlecsError::Build {
+                kind: "observer",
+                message,
+            })
         } else {
-            Some(observer)
+            Ok(observer)
         }
     }
 }
@@ -231,6 +244,9 @@ where
         }
 
         let observer = Observer::new(self.world(), self.desc);
+        // the observer owns the contexts now
+        self.desc.ctx = core::ptr::null_mut();
+        take_group_callbacks(&mut self.desc.query);
         for s in self.term_builder.str_ptrs_to_free.iter_mut() {
             unsafe { core::mem::ManuallyDrop::drop(s) };
         }
@@ -266,6 +282,12 @@ pub struct ObserverUpdater<'a, P = (), T: QueryTuple = ()> {
     _phantom: core::marker::PhantomData<&'a (T, P)>,
 }
 
+impl<P, T: QueryTuple> Drop for ObserverUpdater<'_, P, T> {
+    fn drop(&mut self) {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+    }
+}
+
 impl<'a, P, T: QueryTuple> ObserverUpdater<'a, P, T> {
     pub(crate) fn new(entity: EntityView<'a>) -> Self {
         Self {
@@ -291,6 +313,7 @@ where
         unsafe {
             sys::ecs_observer_update(self.world.world_ptr_mut(), *self.entity.id(), &self.desc);
         }
+        self.desc.ctx = core::ptr::null_mut();
         Observer::new_from_existing(self.entity)
     }
 }
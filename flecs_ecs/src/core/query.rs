@@ -309,12 +309,17 @@
 //! - [`World::each()`] for quick one-off iterations
 //! - [`TableIter`] for low-level table iteration
 
+use core::any::Any;
 use core::panic;
+use core::panic::AssertUnwindSafe;
 use core::{ffi::c_void, marker::PhantomData, ptr::NonNull};
 
+use alloc::boxed::Box;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use std::sync::{Mutex, PoisonError};
 
+use flecs_ecs_derive::extern_abi;
 use flecs_ecs_sys::ecs_get_binding_ctx;
 use sys::ecs_get_alive;
 
@@ -413,7 +418,8 @@ use crate::sys;
 /// reads world storage that the owning thread may be mutating without
 /// synchronization. To use a query inside multithreaded (`par_*`) system
 /// callbacks, create a thread-shareable handle with [`Query::handle()`] and
-/// iterate it through the callback's stage; see [`QueryHandle`].
+/// iterate it through the callback's stage; see [`QueryHandle`]. To split the
+/// iteration of a query itself across threads, use [`Query::par_each()`].
 ///
 /// # Lifetime and Ownership
 ///
@@ -979,12 +985,12 @@ where
     /// # Panics
     ///
     /// Panics if the flecs OS API has no threading functions installed
-    /// (`flecs_os_api_impl` feature disabled without a custom OS API): the
+    /// (`flecs_os_api_impl` and `flecs_rust_os_api` features disabled without a custom OS API): the
     /// handle's cross-thread reference counting relies on them being atomic.
     pub fn handle(&self) -> QueryHandle<T> {
         assert!(
             unsafe { sys::ecs_os_has_threading() },
-            "QueryHandle requires a flecs OS API with threading functions (enable `flecs_os_api_impl` or install a custom OS API)"
+            "QueryHandle requires a flecs OS API with threading functions (enable `flecs_os_api_impl`, `flecs_rust_os_api` or install a custom OS API)"
         );
         unsafe {
             sys::flecs_poly_claim_(self.query.as_ptr() as *mut c_void);
@@ -1034,6 +1040,19 @@ where
         unsafe { sys::ecs_query_changed(self.query.as_ptr()) }
     }
 
+    /// Get the context of the query
+    ///
+    /// Returns `None` if no context was set or if it is not of type `C`.
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilder::set_context()`]
+    pub fn context<C: 'static>(&self) -> Option<&C> {
+        // SAFETY: `self.query` is a live `ecs_query_t` owned by `self`; its context is
+        // only installed through `QueryBuilder::set_context`.
+        unsafe { context_from_raw::<C>((*self.query.as_ptr()).ctx) }
+    }
+
     /// Get info for group
     ///
     /// # Arguments
@@ -1051,26 +1070,29 @@ where
         unsafe { sys::ecs_query_get_group_info(self.query.as_ptr(), *group_id) }
     }
 
-    /// Get context for group
+    /// Get the context of a group
+    ///
+    /// Returns `None` if the group doesn't exist, has no context, or its context is not of
+    /// type `G`.
     ///
     /// # Arguments
     ///
     /// * `group_id` - The group id to get context for
     ///
-    /// # Returns
+    /// # See also
     ///
-    /// Returns a (void) pointer to the group context
-    pub fn group_context(&self, group_id: impl IntoEntity) -> *mut c_void {
+    /// * [`QueryBuilderImpl::on_group_create()`]
+    pub fn group_context<G: 'static>(&self, group_id: impl IntoEntity) -> Option<&G> {
         let group_info = self.group_info(group_id);
 
-        if !group_info.is_null() {
-            // SAFETY: `group_info` was just checked non-null; it was returned by
-            // `ecs_query_get_group_info` for `self.query`, which is a live query pointer
-            // owned by `self` for the duration of this call, so the pointee is valid to read.
-            unsafe { (*group_info).ctx }
-        } else {
-            core::ptr::null_mut()
+        if group_info.is_null() {
+            return None;
         }
+
+        // SAFETY: `group_info` was just checked non-null; it was returned by
+        // `ecs_query_get_group_info` for `self.query`, which is a live query pointer
+        // owned by `self`. Group contexts are only installed by `on_group_create`.
+        unsafe { context_from_raw::<G>((*group_info).ctx) }
     }
 
     /// Returns true if the entity matches the query.
@@ -1099,9 +1121,10 @@ where
     /// Iterate the query's groups, invoking `func` with each group id and
     /// its group context (as set by `on_group_create`).
     ///
+    /// The context is `None` for groups without a context of type `G`.
     /// Groups exist for tables that have been matched by the query; iterate
     /// the query at least once to populate them.
-    pub fn each_group(&self, mut func: impl FnMut(Entity, *mut core::ffi::c_void)) {
+    pub fn each_group<G: 'static>(&self, mut func: impl FnMut(Entity, Option<&G>)) {
         let map = unsafe { sys::ecs_query_get_groups(self.query.as_ptr()) };
         if map.is_null() {
             return;
@@ -1109,8 +1132,7 @@ where
         let mut it = unsafe { sys::ecs_map_iter(map) };
         while unsafe { sys::ecs_map_next(&mut it) } {
             let key = unsafe { *it.res };
-            let value = unsafe { *it.res.add(1) };
-            func(Entity::new(key), value as usize as *mut core::ffi::c_void);
+            func(Entity::new(key), self.group_context::<G>(key));
         }
     }
 
@@ -1131,6 +1153,341 @@ where
     }
 }
 
+impl<T> Query<T>
+where
+    T: QueryTuple,
+{
+    /// Variant of [`QueryAPI::each`] that iterates the query on multiple threads.
+    ///
+    /// The matched entities are split across the world's stages, one worker per stage, like
+    /// [`QueryAPI::worker()`] does. The number of stages is configured with
+    /// [`World::set_threads()`] or [`World::set_stage_count()`]; with a single stage the query
+    /// is iterated on the calling thread.
+    ///
+    /// The world is in readonly mode while the workers run. Operations that mutate the world
+    /// are deferred to the stage of the worker that issued them, and merged once all workers
+    /// are done.
+    ///
+    /// When the `flecs_safety_locks` feature is enabled, this function performs the same runtime
+    /// checks for mutable component aliasing as [`ParSystemAPI::par_each()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world is already readonly or deferred, for example when called from a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.set_stage_count(4);
+    ///
+    /// for _ in 0..100 {
+    ///     world
+    ///         .entity()
+    ///         .set(Position { x: 0.0, y: 0.0 })
+    ///         .set(Velocity { x: 1.0, y: 2.0 });
+    /// }
+    ///
+    /// let query = world.new_query::<(&mut Position, &Velocity)>();
+    ///
+    /// query.par_each(|(pos, vel)| {
+    ///     pos.x += vel.x;
+    ///     pos.y += vel.y;
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::par_each_entity()`]
+    /// * [`Query::par_run()`]
+    /// * [`ParSystemAPI::par_each()`]
+    pub fn par_each<Func>(&self, func: Func)
+    where
+        Func: Fn(T::TupleType<'_>) + Send + Sync,
+        for<'w> T::TupleType<'w>: Send,
+    {
+        self.par_iter(&|stage, iter| {
+            let mut func = &func;
+
+            #[cfg(feature = "flecs_safety_locks")]
+            if iter.row_fields != 0 {
+                while unsafe { sys::ecs_worker_next(iter) } {
+                    internal_each_iter_next::<T, false, true>(iter, &stage, &mut func);
+                }
+                return;
+            }
+
+            while unsafe { sys::ecs_worker_next(iter) } {
+                internal_each_iter_next::<T, false, false>(iter, &stage, &mut func);
+            }
+        });
+    }
+
+    /// Variant of [`QueryAPI::each_entity`] that iterates the query on multiple threads.
+    ///
+    /// The [`EntityView`] passed to `func` belongs to the stage of the worker, so operations
+    /// on it are deferred until all workers are done. See [`Query::par_each()`] for how the
+    /// work is split.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world is already readonly or deferred, for example when called from a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(i32);
+    ///
+    /// #[derive(Component)]
+    /// struct Dead;
+    ///
+    /// let world = World::new();
+    /// world.set_stage_count(2);
+    ///
+    /// // components can't be registered while the workers run
+    /// world.component::<Dead>();
+    ///
+    /// for i in 0..10 {
+    ///     world.entity().set(Health(i % 2));
+    /// }
+    ///
+    /// world.new_query::<&Health>().par_each_entity(|e, health| {
+    ///     if health.0 == 0 {
+    ///         e.add(Dead);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(world.count(Dead), 5);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::par_each()`]
+    /// * [`ParSystemAPI::par_each_entity()`]
+    pub fn par_each_entity<Func>(&self, func: Func)
+    where
+        Func: Fn(EntityView, T::TupleType<'_>) + Send + Sync,
+        for<'w> T::TupleType<'w>: Send,
+    {
+        self.par_iter(&|stage, iter| {
+            let mut func = &func;
+
+            #[cfg(feature = "flecs_safety_locks")]
+            if iter.row_fields != 0 {
+                while unsafe { sys::ecs_worker_next(iter) } {
+                    internal_each_entity_iter_next::<T, false, true>(iter, &stage, &mut func);
+                }
+                return;
+            }
+
+            while unsafe { sys::ecs_worker_next(iter) } {
+                internal_each_entity_iter_next::<T, false, false>(iter, &stage, &mut func);
+            }
+        });
+    }
+
+    /// Variant of [`QueryAPI::run`] that iterates the query on multiple threads.
+    ///
+    /// `func` is invoked once per worker, with an iterator that only returns the part of the
+    /// matched entities assigned to that worker. See [`Query::par_each()`] for how the work
+    /// is split.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world is already readonly or deferred, for example when called from a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.set_stage_count(2);
+    ///
+    /// for _ in 0..10 {
+    ///     world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// }
+    ///
+    /// let count = AtomicUsize::new(0);
+    ///
+    /// world.new_query::<&Position>().par_run(|mut it| {
+    ///     while it.next() {
+    ///         count.fetch_add(it.count(), Ordering::Relaxed);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(count.into_inner(), 10);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Query::par_each()`]
+    /// * [`ParSystemAPI::par_run()`]
+    pub fn par_run<Func>(&self, func: Func)
+    where
+        Func: Fn(TableIter<true, ()>) + Send + Sync,
+        for<'w> T::TupleType<'w>: Send,
+    {
+        self.par_iter(&|stage, iter| {
+            internal_run::<()>(iter, &mut &func, stage);
+        });
+    }
+
+    /// Runs `job` once for every stage of the world, each on its own thread, with a worker
+    /// iterator for that stage. The calling thread runs the job of stage 0.
+    fn par_iter(&self, job: &(dyn Fn(WorldRef<'_>, &mut sys::ecs_iter_t) + Sync)) {
+        let world = self.world();
+        assert!(
+            !world.is_readonly() && !world.is_deferred(),
+            "parallel query iteration cannot be started while the world is readonly or deferred"
+        );
+
+        let count = world.get_stage_count();
+        let workers: Vec<StageWorker> = (0..count)
+            .map(|index| StageWorker {
+                world: world.world_ptr_mut(),
+                query: self.query.as_ptr(),
+                index,
+                count,
+                job,
+                panic: Mutex::new(None),
+            })
+            .collect();
+
+        // joins the worker threads and leaves readonly mode, also when starting a thread panics
+        let mut guard = StageWorkersGuard {
+            world: world.world_ptr_mut(),
+            threads: Vec::with_capacity(workers.len().saturating_sub(1)),
+            join: None,
+        };
+
+        world.readonly_begin(count > 1);
+
+        if count > 1 {
+            let (thread_new, thread_join) = unsafe {
+                match (sys::ecs_os_api.task_new_, sys::ecs_os_api.task_join_) {
+                    (Some(task_new), Some(task_join)) => (Some(task_new), Some(task_join)),
+                    _ => (sys::ecs_os_api.thread_new_, sys::ecs_os_api.thread_join_),
+                }
+            };
+            let (Some(thread_new), Some(_)) = (thread_new, thread_join) else {
+                panic!("parallel query iteration requires an OS API with thread support");
+            };
+            guard.join = thread_join;
+
+            for worker in &workers[1..] {
+                let thread = unsafe {
+                    thread_new(
+                        Some(stage_worker),
+                        worker as *const StageWorker as *mut c_void,
+                    )
+                };
+                guard.threads.push(thread);
+            }
+        }
+
+        workers[0].run();
+
+        // resume a panic of a stage once all stages have finished
+        drop(guard);
+        for worker in &workers {
+            let panic = worker
+                .panic
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            if let Some(payload) = panic {
+                std::panic::resume_unwind(payload);
+            }
+        }
+    }
+}
+
+/// A worker of [`Query::par_iter`], iterating the part of the query assigned to one stage.
+struct StageWorker<'f> {
+    world: *mut sys::ecs_world_t,
+    query: *mut sys::ecs_query_t,
+    index: i32,
+    count: i32,
+    job: &'f (dyn Fn(WorldRef<'_>, &mut sys::ecs_iter_t) + Sync),
+    /// Payload of a panic in the job, caught on the thread of the stage so it doesn't unwind
+    /// into the thread entry of the OS API.
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl StageWorker<'_> {
+    fn run(&self) {
+        unsafe {
+            let stage = sys::ecs_get_stage(self.world, self.index);
+            // the worker iterator keeps a pointer to the query iterator, so it can't be moved
+            let query_iter = sys::ecs_query_iter(stage, self.query);
+            let mut iter = sys::ecs_worker_iter(&query_iter, self.index, self.count);
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                (self.job)(WorldRef::from_ptr(stage), &mut iter);
+            }));
+
+            if let Err(payload) = result {
+                // ecs_query_next sets EcsIterSkip when it finishes the query, before that the
+                // iterator still holds memory of the stage. Tables aren't locked in readonly mode.
+                if query_iter.flags & sys::EcsIterSkip == 0 {
+                    sys::ecs_iter_fini(&mut iter);
+                }
+                *self.panic.lock().unwrap_or_else(PoisonError::into_inner) = Some(payload);
+            }
+        }
+    }
+}
+
+#[extern_abi]
+fn stage_worker(worker: *mut c_void) -> *mut c_void {
+    // SAFETY: `Query::par_iter` passes a `StageWorker` that outlives the thread, as the
+    // thread is joined by `StageWorkersGuard` before the workers are dropped.
+    let worker = unsafe { &*(worker as *const StageWorker) };
+    worker.run();
+    core::ptr::null_mut()
+}
+
+struct StageWorkersGuard {
+    world: *mut sys::ecs_world_t,
+    threads: Vec<sys::ecs_os_thread_t>,
+    join: sys::ecs_os_api_thread_join_t,
+}
+
+impl Drop for StageWorkersGuard {
+    fn drop(&mut self) {
+        if let Some(join) = self.join {
+            for thread in self.threads.drain(..) {
+                unsafe { join(thread) };
+            }
+        }
+        unsafe { sys::ecs_readonly_end(self.world) };
+    }
+}
+
 impl<T: QueryTuple> From<&Query<T>> for NonNull<sys::ecs_query_t> {
     #[inline]
     fn from(q: &Query<T>) -> Self {
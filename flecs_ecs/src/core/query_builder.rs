@@ -250,7 +250,7 @@
 //!
 //! # let world = World::new();
 //! // Create a query grouped by the Group relationship
-//! let query = world.query::<&Position>().group_by(Group).build();
+//! let query = world.query::<&Position>().group_by_target(Group).build();
 //!
 //! // Create entities with different group targets
 //! world
@@ -312,9 +312,12 @@ use crate::sys;
 extern crate std;
 
 extern crate alloc;
-use alloc::{format, vec::Vec};
+use alloc::{boxed::Box, format, vec::Vec};
+use core::ptr::NonNull;
 use flecs_ecs_derive::extern_abi;
 
+use crate::core::utility::context::{context_into_raw, free_context, free_desc_context};
+
 /// Builder for constructing complex [`Query`] objects.
 ///
 /// `QueryBuilder` provides a fluent interface for incrementally building queries with
@@ -402,7 +405,7 @@ use flecs_ecs_derive::extern_abi;
 /// - [`with()`](QueryBuilder::with) / [`without()`](QueryBuilder::without) - Add/exclude components
 /// - [`set_cache_kind()`](QueryBuilder::set_cache_kind) - Control caching behavior
 /// - [`order_by()`](QueryBuilder::order_by) - Sort results
-/// - [`group_by()`](QueryBuilder::group_by) - Group results
+/// - [`group_by()`](QueryBuilder::group_by) / [`group_by_target()`](QueryBuilder::group_by_target) - Group results
 ///
 /// # See Also
 ///
@@ -420,6 +423,16 @@ where
     _phantom: core::marker::PhantomData<T>,
 }
 
+impl<T: QueryTuple> Drop for QueryBuilder<'_, T> {
+    fn drop(&mut self) {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+        free_desc_context(
+            &mut self.desc.group_by_ctx,
+            &mut self.desc.group_by_ctx_free,
+        );
+    }
+}
+
 impl<T: QueryTuple> core::fmt::Debug for QueryBuilder<'_, T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("QueryBuilder")
@@ -610,6 +623,9 @@ where
     fn build(&mut self) -> Self::BuiltType {
         let world = self.world;
         let query = Query::<T>::new_from_desc(world, &mut self.desc);
+        // the query owns the contexts now
+        self.desc.ctx = core::ptr::null_mut();
+        take_group_callbacks(&mut self.desc);
         for s in self.term_builder.str_ptrs_to_free.iter_mut() {
             unsafe { ManuallyDrop::drop(s) };
         }
@@ -619,9 +635,43 @@ where
 }
 
 impl<'a, T: QueryTuple> QueryBuilder<'a, T> {
-    /// Attempts to build the query, returning `None` if the query is invalid.
+    /// Set a context value that is owned by the query.
+    ///
+    /// The value is dropped when the query is deleted, or when the builder is dropped without
+    /// being built. Read it back with [`Query::context()`] or [`TableIter::context()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world
+    ///     .query::<&Position>()
+    ///     .set_context(String::from("positions"))
+    ///     .build();
+    ///
+    /// assert_eq!(query.context::<String>().unwrap(), "positions");
+    /// assert!(query.context::<u32>().is_none());
+    /// ```
+    pub fn set_context<C: Send + Sync + 'static>(&mut self, context: C) -> &mut Self {
+        free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+        let (ctx, ctx_free) = context_into_raw(context);
+        self.desc.ctx = ctx;
+        self.desc.ctx_free = ctx_free;
+        self
+    }
+
+    /// Attempts to build the query, returning an error if the query is invalid.
     ///
-    /// This is a fallible version of [`build()`](Builder::build) that returns `None`
+    /// This is a fallible version of [`build()`](Builder::build) that returns an error
     /// instead of panicking when query creation fails. Query creation can fail for
     /// several reasons, most commonly:
     /// - Invalid query expression syntax (when using `expr()`)
@@ -629,8 +679,8 @@ impl<'a, T: QueryTuple> QueryBuilder<'a, T> {
     ///
     /// # Returns
     ///
-    /// * `Some(Query<T>)` - Successfully created query
-    /// * `None` - Query creation failed
+    /// * `Ok(Query<T>)` - Successfully created query
+    /// * `Err(FlecsError::Build)` - Query creation failed, with the error reported by flecs
     ///
     /// # Example
     ///
@@ -645,43 +695,36 @@ impl<'a, T: QueryTuple> QueryBuilder<'a, T> {
     /// // Valid query
     /// let valid_query = world.query::<&Position>()
     ///     .try_build();
-    /// assert!(valid_query.is_some());
+    /// assert!(valid_query.is_ok());
     ///
     /// // Invalid query expression
     /// let invalid_query = world.query::<()>()
     ///     .expr("invalid syntax!!!")
     ///     .try_build();
-    /// assert!(invalid_query.is_none());
+    /// assert!(invalid_query.is_err());
     /// ```
     ///
     /// # See also
     ///
     /// * [`build()`](Builder::build) - Panicking version that fails fast on invalid queries
-    pub fn try_build(&mut self) -> Option<Query<T>> {
+    pub fn try_build(&mut self) -> Result<Query<T>, FlecsError> {
         let world = self.world;
-        let query = Query::<T>::try_new_from_desc(world, &mut self.desc)?;
+        let (query, message) =
+            capture_errors(|| Query::<T>::try_new_from_desc(world, &mut self.desc));
+        // flecs owns the context now, also when the query is invalid
+        self.desc.ctx = core::ptr::null_mut();
+        let query = query.ok_or(FlecsError::Build {
+            kind: "query",
+            message,
+        })?;
         for s in self.term_builder.str_ptrs_to_free.iter_mut() {
             unsafe { ManuallyDrop::drop(s) };
         }
         self.term_builder.str_ptrs_to_free.clear();
-        Some(query)
+        Ok(query)
     }
 }
 
-//this doesn't work because world ptr gets misaligned
-// Assuming some imports and definitions from your previous example, and adding the required ones for this example.
-// #[cfg(not(target_family = "wasm"))]
-// type GroupByFn = extern "C-unwind" fn(
-//     *mut sys::ecs_world_t,
-//     *mut sys::ecs_table_t,
-//     sys::ecs_id_t,
-//     *mut c_void,
-// ) -> u64;
-
-// #[cfg(target_family = "wasm")]
-// type GroupByFn =
-//     extern "C" fn(*mut sys::ecs_world_t, *mut sys::ecs_table_t, sys::ecs_id_t, *mut c_void) -> u64;
-
 // Type definitions for OrderBy function pointers
 #[cfg(not(target_family = "wasm"))]
 type OrderByFnPtr<T> = extern "C-unwind" fn(Entity, &T, Entity, &T) -> i32;
@@ -1174,66 +1217,258 @@ pub trait QueryBuilderImpl<'a>: TermBuilderImpl<'a> {
     /// # Arguments
     ///
     /// * `component`: The component used to determine the group rank.
-    /// * `group_by_action`: Callback that determines group id for table.
-    fn group_by_fn(
+    /// * `group_by_action`: Callback that determines the group id for a table. It is invoked
+    ///   with the world, the matched table and `component`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Group;
+    ///
+    /// let world = World::new();
+    ///
+    /// // group tables by the target of their (Group, *) pair
+    /// let query = world
+    ///     .query::<&Position>()
+    ///     .group_by(Group, |_world, table, id| {
+    ///         table
+    ///             .find_type_index((*id, flecs::Wildcard::ID))
+    ///             .and_then(|index| table.archetype().get(index as usize).map(|pair| *pair.second_id().id()))
+    ///             .unwrap_or(0)
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::group_by_target()`]
+    /// * [`QueryBuilderImpl::on_group_create()`]
+    fn group_by(
         &mut self,
         component: impl IntoEntity,
-        group_by_action: sys::ecs_group_by_action_t,
+        group_by_action: impl Fn(&World, Table, Id) -> u64 + 'static,
     ) -> &mut Self {
         let world = self.world();
         let desc = self.query_desc_mut();
-        desc.group_by_callback = group_by_action;
+        group_callbacks(desc).group_by = Some(Box::new(group_by_action));
+        desc.group_by_callback = Some(group_by_trampoline);
         desc.group_by = *component.into_entity(world);
         self
     }
 
     /// Group and sort matched tables.
     ///
-    /// This is similar to `group_by_default<T>`, but uses a component identifier instead.
+    /// This is similar to [`group_by()`](QueryBuilderImpl::group_by), but uses the
+    /// default grouping, which groups tables by the target of the `(component, *)` pair.
     ///
     /// # Arguments
     ///
     /// * `component`: The component used to determine the group rank.
-    fn group_by(&mut self, component: impl IntoEntity) -> &mut Self {
-        self.group_by_fn(component, None)
-    }
-
-    /// Specify context to be passed to the `group_by` function.
-    ///
-    /// # Arguments
-    ///
-    /// * `ctx`: Context to pass to the `group_by` function.
-    /// * `ctx_free`: Function to clean up the context (called when the query is deleted).
-    fn group_by_ctx(&mut self, ctx: *mut c_void, ctx_free: sys::ecs_ctx_free_t) -> &mut Self {
+    fn group_by_target(&mut self, component: impl IntoEntity) -> &mut Self {
+        let world = self.world();
         let desc = self.query_desc_mut();
-        desc.group_by_ctx = ctx;
-        desc.group_by_ctx_free = ctx_free;
+        desc.group_by_callback = None;
+        desc.group_by = *component.into_entity(world);
         self
     }
 
-    /// Specify the `on_group_create` action.
+    /// Specify the action to execute when a group is created.
+    ///
+    /// The value returned by `action` is the context of the group. It is owned by the
+    /// query, dropped when the group is deleted, and can be read back with
+    /// [`Query::group_context()`] and [`Query::each_group()`].
     ///
     /// # Arguments
     ///
-    /// * `action`: The action to execute when a group is created.
-    fn on_group_create(&mut self, action: sys::ecs_group_create_action_t) -> &mut Self {
+    /// * `action`: The action to execute when a group is created. It is invoked with the
+    ///   world and the group id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Group;
+    ///
+    /// #[derive(Component)]
+    /// struct First;
+    ///
+    /// let world = World::new();
+    ///
+    /// let query = world
+    ///     .query::<&Position>()
+    ///     .group_by_target(Group)
+    ///     .on_group_create(|world, group_id| {
+    ///         // Tables without a `(Group, *)` pair end up in group 0
+    ///         if group_id == 0 {
+    ///             String::new()
+    ///         } else {
+    ///             world.entity_from_id(group_id).name()
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    /// world
+    ///     .entity()
+    ///     .set(Position { x: 1.0, y: 2.0 })
+    ///     .add((Group, First));
+    ///
+    /// query.run(|mut it| {
+    ///     while it.next() {
+    ///         if it.group_id() != 0 {
+    ///             let name = query.group_context::<String>(it.group_id()).unwrap();
+    ///             assert_eq!(name, "First");
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryBuilderImpl::on_group_delete()`]
+    /// * [`Query::group_context()`]
+    fn on_group_create<G: Send + Sync + 'static>(
+        &mut self,
+        action: impl Fn(&World, u64) -> G + 'static,
+    ) -> &mut Self {
         let desc = self.query_desc_mut();
-        desc.on_group_create = action;
+        let callbacks = group_callbacks(desc);
+        callbacks.on_create = Some(Box::new(move |world, group_id| {
+            context_into_raw(action(world, group_id)).0
+        }));
+        callbacks.group_ctx_free = Some(free_context::<G>);
+        desc.on_group_create = Some(group_create_trampoline);
+        desc.on_group_delete = Some(group_delete_trampoline);
         self
     }
 
-    /// Specify the `on_group_delete` action.
+    /// Specify the action to execute when a group is deleted.
+    ///
+    /// The context created by [`on_group_create()`](QueryBuilderImpl::on_group_create) is
+    /// dropped after `action` returns.
     ///
     /// # Arguments
     ///
-    /// * `action`: The action to execute when a group is deleted.
-    fn on_group_delete(&mut self, action: sys::ecs_group_delete_action_t) -> &mut Self {
+    /// * `action`: The action to execute when a group is deleted. It is invoked with the
+    ///   world and the group id.
+    fn on_group_delete(&mut self, action: impl Fn(&World, u64) + 'static) -> &mut Self {
         let desc = self.query_desc_mut();
-        desc.on_group_delete = action;
+        group_callbacks(desc).on_delete = Some(Box::new(action));
+        desc.on_group_delete = Some(group_delete_trampoline);
         self
     }
 }
 
+type GroupByFn = dyn Fn(&World, Table, Id) -> u64;
+type GroupCreateFn = dyn Fn(&World, u64) -> *mut c_void;
+type GroupDeleteFn = dyn Fn(&World, u64);
+
+/// The closures passed to the group functions of [`QueryBuilderImpl`].
+///
+/// Installed as the `group_by_ctx` of the query, which flecs passes to all group callbacks.
+#[derive(Default)]
+struct GroupCallbacks {
+    group_by: Option<Box<GroupByFn>>,
+    on_create: Option<Box<GroupCreateFn>>,
+    on_delete: Option<Box<GroupDeleteFn>>,
+    group_ctx_free: sys::ecs_ctx_free_t,
+}
+
+/// Returns the group callbacks of `desc`, installing them on first use.
+fn group_callbacks(desc: &mut sys::ecs_query_desc_t) -> &mut GroupCallbacks {
+    if desc.group_by_ctx.is_null() {
+        desc.group_by_ctx = Box::into_raw(Box::<GroupCallbacks>::default()) as *mut c_void;
+        desc.group_by_ctx_free = Some(free_group_callbacks);
+    }
+    // SAFETY: `group_by_ctx` is only ever set here, to a `GroupCallbacks`.
+    unsafe { &mut *(desc.group_by_ctx as *mut GroupCallbacks) }
+}
+
+/// Clears the group callbacks of `desc` once they are handed to the query built from it.
+///
+/// The query owns the closures, so a query built again from `desc` has no group callbacks.
+pub(crate) fn take_group_callbacks(desc: &mut sys::ecs_query_desc_t) {
+    if desc.group_by_ctx.is_null() {
+        return;
+    }
+    desc.group_by_ctx = core::ptr::null_mut();
+    desc.group_by_ctx_free = None;
+    desc.group_by_callback = None;
+    desc.on_group_create = None;
+    desc.on_group_delete = None;
+}
+
+#[extern_abi]
+fn free_group_callbacks(ptr: *mut c_void) {
+    drop(unsafe { Box::from_raw(ptr as *mut GroupCallbacks) });
+}
+
+#[extern_abi]
+fn group_by_trampoline(
+    world: *mut sys::ecs_world_t,
+    table: *mut sys::ecs_table_t,
+    id: sys::ecs_id_t,
+    ctx: *mut c_void,
+) -> u64 {
+    let callbacks = unsafe { &*(ctx as *const GroupCallbacks) };
+    let world = unsafe { WorldRef::from_ptr(world) };
+    let table = unsafe { Table::new(world, NonNull::new_unchecked(table)) };
+    let group_by = callbacks
+        .group_by
+        .as_ref()
+        .expect("group_by callback not set");
+    group_by(&world, table, Id(id))
+}
+
+#[extern_abi]
+fn group_create_trampoline(
+    world: *mut sys::ecs_world_t,
+    group_id: u64,
+    group_by_ctx: *mut c_void,
+) -> *mut c_void {
+    let callbacks = unsafe { &*(group_by_ctx as *const GroupCallbacks) };
+    let world = unsafe { WorldRef::from_ptr(world) };
+    match &callbacks.on_create {
+        Some(on_create) => on_create(&world, group_id),
+        None => core::ptr::null_mut(),
+    }
+}
+
+#[extern_abi]
+fn group_delete_trampoline(
+    world: *mut sys::ecs_world_t,
+    group_id: u64,
+    group_ctx: *mut c_void,
+    group_by_ctx: *mut c_void,
+) {
+    let callbacks = unsafe { &*(group_by_ctx as *const GroupCallbacks) };
+    let world = unsafe { WorldRef::from_ptr(world) };
+    if let Some(on_delete) = &callbacks.on_delete {
+        on_delete(&world, group_id);
+    }
+    if !group_ctx.is_null()
+        && let Some(free) = callbacks.group_ctx_free
+    {
+        unsafe { free(group_ctx) };
+    }
+}
+
 pub trait OrderByFn<T>
 where
     T: ComponentId,
@@ -1,7 +1,10 @@
 //! Class that extends the capabilities of a [`Query`] by providing additional operations on the query's iterator.
+use alloc::boxed::Box;
 use core::ffi::c_void;
 
 use crate::core::*;
+#[cfg(feature = "flecs_safety_locks")]
+use crate::core::{DECREMENT, INCREMENT, do_read_write_locks};
 use crate::sys;
 
 /// An iterator over a query, bound to the world or stage it was created with.
@@ -402,3 +405,355 @@ where
         unsafe { WorldRef::from_ptr((*self.parent.get()).world) }
     }
 }
+
+/// A lending iterator over the entities matched by a query, created with [`QueryAPI::iter`].
+///
+/// Each call to [`next`](Self::next) returns the entity and its components. The components
+/// borrow the iterator, so only one row is live at a time, which lets the iterator hold the
+/// table and component locks of the current table until it moves on to the next table.
+/// Dropping the iterator before it is exhausted releases the locks and the underlying flecs
+/// iterator, so it is safe to `break` out of a `while let` loop.
+///
+/// To use [`Iterator`] adapters such as `zip`, `filter_map`, `take_while` or `collect`,
+/// turn the rows into owned values with [`map`](Self::map).
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Debug)]
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let world = World::new();
+/// world.entity().set(Position { x: 1, y: 2 });
+/// world.entity().set(Position { x: 3, y: 4 });
+///
+/// let query = world.new_query::<&mut Position>();
+///
+/// let mut rows = query.iter();
+/// while let Some((_entity, pos)) = rows.next() {
+///     pos.x += 10;
+/// }
+///
+/// let xs: Vec<i32> = query.iter().map(|_, pos| pos.x).collect();
+/// assert_eq!(xs, [11, 13]);
+/// ```
+pub struct QueryRowIter<'a, P, T>
+where
+    T: QueryTuple,
+{
+    /// Boxed, as chained iterators keep pointers into the iterator they are created from.
+    iter: Box<sys::ecs_iter_t>,
+    iter_next: ExternIterNextFn,
+    world: WorldRef<'a>,
+    /// Component pointers of the current table, while its locks are held.
+    pointers: Option<(IsAnyArray, T::Pointers)>,
+    row: usize,
+    count: usize,
+    #[cfg(feature = "flecs_safety_locks")]
+    any_sparse_terms: bool,
+    done: bool,
+    _phantom: core::marker::PhantomData<&'a P>,
+}
+
+impl<'a, P, T> QueryRowIter<'a, P, T>
+where
+    T: QueryTuple,
+{
+    pub(crate) fn new(
+        iter: sys::ecs_iter_t,
+        iter_next: ExternIterNextFn,
+        world: WorldRef<'a>,
+    ) -> Self {
+        const {
+            assert!(
+                !T::CONTAINS_ANY_TAG_TERM,
+                "a type provided in the query signature is a Tag and cannot be used with \
+                 `.iter`. use `.iter_tables` instead or provide the tag with `.with()`"
+            );
+        }
+
+        Self {
+            #[cfg(feature = "flecs_safety_locks")]
+            any_sparse_terms: iter.row_fields != 0,
+            iter: Box::new(iter),
+            iter_next,
+            world,
+            pointers: None,
+            row: 0,
+            count: 0,
+            done: false,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Advance to the next row, returning the entity and its components.
+    ///
+    /// Returns `None` once all matched entities have been returned.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(EntityView<'a>, T::TupleType<'_>)> {
+        while self.row >= self.count {
+            if !self.next_table() {
+                return None;
+            }
+        }
+
+        let row = self.row;
+        self.row += 1;
+
+        let entity = EntityView::new_from(self.world, unsafe { *self.iter.entities.add(row) });
+        let (is_any_array, pointers) = self
+            .pointers
+            .as_mut()
+            .expect("table locks are held while rows are left");
+        let tuple = if !is_any_array.a_ref && !is_any_array.a_row {
+            pointers.get_tuple(row)
+        } else if is_any_array.a_row {
+            pointers.get_tuple_with_row(&self.iter, row)
+        } else {
+            pointers.get_tuple_with_ref(row)
+        };
+        Some((entity, tuple))
+    }
+
+    /// Turn the rows into an [`Iterator`] over the values returned by `func`.
+    ///
+    /// `func` cannot return the components it is passed, which keeps the components from
+    /// outliving the locks of their table.
+    pub fn map<F, R>(self, func: F) -> QueryRowMap<'a, P, T, F>
+    where
+        F: FnMut(EntityView<'a>, T::TupleType<'_>) -> R,
+    {
+        QueryRowMap { rows: self, func }
+    }
+
+    /// Release the current table and move to the next one, acquiring its locks.
+    fn next_table(&mut self) -> bool {
+        self.release_table();
+        if self.done {
+            return false;
+        }
+        if !unsafe { (self.iter_next)(&mut *self.iter) } {
+            // the iterator released its resources when it ran to completion
+            self.done = true;
+            return false;
+        }
+
+        self.iter.flags |= sys::EcsIterCppEach;
+        ecs_assert!(
+            !self.iter.entities.is_null(),
+            FlecsErrorCode::InvalidOperation,
+            "query does not return entities ($this variable is not populated)"
+        );
+
+        let (is_any_array, pointers) = T::create_ptrs(&self.iter);
+
+        #[cfg(feature = "flecs_safety_locks")]
+        if self.any_sparse_terms {
+            do_read_write_locks::<INCREMENT, true, T>(&self.world, pointers.safety_table_records());
+        } else {
+            do_read_write_locks::<INCREMENT, false, T>(
+                &self.world,
+                pointers.safety_table_records(),
+            );
+        }
+        table_lock(self.iter.world, self.iter.table);
+
+        self.pointers = Some((is_any_array, pointers));
+        self.row = 0;
+        self.count = self.iter.count as usize;
+        true
+    }
+
+    fn release_table(&mut self) {
+        let Some((_, _pointers)) = self.pointers.take() else {
+            return;
+        };
+
+        table_unlock(self.iter.world, self.iter.table);
+        #[cfg(feature = "flecs_safety_locks")]
+        if self.any_sparse_terms {
+            do_read_write_locks::<DECREMENT, true, T>(
+                &self.world,
+                _pointers.safety_table_records(),
+            );
+        } else {
+            do_read_write_locks::<DECREMENT, false, T>(
+                &self.world,
+                _pointers.safety_table_records(),
+            );
+        }
+        self.count = 0;
+    }
+}
+
+impl<P, T> Drop for QueryRowIter<'_, P, T>
+where
+    T: QueryTuple,
+{
+    fn drop(&mut self) {
+        self.release_table();
+        if !self.done {
+            // SAFETY: the iterator did not run to completion, so its resources are still
+            // owned by this struct.
+            unsafe { sys::ecs_iter_fini(&mut *self.iter) };
+        }
+    }
+}
+
+/// An [`Iterator`] over the values returned by a function for each row of a
+/// [`QueryRowIter`], created with [`QueryRowIter::map`].
+pub struct QueryRowMap<'a, P, T, F>
+where
+    T: QueryTuple,
+{
+    rows: QueryRowIter<'a, P, T>,
+    func: F,
+}
+
+impl<'a, P, T, F, R> Iterator for QueryRowMap<'a, P, T, F>
+where
+    T: QueryTuple,
+    F: FnMut(EntityView<'a>, T::TupleType<'_>) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let (entity, components) = self.rows.next()?;
+        Some((self.func)(entity, components))
+    }
+}
+
+/// A lending iterator over the tables matched by a query, created with
+/// [`QueryAPI::iter_tables`].
+///
+/// Each call to [`next`](Self::next) returns a [`TableIter`] for the next matched table,
+/// which gives access to the fields of the table. The table stays locked until the
+/// iterator moves on to the next table or is dropped.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component, Debug)]
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Frozen;
+///
+/// let world = World::new();
+/// world.entity().set(Position { x: 1, y: 2 });
+/// world.entity().set(Position { x: 3, y: 4 });
+/// world.entity().set(Position { x: 5, y: 6 }).add(Frozen);
+///
+/// let query = world.new_query::<&Position>();
+///
+/// let counts: Vec<usize> = query.iter_tables().map(|it| it.count()).collect();
+/// assert_eq!(counts, [2, 1]);
+/// ```
+pub struct QueryTableIter<'a, P> {
+    /// Boxed, as chained iterators keep pointers into the iterator they are created from.
+    iter: Box<sys::ecs_iter_t>,
+    iter_next: ExternIterNextFn,
+    world: WorldRef<'a>,
+    locked: bool,
+    done: bool,
+    _phantom: core::marker::PhantomData<P>,
+}
+
+impl<'a, P> QueryTableIter<'a, P>
+where
+    P: ComponentId,
+{
+    pub(crate) fn new(
+        iter: sys::ecs_iter_t,
+        iter_next: ExternIterNextFn,
+        world: WorldRef<'a>,
+    ) -> Self {
+        Self {
+            iter: Box::new(iter),
+            iter_next,
+            world,
+            locked: false,
+            done: false,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Advance to the next matched table.
+    ///
+    /// Returns `None` once all matched tables have been returned.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<TableIter<'_, false, P>> {
+        self.unlock();
+        if self.done {
+            return None;
+        }
+        if !unsafe { (self.iter_next)(&mut *self.iter) } {
+            // the iterator released its resources when it ran to completion
+            self.done = true;
+            return None;
+        }
+
+        self.iter.flags |= sys::EcsIterIsValid;
+        table_lock(self.iter.world, self.iter.table);
+        self.locked = true;
+        Some(unsafe { TableIter::new(&mut self.iter, self.world) })
+    }
+
+    /// Turn the tables into an [`Iterator`] over the values returned by `func`.
+    pub fn map<F, R>(self, func: F) -> QueryTableMap<'a, P, F>
+    where
+        F: FnMut(TableIter<'_, false, P>) -> R,
+    {
+        QueryTableMap { tables: self, func }
+    }
+
+    fn unlock(&mut self) {
+        if self.locked {
+            table_unlock(self.iter.world, self.iter.table);
+            self.locked = false;
+        }
+    }
+}
+
+impl<P> Drop for QueryTableIter<'_, P> {
+    fn drop(&mut self) {
+        if self.locked {
+            table_unlock(self.iter.world, self.iter.table);
+        }
+        if !self.done {
+            // SAFETY: the iterator did not run to completion, so its resources are still
+            // owned by this struct.
+            unsafe { sys::ecs_iter_fini(&mut *self.iter) };
+        }
+    }
+}
+
+/// An [`Iterator`] over the values returned by a function for each table of a
+/// [`QueryTableIter`], created with [`QueryTableIter::map`].
+pub struct QueryTableMap<'a, P, F> {
+    tables: QueryTableIter<'a, P>,
+    func: F,
+}
+
+impl<P, F, R> Iterator for QueryTableMap<'_, P, F>
+where
+    P: ComponentId,
+    F: FnMut(TableIter<'_, false, P>) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let table = self.tables.next()?;
+        Some((self.func)(table))
+    }
+}
@@ -11,6 +11,42 @@ pub struct IsAnyArray {
     pub a_row: bool, //e.g. sparse, non_fragmenting
 }
 
+/// Term modifiers of a `#[derive(QueryData)]` field, applied after the term of
+/// the field has been populated.
+#[derive(Debug, Default, Clone, Copy)]
+#[doc(hidden)]
+pub struct QueryDataTerm {
+    pub up: bool,
+    pub cascade: bool,
+    pub trav: u64,
+    pub target: u64,
+    pub filter: bool,
+}
+
+impl QueryDataTerm {
+    pub fn apply(&self, term: &mut sys::ecs_term_t) {
+        if self.up || self.cascade {
+            term.src.id |= ECS_UP;
+        }
+        if self.cascade {
+            term.src.id |= ECS_CASCADE;
+        }
+        if self.trav != 0 {
+            term.trav = self.trav;
+        }
+        if self.target != 0 {
+            if term.first.id == 0 {
+                term.first.id = term.id;
+                term.id = 0;
+            }
+            term.second.id = self.target;
+        }
+        if self.filter {
+            term.inout = InOutKind::Filter as i16;
+        }
+    }
+}
+
 #[cfg(feature = "flecs_safety_locks")]
 #[derive(Debug, Clone, Copy)]
 pub struct TableColumnSafety {
@@ -41,6 +77,10 @@ pub struct ComponentsData<T: QueryTuple, const LEN: usize> {
     _marker: PhantomData<T>,
 }
 
+/// Component pointers of a `#[derive(QueryData)]` struct.
+#[doc(hidden)]
+pub type QueryDataPointers<T, const LEN: usize> = ComponentsData<T, LEN>;
+
 pub trait ComponentPointers<T: QueryTuple> {
     fn new(iter: &sys::ecs_iter_t) -> (IsAnyArray, Self);
 
@@ -0,0 +1,439 @@
+//! An implementation of the Flecs OS API on top of the Rust standard library.
+//!
+//! Enabled with the `flecs_rust_os_api` feature. When enabled, [`ecs_os_api::ensure_initialized`]
+//! fills the OS API with the functions in this module before the init hooks run, so hooks can
+//! still override individual slots:
+//!
+//! - **Memory**: `malloc_`, `calloc_`, `realloc_`, `free_` and `strdup_` allocate through the
+//!   Rust [global allocator](alloc::alloc::GlobalAlloc), so flecs allocations show up in Rust
+//!   heap profilers and custom allocators.
+//! - **Threads**: `thread_new_`, `task_new_` and their joins spawn [`std::thread`]s named
+//!   `flecs-worker` and `flecs-task`. A panic on a flecs thread is resumed on the thread that
+//!   joins it.
+//! - **Synchronization**: mutexes and condition variables are built on [`std::sync::Mutex`]
+//!   and [`std::sync::Condvar`], and the atomic counters on [`core::sync::atomic`].
+//! - **Time**: `now_`, `get_time_` and `sleep_` use [`std::time::Instant`] and
+//!   [`std::thread::sleep`].
+//! - **Logging and abort**: `log_` writes to stderr, and `abort_` calls
+//!   [`std::process::abort`].
+//!
+//! File I/O, dynamic library loading and module paths keep their C implementations.
+//!
+//! [`ecs_os_api::ensure_initialized`]: super::ecs_os_api::ensure_initialized
+extern crate std;
+
+use core::alloc::Layout;
+use core::ffi::{CStr, c_char, c_void};
+use core::sync::atomic::{AtomicI32, AtomicI64, AtomicU64, Ordering};
+use core::time::Duration;
+use std::io::Write;
+use std::sync::{Condvar, LazyLock, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+use flecs_ecs_derive::extern_abi;
+
+use crate::sys;
+
+extern crate alloc;
+use alloc::boxed::Box;
+
+/// Fills the memory, thread, synchronization, time, logging and abort slots of `api`.
+///
+/// Called by [`ecs_os_api::ensure_initialized`](super::ecs_os_api::ensure_initialized), so this
+/// is only needed when the OS API is set up by other means.
+pub fn install(api: &mut sys::ecs_os_api_t) {
+    api.malloc_ = Some(rust_malloc);
+    api.calloc_ = Some(rust_calloc);
+    api.realloc_ = Some(rust_realloc);
+    api.free_ = Some(rust_free);
+    api.strdup_ = Some(rust_strdup);
+
+    api.thread_new_ = Some(rust_thread_new);
+    api.thread_join_ = Some(rust_thread_join);
+    api.thread_self_ = Some(rust_thread_self);
+    api.task_new_ = Some(rust_task_new);
+    api.task_join_ = Some(rust_thread_join);
+
+    api.ainc_ = Some(rust_ainc);
+    api.adec_ = Some(rust_adec);
+    api.lainc_ = Some(rust_lainc);
+    api.ladec_ = Some(rust_ladec);
+
+    api.mutex_new_ = Some(rust_mutex_new);
+    api.mutex_free_ = Some(rust_mutex_free);
+    api.mutex_lock_ = Some(rust_mutex_lock);
+    api.mutex_unlock_ = Some(rust_mutex_unlock);
+
+    api.cond_new_ = Some(rust_cond_new);
+    api.cond_free_ = Some(rust_cond_free);
+    api.cond_signal_ = Some(rust_cond_signal);
+    api.cond_broadcast_ = Some(rust_cond_broadcast);
+    api.cond_wait_ = Some(rust_cond_wait);
+
+    api.sleep_ = Some(rust_sleep);
+    api.now_ = Some(rust_now);
+    api.get_time_ = Some(rust_get_time);
+
+    api.log_ = Some(rust_log);
+    api.abort_ = Some(rust_abort);
+}
+
+// Memory
+//
+// Every allocation is prefixed with a header that stores its size, since `free_` doesn't
+// receive it. The header keeps the alignment that `malloc` guarantees.
+
+const HEADER_SIZE: usize = 16;
+
+fn alloc_layout(size: usize) -> Layout {
+    Layout::from_size_align(size + HEADER_SIZE, HEADER_SIZE).expect("allocation too large")
+}
+
+/// # Safety
+///
+/// `base` must be an allocation of at least [`HEADER_SIZE`] bytes, or null.
+unsafe fn finish_alloc(base: *mut u8, size: usize, layout: Layout) -> *mut c_void {
+    if base.is_null() {
+        alloc::alloc::handle_alloc_error(layout);
+    }
+    // SAFETY: the header is part of the allocation and aligned for `usize`.
+    unsafe {
+        (base as *mut usize).write(size);
+        base.add(HEADER_SIZE) as *mut c_void
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must have been returned by one of the allocation functions of this module.
+unsafe fn alloc_base(ptr: *mut c_void) -> (*mut u8, usize) {
+    // SAFETY: the header precedes the pointer that was handed out.
+    unsafe {
+        let base = (ptr as *mut u8).sub(HEADER_SIZE);
+        (base, (base as *const usize).read())
+    }
+}
+
+#[extern_abi]
+fn rust_malloc(size: sys::ecs_size_t) -> *mut c_void {
+    let size = size.max(0) as usize;
+    let layout = alloc_layout(size);
+    // SAFETY: the layout is never zero sized.
+    unsafe { finish_alloc(alloc::alloc::alloc(layout), size, layout) }
+}
+
+#[extern_abi]
+fn rust_calloc(size: sys::ecs_size_t) -> *mut c_void {
+    let size = size.max(0) as usize;
+    let layout = alloc_layout(size);
+    // SAFETY: the layout is never zero sized.
+    unsafe { finish_alloc(alloc::alloc::alloc_zeroed(layout), size, layout) }
+}
+
+#[extern_abi]
+fn rust_realloc(ptr: *mut c_void, size: sys::ecs_size_t) -> *mut c_void {
+    if ptr.is_null() {
+        return rust_malloc(size);
+    }
+    let size = size.max(0) as usize;
+    // SAFETY: flecs only reallocates memory it allocated through the OS API.
+    unsafe {
+        let (base, old_size) = alloc_base(ptr);
+        let new_base = alloc::alloc::realloc(base, alloc_layout(old_size), size + HEADER_SIZE);
+        finish_alloc(new_base, size, alloc_layout(size))
+    }
+}
+
+#[extern_abi]
+fn rust_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: flecs only frees memory it allocated through the OS API.
+    unsafe {
+        let (base, size) = alloc_base(ptr);
+        alloc::alloc::dealloc(base, alloc_layout(size));
+    }
+}
+
+#[extern_abi]
+fn rust_strdup(str: *const c_char) -> *mut c_char {
+    if str.is_null() {
+        return core::ptr::null_mut();
+    }
+    // SAFETY: `str` is a null terminated string, and the copy has room for it and its null
+    // terminator.
+    unsafe {
+        let len = CStr::from_ptr(str).to_bytes_with_nul().len();
+        let copy = rust_malloc(len as sys::ecs_size_t) as *mut c_char;
+        core::ptr::copy_nonoverlapping(str, copy, len);
+        copy
+    }
+}
+
+// Threads
+
+struct SendPtr(*mut c_void);
+
+// SAFETY: flecs hands the parameter of a thread over to that thread, and its result back to
+// the thread that joins it.
+unsafe impl Send for SendPtr {}
+
+type ThreadHandle = JoinHandle<SendPtr>;
+
+fn spawn(
+    name: &str,
+    callback: sys::ecs_os_thread_callback_t,
+    param: *mut c_void,
+) -> sys::ecs_os_thread_t {
+    let callback = callback.expect("flecs thread has no callback");
+    let param = SendPtr(param);
+    let handle: ThreadHandle = std::thread::Builder::new()
+        .name(name.into())
+        .spawn(move || {
+            let param = param;
+            // SAFETY: flecs passes a callback that accepts its own parameter.
+            SendPtr(unsafe { callback(param.0) })
+        })
+        .expect("failed to spawn flecs thread");
+    Box::into_raw(Box::new(handle)) as sys::ecs_os_thread_t
+}
+
+#[extern_abi]
+fn rust_thread_new(
+    callback: sys::ecs_os_thread_callback_t,
+    param: *mut c_void,
+) -> sys::ecs_os_thread_t {
+    spawn("flecs-worker", callback, param)
+}
+
+#[extern_abi]
+fn rust_task_new(
+    callback: sys::ecs_os_thread_callback_t,
+    param: *mut c_void,
+) -> sys::ecs_os_thread_t {
+    spawn("flecs-task", callback, param)
+}
+
+#[extern_abi]
+fn rust_thread_join(thread: sys::ecs_os_thread_t) -> *mut c_void {
+    // SAFETY: the thread was created by `spawn`, and is joined once.
+    let handle = unsafe { Box::from_raw(thread as *mut ThreadHandle) };
+    match handle.join() {
+        Ok(result) => result.0,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+
+std::thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+#[extern_abi]
+fn rust_thread_self() -> sys::ecs_os_thread_id_t {
+    THREAD_ID.with(|id| *id)
+}
+
+// Atomics
+
+#[extern_abi]
+fn rust_ainc(value: *mut i32) -> i32 {
+    // SAFETY: flecs passes a valid, aligned counter that is only accessed atomically.
+    unsafe { AtomicI32::from_ptr(value) }.fetch_add(1, Ordering::AcqRel) + 1
+}
+
+#[extern_abi]
+fn rust_adec(value: *mut i32) -> i32 {
+    // SAFETY: flecs passes a valid, aligned counter that is only accessed atomically.
+    unsafe { AtomicI32::from_ptr(value) }.fetch_sub(1, Ordering::AcqRel) - 1
+}
+
+#[extern_abi]
+fn rust_lainc(value: *mut i64) -> i64 {
+    // SAFETY: flecs passes a valid, aligned counter that is only accessed atomically.
+    unsafe { AtomicI64::from_ptr(value) }.fetch_add(1, Ordering::AcqRel) + 1
+}
+
+#[extern_abi]
+fn rust_ladec(value: *mut i64) -> i64 {
+    // SAFETY: flecs passes a valid, aligned counter that is only accessed atomically.
+    unsafe { AtomicI64::from_ptr(value) }.fetch_sub(1, Ordering::AcqRel) - 1
+}
+
+// Mutexes and condition variables
+//
+// The OS API locks and unlocks a mutex in separate calls, which doesn't fit the guard based
+// `std::sync::Mutex`. A flecs mutex is therefore a `locked` flag protected by a std mutex, with
+// a condition variable to wait for the flag to clear.
+
+#[derive(Default)]
+struct RawMutex {
+    locked: Mutex<bool>,
+    unlocked: Condvar,
+}
+
+impl RawMutex {
+    fn state(&self) -> MutexGuard<'_, bool> {
+        self.locked.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn acquire<'a>(&'a self, mut locked: MutexGuard<'a, bool>) {
+        while *locked {
+            locked = self
+                .unlocked
+                .wait(locked)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *locked = true;
+    }
+
+    fn release(&self, locked: &mut MutexGuard<'_, bool>) {
+        **locked = false;
+        self.unlocked.notify_one();
+    }
+}
+
+/// # Safety
+///
+/// `mutex` must have been created by [`rust_mutex_new`] and not freed.
+unsafe fn raw_mutex<'a>(mutex: sys::ecs_os_mutex_t) -> &'a RawMutex {
+    // SAFETY: guaranteed by the caller.
+    unsafe { &*(mutex as *const RawMutex) }
+}
+
+/// # Safety
+///
+/// `cond` must have been created by [`rust_cond_new`] and not freed.
+unsafe fn raw_cond<'a>(cond: sys::ecs_os_cond_t) -> &'a Condvar {
+    // SAFETY: guaranteed by the caller.
+    unsafe { &*(cond as *const Condvar) }
+}
+
+#[extern_abi]
+fn rust_mutex_new() -> sys::ecs_os_mutex_t {
+    Box::into_raw(Box::new(RawMutex::default())) as sys::ecs_os_mutex_t
+}
+
+#[extern_abi]
+fn rust_mutex_free(mutex: sys::ecs_os_mutex_t) {
+    // SAFETY: flecs frees a mutex once, after it is no longer used.
+    drop(unsafe { Box::from_raw(mutex as *mut RawMutex) });
+}
+
+#[extern_abi]
+fn rust_mutex_lock(mutex: sys::ecs_os_mutex_t) {
+    // SAFETY: flecs passes a mutex created by `rust_mutex_new`.
+    let mutex = unsafe { raw_mutex(mutex) };
+    mutex.acquire(mutex.state());
+}
+
+#[extern_abi]
+fn rust_mutex_unlock(mutex: sys::ecs_os_mutex_t) {
+    // SAFETY: flecs passes a mutex created by `rust_mutex_new`.
+    let mutex = unsafe { raw_mutex(mutex) };
+    mutex.release(&mut mutex.state());
+}
+
+#[extern_abi]
+fn rust_cond_new() -> sys::ecs_os_cond_t {
+    Box::into_raw(Box::new(Condvar::new())) as sys::ecs_os_cond_t
+}
+
+#[extern_abi]
+fn rust_cond_free(cond: sys::ecs_os_cond_t) {
+    // SAFETY: flecs frees a condition variable once, after it is no longer used.
+    drop(unsafe { Box::from_raw(cond as *mut Condvar) });
+}
+
+#[extern_abi]
+fn rust_cond_signal(cond: sys::ecs_os_cond_t) {
+    // SAFETY: flecs passes a condition variable created by `rust_cond_new`.
+    unsafe { raw_cond(cond) }.notify_one();
+}
+
+#[extern_abi]
+fn rust_cond_broadcast(cond: sys::ecs_os_cond_t) {
+    // SAFETY: flecs passes a condition variable created by `rust_cond_new`.
+    unsafe { raw_cond(cond) }.notify_all();
+}
+
+#[extern_abi]
+fn rust_cond_wait(cond: sys::ecs_os_cond_t, mutex: sys::ecs_os_mutex_t) {
+    // SAFETY: flecs passes a condition variable and mutex created by this module, and always
+    // waits on a condition variable with the same mutex.
+    let (cond, mutex) = unsafe { (raw_cond(cond), raw_mutex(mutex)) };
+
+    // Releasing the flecs mutex and waiting happens while holding the std mutex, so a signal
+    // that is sent after the flecs mutex is released can't be missed.
+    let mut locked = mutex.state();
+    mutex.release(&mut locked);
+    let locked = cond.wait(locked).unwrap_or_else(PoisonError::into_inner);
+    mutex.acquire(locked);
+}
+
+// Time
+
+static START: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+#[extern_abi]
+fn rust_sleep(sec: i32, nanosec: i32) {
+    std::thread::sleep(Duration::new(sec.max(0) as u64, nanosec.max(0) as u32));
+}
+
+#[extern_abi]
+fn rust_now() -> u64 {
+    START.elapsed().as_nanos() as u64
+}
+
+#[extern_abi]
+fn rust_get_time(time_out: *mut sys::ecs_time_t) {
+    let elapsed = START.elapsed();
+    // SAFETY: flecs passes a valid time to write to.
+    unsafe {
+        *time_out = sys::ecs_time_t {
+            sec: elapsed.as_secs() as u32,
+            nanosec: elapsed.subsec_nanos(),
+        };
+    }
+}
+
+// Logging and abort
+
+#[extern_abi]
+fn rust_log(level: i32, file: *const c_char, line: i32, msg: *const c_char) {
+    let label = match level {
+        ..=-4 => "fatal",
+        -3 => "error",
+        -2 => "warning",
+        -1 | 0 => "info",
+        _ => "debug",
+    };
+    // SAFETY: flecs passes null or null terminated strings.
+    let (file, msg) = unsafe {
+        (
+            (!file.is_null()).then(|| CStr::from_ptr(file).to_string_lossy()),
+            (!msg.is_null()).then(|| CStr::from_ptr(msg).to_string_lossy()),
+        )
+    };
+    // SAFETY: the indentation is only changed by the thread that logs.
+    let indent = unsafe { sys::ecs_os_api.log_indent_ }.max(0) as usize * 2;
+
+    let mut stderr = std::io::stderr().lock();
+    let _ = match file {
+        Some(file) if level < 0 => writeln!(
+            stderr,
+            "{label}: {:indent$}{file}:{line}: {}",
+            "",
+            msg.unwrap_or_default()
+        ),
+        _ => writeln!(stderr, "{label}: {:indent$}{}", "", msg.unwrap_or_default()),
+    };
+}
+
+#[extern_abi]
+fn rust_abort() {
+    std::process::abort();
+}
@@ -278,21 +278,26 @@ where
         })
     }
 
-    /// Access ctx.
-    /// ctx contains the context pointer assigned to a system
-    ///
-    /// # See also
+    /// Access the context of the system, observer or query being iterated.
     ///
-    /// # Safety
-    /// - caller must ensure the ctx variable was set to a type accessible as C and is not aliased
-    pub unsafe fn context<T>(&mut self) -> &'a mut T {
-        unsafe { &mut *(self.iter.ctx as *mut T) }
+    /// Returns `None` if no context was set or if it is not of type `C`.
+    /// Contexts are set with `set_context` on [`SystemAPI`], [`QueryBuilder`],
+    /// [`System`](crate::addons::system::System) or [`Observer`].
+    pub fn context<C: 'static>(&self) -> Option<&'a C> {
+        // SAFETY: contexts are only installed through `context_into_raw` and live as
+        // long as the system, observer or query that is being iterated.
+        unsafe { context_from_raw::<C>(self.context_ptr()) }
     }
 
     /// Access ctx.
-    /// ctx contains the context pointer assigned to a system
+    /// ctx contains the context pointer assigned to a system, observer or query
     pub fn context_ptr(&self) -> *mut c_void {
-        self.iter.ctx
+        if self.iter.ctx.is_null() && !self.iter.query.is_null() {
+            // query iterators don't forward the query context to the iterator
+            unsafe { (*self.iter.query).ctx }
+        } else {
+            self.iter.ctx
+        }
     }
 
     /// Access param.
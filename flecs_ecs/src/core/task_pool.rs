@@ -0,0 +1,248 @@
+//! Run the task threads of multithreaded pipelines on a Rust executor.
+//!
+//! With [`World::set_task_threads`](super::World::set_task_threads), flecs starts a task for
+//! each worker on every world update through the `task_new_` and `task_join_` functions of the
+//! OS API, instead of keeping its own threads alive. [`set_task_pool`] routes those tasks to a
+//! [`TaskPool`], so the ECS can share worker threads with the job system of the application
+//! instead of oversubscribing cores.
+//!
+//! [`StdTaskPool`] is a fixed size pool of std threads that implements [`TaskPool`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use flecs_ecs::prelude::*;
+//! use std::sync::Arc;
+//!
+//! #[derive(Component)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! // The pool can be shared with the rest of the application. It needs a thread for every
+//! // task thread except the one that calls `progress`.
+//! let pool = Arc::new(StdTaskPool::new(3));
+//! set_task_pool(pool.clone());
+//!
+//! let world = World::new();
+//! world.entity().set(Position { x: 0.0, y: 0.0 });
+//!
+//! world.system::<&mut Position>().par_each(|pos| {
+//!     pos.x += 1.0;
+//! });
+//!
+//! world.set_task_threads(4);
+//! world.progress();
+//! ```
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::ffi::c_void;
+use core::panic::AssertUnwindSafe;
+use std::sync::{Condvar, Mutex, PoisonError, RwLock};
+use std::thread::JoinHandle;
+
+use crate::core::ecs_os_api;
+use crate::sys;
+
+extern crate alloc;
+use alloc::{boxed::Box, collections::VecDeque, format, sync::Arc, vec::Vec};
+
+/// A unit of work handed to a [`TaskPool`].
+pub type Task = Box<dyn FnOnce() + Send + 'static>;
+
+/// An executor that runs the task threads of multithreaded pipelines.
+///
+/// Installed with [`set_task_pool`]. The thread that calls
+/// [`World::progress`](super::World::progress) runs the first stage itself, and starts a task
+/// for each other stage. These tasks run at the same time and wait for each other at every
+/// sync point, so the pool needs at least `task_threads - 1` threads that are free to run
+/// them, or the world update never finishes.
+pub trait TaskPool: Send + Sync + 'static {
+    /// Run `task` on a thread of the pool.
+    fn spawn(&self, task: Task);
+}
+
+impl<T: TaskPool + ?Sized> TaskPool for Arc<T> {
+    fn spawn(&self, task: Task) {
+        (**self).spawn(task);
+    }
+}
+
+static TASK_POOL: RwLock<Option<Arc<dyn TaskPool>>> = RwLock::new(None);
+
+/// Use `pool` to run the task threads of all worlds.
+///
+/// Task threads are enabled per world with
+/// [`World::set_task_threads`](super::World::set_task_threads). The pool replaces the
+/// `task_new_` and `task_join_` functions of the OS API, which is process-global. Can be
+/// called before or after the first world is created, but not while a world is progressing.
+pub fn set_task_pool(pool: impl TaskPool) {
+    *TASK_POOL.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(pool));
+
+    fn install(api: &mut sys::ecs_os_api_t) {
+        api.task_new_ = Some(task_new);
+        api.task_join_ = Some(task_join);
+    }
+
+    if ecs_os_api::try_add_init_hook(Box::new(install)).is_err() {
+        // SAFETY: the OS API is initialized, and no tasks are running.
+        unsafe { install(&mut *core::ptr::addr_of_mut!(sys::ecs_os_api)) };
+    }
+}
+
+struct SendPtr(*mut c_void);
+
+// SAFETY: flecs hands the parameter of a task over to the task, and its result back to the
+// thread that joins it.
+unsafe impl Send for SendPtr {}
+
+/// Completion state of a task started by flecs.
+#[derive(Default)]
+struct TaskState {
+    result: Mutex<Option<std::thread::Result<SendPtr>>>,
+    done: Condvar,
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn task_new(callback: sys::ecs_os_thread_callback_t, param: *mut c_void) -> sys::ecs_os_thread_t {
+    let callback = callback.expect("flecs task has no callback");
+    let pool = TASK_POOL
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .expect("no task pool set");
+
+    let state = Arc::new(TaskState::default());
+    let task_state = state.clone();
+    let param = SendPtr(param);
+    pool.spawn(Box::new(move || {
+        let param = param;
+        // SAFETY: flecs passes a callback that accepts its own parameter.
+        let result =
+            std::panic::catch_unwind(AssertUnwindSafe(|| SendPtr(unsafe { callback(param.0) })));
+        *task_state
+            .result
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(result);
+        task_state.done.notify_all();
+    }));
+
+    Arc::into_raw(state) as sys::ecs_os_thread_t
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn task_join(thread: sys::ecs_os_thread_t) -> *mut c_void {
+    // SAFETY: the task was started by `task_new`, and is joined once.
+    let state = unsafe { Arc::from_raw(thread as *const TaskState) };
+    let mut result = state.result.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+        match result.take() {
+            Some(Ok(value)) => return value.0,
+            Some(Err(panic)) => std::panic::resume_unwind(panic),
+            None => {
+                result = state
+                    .done
+                    .wait(result)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct PoolQueue {
+    tasks: VecDeque<Task>,
+    shutdown: bool,
+}
+
+#[derive(Default)]
+struct PoolShared {
+    queue: Mutex<PoolQueue>,
+    available: Condvar,
+}
+
+impl PoolShared {
+    fn run_worker(&self) {
+        loop {
+            let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+            let task = loop {
+                if let Some(task) = queue.tasks.pop_front() {
+                    break task;
+                }
+                if queue.shutdown {
+                    return;
+                }
+                queue = self
+                    .available
+                    .wait(queue)
+                    .unwrap_or_else(PoisonError::into_inner);
+            };
+            drop(queue);
+            task();
+        }
+    }
+}
+
+/// A [`TaskPool`] with a fixed number of std threads.
+///
+/// The threads are named `flecs-pool-<index>`. Tasks run in the order they were spawned.
+/// Dropping the pool runs the remaining tasks and joins its threads.
+pub struct StdTaskPool {
+    shared: Arc<PoolShared>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl StdTaskPool {
+    /// Create a pool with `threads` threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero, or a thread fails to spawn.
+    pub fn new(threads: usize) -> Self {
+        assert!(threads > 0, "a task pool needs at least one thread");
+        let shared = Arc::new(PoolShared::default());
+        let threads = (0..threads)
+            .map(|index| {
+                let shared = shared.clone();
+                std::thread::Builder::new()
+                    .name(format!("flecs-pool-{index}"))
+                    .spawn(move || shared.run_worker())
+                    .expect("failed to spawn task pool thread")
+            })
+            .collect();
+        StdTaskPool { shared, threads }
+    }
+
+    /// Returns the number of threads of the pool.
+    pub fn threads(&self) -> usize {
+        self.threads.len()
+    }
+}
+
+impl TaskPool for StdTaskPool {
+    fn spawn(&self, task: Task) {
+        self.shared
+            .queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tasks
+            .push_back(task);
+        self.shared.available.notify_one();
+    }
+}
+
+impl Drop for StdTaskPool {
+    fn drop(&mut self) {
+        self.shared
+            .queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .shutdown = true;
+        self.shared.available.notify_all();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
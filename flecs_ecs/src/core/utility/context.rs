@@ -0,0 +1,77 @@
+//! Owned, type-checked user contexts for systems, observers and queries.
+//!
+//! The typed `set_context` methods move a Rust value to the heap and install it as the
+//! `ctx` of the underlying C object together with a free function that drops it. The
+//! allocation starts with the [`TypeId`] of the value, so reading it back as a different
+//! type returns `None` instead of reinterpreting the memory.
+//!
+//! Contexts are read from callbacks, which run on worker threads for multithreaded systems,
+//! so the values must be `Send + Sync`.
+
+use core::any::TypeId;
+use core::ffi::c_void;
+
+use crate::sys;
+use flecs_ecs_derive::extern_abi;
+
+extern crate alloc;
+use alloc::boxed::Box;
+
+#[repr(C)]
+struct ContextCell<C> {
+    type_id: TypeId,
+    value: C,
+}
+
+/// Move `value` to the heap, returning the `ctx` pointer and the free function to install
+/// alongside it.
+pub(crate) fn context_into_raw<C: Send + Sync + 'static>(
+    value: C,
+) -> (*mut c_void, sys::ecs_ctx_free_t) {
+    let cell = Box::new(ContextCell {
+        type_id: TypeId::of::<C>(),
+        value,
+    });
+    (Box::into_raw(cell) as *mut c_void, Some(free_context::<C>))
+}
+
+/// Drop the context of a descriptor that was not handed to flecs, and clear it.
+///
+/// Builders own the context until they are built, so they call this when the context is
+/// replaced or when they are dropped without being built.
+pub(crate) fn free_desc_context(ctx: &mut *mut c_void, ctx_free: &mut sys::ecs_ctx_free_t) {
+    if let Some(free) = ctx_free.take()
+        && !ctx.is_null()
+    {
+        // SAFETY: the free function was installed together with the context.
+        unsafe { free(*ctx) };
+    }
+    *ctx = core::ptr::null_mut();
+}
+
+#[extern_abi]
+pub(crate) fn free_context<C: 'static>(ptr: *mut c_void) {
+    drop(unsafe { Box::from_raw(ptr as *mut ContextCell<C>) });
+}
+
+/// Read back a context installed by [`context_into_raw`].
+///
+/// Returns `None` when `ptr` is null or the context was created with a type other than `C`.
+///
+/// # Safety
+///
+/// `ptr` must be null or a pointer returned by [`context_into_raw`] that stays alive for `'a`.
+pub(crate) unsafe fn context_from_raw<'a, C: 'static>(ptr: *const c_void) -> Option<&'a C> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    // SAFETY: `ContextCell` is `repr(C)`, so the `TypeId` header is at offset 0 for every `C`.
+    let type_id = unsafe { *(ptr as *const TypeId) };
+    if type_id != TypeId::of::<C>() {
+        return None;
+    }
+
+    // SAFETY: the header matches, so the allocation is a `ContextCell<C>`.
+    Some(unsafe { &(*(ptr as *const ContextCell<C>)).value })
+}
@@ -1,8 +1,17 @@
-#![doc(hidden)]
+//! Error codes and the [`FlecsError`] type returned by the fallible `try_*` operations.
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::string::String;
+use core::cell::RefCell;
+use core::ffi::{CStr, c_char};
 use core::fmt::{Display, Formatter};
 
-#[doc(hidden)]
-/// Enum representing the error codes that can be used by `ecs_asserts` and `ecs_abort`
+use crate::core::Entity;
+use crate::sys;
+
+/// The error codes used by flecs asserts and aborts, and carried by [`FlecsError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FlecsErrorCode {
     InvalidOperation,
     InvalidParameter,
@@ -86,6 +95,7 @@ impl Display for FlecsErrorCode {
 }
 
 impl FlecsErrorCode {
+    /// Returns the C value of the error code, e.g. `ECS_INVALID_PARAMETER`.
     pub fn to_int(&self) -> i32 {
         match self {
             FlecsErrorCode::InvalidOperation => 1,
@@ -126,6 +136,235 @@ impl FlecsErrorCode {
     }
 }
 
+/// Errors returned by the fallible (`try_*`) operations of the API.
+///
+/// Operations that go through flecs report the error message flecs logged while the operation
+/// ran, so it can be shown to a user instead of being written to stderr.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// let world = World::new();
+///
+/// let err = world.try_lookup("DoesNotExist").unwrap_err();
+/// assert_eq!(err, FlecsError::EntityNotFound { name: "DoesNotExist".to_string() });
+/// assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+/// assert_eq!(err.to_string(), "entity `DoesNotExist` not found");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FlecsError {
+    /// No entity with the given name or path exists.
+    EntityNotFound {
+        /// The name or path that was looked up.
+        name: String,
+    },
+    /// The entity is not alive.
+    EntityNotAlive {
+        /// The entity that was requested.
+        entity: Entity,
+    },
+    /// A query did not match any entities.
+    QueryEmpty,
+    /// A query, system, observer or alert could not be created.
+    Build {
+        /// What was being built, e.g. `"query"` or `"system"`.
+        kind: &'static str,
+        /// The error flecs reported.
+        message: String,
+    },
+    /// A script failed to parse or evaluate.
+    Script {
+        /// The name of the script, typically its file name.
+        name: String,
+        /// The error flecs reported.
+        message: String,
+    },
+    /// An expression failed to parse or evaluate.
+    Expr {
+        /// The expression.
+        expr: String,
+        /// The error flecs reported.
+        message: String,
+    },
+    /// A value could not be deserialized from JSON.
+    Json {
+        /// The name of the component that was deserialized, if any.
+        component: Option<String>,
+        /// The error flecs reported.
+        message: String,
+    },
+    /// A component could not be registered.
+    Component {
+        /// The name of the component.
+        name: String,
+        /// Why registration failed.
+        code: FlecsErrorCode,
+        /// A description of the failure.
+        message: String,
+    },
+    /// The schedule of a pipeline could not be built.
+    Schedule {
+        /// The pipeline.
+        pipeline: Entity,
+        /// Why the schedule could not be built.
+        code: FlecsErrorCode,
+        /// A description of the failure.
+        message: String,
+    },
+}
+
+impl FlecsError {
+    /// Returns the flecs error code that best describes this error.
+    pub fn code(&self) -> FlecsErrorCode {
+        match self {
+            FlecsError::EntityNotFound { .. }
+            | FlecsError::EntityNotAlive { .. }
+            | FlecsError::Build { .. } => FlecsErrorCode::InvalidParameter,
+            FlecsError::QueryEmpty => FlecsErrorCode::InvalidOperation,
+            FlecsError::Script { .. } | FlecsError::Expr { .. } => FlecsErrorCode::OperationFailed,
+            FlecsError::Json { .. } => FlecsErrorCode::InvalidConversion,
+            FlecsError::Component { code, .. } | FlecsError::Schedule { code, .. } => *code,
+        }
+    }
+
+    /// Returns the error message reported by flecs, which may be empty.
+    pub fn message(&self) -> &str {
+        match self {
+            FlecsError::EntityNotFound { .. }
+            | FlecsError::EntityNotAlive { .. }
+            | FlecsError::QueryEmpty => "",
+            FlecsError::Build { message, .. }
+            | FlecsError::Script { message, .. }
+            | FlecsError::Expr { message, .. }
+            | FlecsError::Json { message, .. }
+            | FlecsError::Component { message, .. }
+            | FlecsError::Schedule { message, .. } => message,
+        }
+    }
+}
+
+impl Display for FlecsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FlecsError::EntityNotFound { name } => write!(f, "entity `{name}` not found"),
+            FlecsError::EntityNotAlive { entity } => write!(f, "entity {entity} is not alive"),
+            FlecsError::QueryEmpty => write!(f, "query matched no entities"),
+            FlecsError::Build { kind, .. } => write!(f, "failed to build {kind}"),
+            FlecsError::Script { name, .. } => write!(f, "failed to run script `{name}`"),
+            FlecsError::Expr { expr, .. } => write!(f, "failed to evaluate expression `{expr}`"),
+            FlecsError::Json {
+                component: Some(component),
+                ..
+            } => write!(f, "failed to deserialize `{component}` from JSON"),
+            FlecsError::Json {
+                component: None, ..
+            } => write!(f, "failed to deserialize JSON"),
+            FlecsError::Component { name, code, .. } => {
+                write!(f, "failed to register component `{name}` ({code})")
+            }
+            FlecsError::Schedule { pipeline, code, .. } => {
+                write!(
+                    f,
+                    "failed to build the schedule of pipeline {pipeline} ({code})"
+                )
+            }
+        }?;
+
+        let message = self.message();
+        if !message.is_empty() {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for FlecsError {}
+
+std::thread_local! {
+    /// The errors logged by flecs on this thread while inside [`capture_errors`].
+    static CAPTURED_ERRORS: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The `log_` function of the OS API before [`install_error_capture`] replaced it.
+static FORWARD_LOG: std::sync::OnceLock<sys::ecs_os_api_log_t> = std::sync::OnceLock::new();
+
+/// Wraps the `log_` function of the OS API, so that errors logged inside [`capture_errors`]
+/// can be returned to the caller instead.
+///
+/// Runs once when the OS API is initialized, after the hooks added with
+/// [`add_init_hook`](crate::core::ecs_os_api::add_init_hook), so messages that are not
+/// captured are forwarded to the logger installed by those hooks.
+pub(crate) fn install_error_capture(api: &mut sys::ecs_os_api_t) {
+    let _ = FORWARD_LOG.set(api.log_);
+    api.log_ = Some(capture_log);
+}
+
+/// Runs `f`, returning the error messages flecs logged on this thread while it ran.
+///
+/// Captured errors are not forwarded to the regular `log_` function of the OS API.
+pub(crate) fn capture_errors<R>(f: impl FnOnce() -> R) -> (R, String) {
+    struct Restore(Option<String>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CAPTURED_ERRORS.with_borrow_mut(|captured| *captured = previous);
+        }
+    }
+
+    let restore = Restore(CAPTURED_ERRORS.replace(Some(String::new())));
+    let result = f();
+    let errors = CAPTURED_ERRORS.take().unwrap_or_default();
+    drop(restore);
+    (result, errors)
+}
+
+#[flecs_ecs_derive::extern_abi]
+fn capture_log(level: i32, file: *const c_char, line: i32, msg: *const c_char) {
+    // only errors are captured, fatal messages precede an abort and should always be logged
+    if level == -3 && !msg.is_null() {
+        let captured = CAPTURED_ERRORS.with_borrow_mut(|captured| {
+            let Some(errors) = captured else {
+                return false;
+            };
+            if !errors.is_empty() {
+                errors.push('\n');
+            }
+            let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+            push_without_ansi_escapes(errors, &msg);
+            true
+        });
+
+        if captured {
+            return;
+        }
+    }
+
+    if let Some(Some(log)) = FORWARD_LOG.get() {
+        unsafe { log(level, file, line, msg) };
+    }
+}
+
+/// Appends `msg` to `out`, leaving out the color escape codes flecs may have inserted.
+fn push_without_ansi_escapes(out: &mut String, msg: &str) {
+    let mut chars = msg.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // skip `ESC [ ... m`
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
 /// Macro to assert a condition.
 /// In release mode, the condition is not checked.
 /// Can be turned off by disabling the `flecs_ecs_asserts` feature
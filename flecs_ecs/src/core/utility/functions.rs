@@ -358,6 +358,17 @@ pub(crate) fn set_helper<T: ComponentId>(
         );
     };
 
+    set_helper_unchecked(world, entity, value, id);
+}
+
+/// Same as [`set_helper`], without the compile time check that `T` is not a zero-sized type,
+/// for callers that only dispatch to it at runtime for data components.
+pub(crate) fn set_helper_unchecked<T: ComponentId>(
+    world: *mut sys::ecs_world_t,
+    entity: u64,
+    value: T,
+    id: u64,
+) {
     unsafe { WorldRef::from_ptr(world) }.check_thread_affinity_exclusive::<T>();
 
     unsafe {
@@ -553,6 +564,22 @@ pub(crate) unsafe fn print_c_string(c_string: *const c_char) {
     }
 }
 
+/// Returns the string representation of an id, like `Position` or `(ChildOf, parent)`.
+pub(crate) fn id_str(world: *const sys::ecs_world_t, id: sys::ecs_id_t) -> String {
+    // SAFETY: the string is allocated by flecs and owned by the caller.
+    unsafe {
+        let ptr = sys::ecs_id_str(world, id);
+        if ptr.is_null() {
+            return String::new();
+        }
+        let name = core::ffi::CStr::from_ptr(ptr)
+            .to_string_lossy()
+            .into_owned();
+        sys::ecs_os_api.free_.expect("os api is missing")(ptr as *mut core::ffi::c_void);
+        name
+    }
+}
+
 /// Strips the given prefix from the given C string, returning a new C string with the prefix removed.
 /// If the given C string does not start with the given prefix, returns `None`.
 pub(crate) fn strip_prefix_str_raw<'a>(str: &'a str, prefix: &str) -> Option<&'a str> {
@@ -1,6 +1,9 @@
 //! sets various internal logging options
 use crate::sys;
 
+#[cfg(any(feature = "log", feature = "tracing"))]
+use crate::core::ecs_os_api::{self, AddInitHookError};
+
 /// Sets the logging level to the specified value.
 ///
 /// # Arguments
@@ -53,3 +56,192 @@ pub fn enable_timedelta_logging(enabled: bool) {
         sys::ecs_log_enable_timedelta(enabled);
     }
 }
+
+/// Forwards flecs log messages to the [`log`](::log) crate.
+///
+/// Installs an OS API init hook that replaces the default `log_` implementation, which writes to
+/// stderr. Messages are emitted under the `flecs` target with the file and line they originate
+/// from. The flecs log level and the current [`log_push`](sys::ecs_log_push_) indentation are
+/// attached as the `flecs_level` and `indent` key-values. Colors are disabled, since they would
+/// otherwise be embedded in the message as escape codes.
+///
+/// Flecs levels map to [`log::Level`](::log::Level) as follows:
+///
+/// | flecs                  | `log`   |
+/// |------------------------|---------|
+/// | fatal (-4), error (-3) | `Error` |
+/// | warning (-2)           | `Warn`  |
+/// | info (0)               | `Info`  |
+/// | debug (1)              | `Debug` |
+/// | debug (2 and up)       | `Trace` |
+///
+/// Which messages flecs produces is still controlled by [`set_log_level`].
+///
+/// # Errors
+///
+/// Must be called before the first [`World`](crate::core::World) is created, otherwise
+/// [`AddInitHookError::AlreadyInitialized`] is returned.
+///
+/// # Example
+///
+/// ```no_run
+/// use flecs_ecs::prelude::*;
+///
+/// // install a `log` implementation, e.g. `env_logger::init()`
+///
+/// forward_log_to_log_crate().unwrap();
+///
+/// let world = World::new();
+/// ```
+#[cfg(feature = "log")]
+pub fn forward_log_to_log_crate() -> Result<(), AddInitHookError> {
+    install_log_hook(Some(log_to_log_crate))
+}
+
+/// Forwards flecs log messages to the [`tracing`](::tracing) crate.
+///
+/// Installs an OS API init hook that replaces the default `log_` implementation, which writes to
+/// stderr. Messages are emitted as events under the `flecs` target, with `flecs_level`, `file`,
+/// `line` and `indent` (the current [`log_push`](sys::ecs_log_push_) depth) fields. Colors are
+/// disabled, since they would otherwise be embedded in the message as escape codes.
+///
+/// Levels are mapped the same way as in [`forward_log_to_log_crate`].
+///
+/// # Errors
+///
+/// Must be called before the first [`World`](crate::core::World) is created, otherwise
+/// [`AddInitHookError::AlreadyInitialized`] is returned.
+///
+/// # Example
+///
+/// ```no_run
+/// use flecs_ecs::prelude::*;
+///
+/// // install a `tracing` subscriber, e.g. `tracing_subscriber::fmt::init()`
+///
+/// forward_log_to_tracing().unwrap();
+///
+/// let world = World::new();
+/// ```
+#[cfg(feature = "tracing")]
+pub fn forward_log_to_tracing() -> Result<(), AddInitHookError> {
+    install_log_hook(Some(log_to_tracing))
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn install_log_hook(log: sys::ecs_os_api_log_t) -> Result<(), AddInitHookError> {
+    ecs_os_api::try_add_init_hook(alloc::boxed::Box::new(move |api| {
+        api.log_ = log;
+        api.flags_ &= !sys::EcsOsApiLogWithColors;
+    }))
+}
+
+/// A message passed to the OS API `log_` hook.
+#[cfg(any(feature = "log", feature = "tracing"))]
+struct LogMessage<'a> {
+    level: i32,
+    file: Option<&'a str>,
+    line: u32,
+    indent: i32,
+    msg: alloc::borrow::Cow<'a, str>,
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl LogMessage<'_> {
+    /// # Safety
+    ///
+    /// `file` and `msg` must be null or valid C strings that outlive the message.
+    unsafe fn new(
+        level: i32,
+        file: *const core::ffi::c_char,
+        line: i32,
+        msg: *const core::ffi::c_char,
+    ) -> Self {
+        let file = (!file.is_null())
+            .then(|| unsafe { core::ffi::CStr::from_ptr(file) }.to_str().ok())
+            .flatten();
+        let msg = if msg.is_null() {
+            alloc::borrow::Cow::Borrowed("")
+        } else {
+            unsafe { core::ffi::CStr::from_ptr(msg) }.to_string_lossy()
+        };
+
+        LogMessage {
+            level,
+            file,
+            line: line.max(0) as u32,
+            indent: unsafe { sys::ecs_os_api.log_indent_ },
+            msg,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+#[flecs_ecs_derive::extern_abi]
+fn log_to_log_crate(
+    level: i32,
+    file: *const core::ffi::c_char,
+    line: i32,
+    msg: *const core::ffi::c_char,
+) {
+    let log_level = match level {
+        ..=-3 => ::log::Level::Error,
+        -2 => ::log::Level::Warn,
+        -1 | 0 => ::log::Level::Info,
+        1 => ::log::Level::Debug,
+        _ => ::log::Level::Trace,
+    };
+
+    if log_level > ::log::max_level() {
+        return;
+    }
+
+    let message = unsafe { LogMessage::new(level, file, line, msg) };
+    let key_values = [("flecs_level", message.level), ("indent", message.indent)];
+
+    ::log::logger().log(
+        &::log::Record::builder()
+            .level(log_level)
+            .target("flecs")
+            .file(message.file)
+            .line(Some(message.line))
+            .key_values(&key_values)
+            .args(format_args!("{}", message.msg))
+            .build(),
+    );
+}
+
+#[cfg(feature = "tracing")]
+#[flecs_ecs_derive::extern_abi]
+fn log_to_tracing(
+    level: i32,
+    file: *const core::ffi::c_char,
+    line: i32,
+    msg: *const core::ffi::c_char,
+) {
+    let message = unsafe { LogMessage::new(level, file, line, msg) };
+
+    // `tracing::event!` needs the level to be known at compile time
+    macro_rules! event {
+        ($level:expr) => {
+            ::tracing::event!(
+                target: "flecs",
+                $level,
+                flecs_level = message.level,
+                file = message.file,
+                line = message.line,
+                indent = message.indent,
+                "{}",
+                message.msg
+            )
+        };
+    }
+
+    match level {
+        ..=-3 => event!(::tracing::Level::ERROR),
+        -2 => event!(::tracing::Level::WARN),
+        -1 | 0 => event!(::tracing::Level::INFO),
+        1 => event!(::tracing::Level::DEBUG),
+        _ => event!(::tracing::Level::TRACE),
+    }
+}
@@ -1,6 +1,7 @@
 //! contains traits that define what a component is and also the API's for [`Query`][super::Query], [`Observer`][super::Observer] and [`System`][crate::addons::system::System].
 //! Also contains lower level utility functions on ECS IDs. This is mostly used internally by the library.
 
+pub(crate) mod context;
 mod errors;
 mod functions;
 pub mod id;
@@ -11,6 +12,7 @@ pub(crate) mod safety;
 pub mod traits;
 pub mod types;
 
+pub(crate) use context::*;
 pub use errors::*;
 pub use functions::*;
 pub use id::id;
@@ -70,6 +70,9 @@ pub mod private {
 
         fn set_desc_run(&mut self, callback: Option<ExternIterFn>);
 
+        /// Install `ctx` as the user context, freeing a context set earlier on this builder.
+        fn set_desc_context(&mut self, ctx: *mut c_void, ctx_free: sys::ecs_ctx_free_t);
+
         /// Callback of the each functionality
         ///
         /// # Arguments
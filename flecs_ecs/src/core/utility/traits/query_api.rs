@@ -973,6 +973,62 @@ where
         QueryIter::new(self.retrieve_iter(), self.iter_next_func())
     }
 
+    /// Return a lending iterator over the matched entities and their components.
+    ///
+    /// Unlike [`each_entity`](QueryAPI::each_entity), the iterator can be stopped at any
+    /// row, and [`QueryRowIter::map`] turns it into an [`Iterator`] for use with adapters
+    /// such as `zip`, `filter_map`, `take_while` and `collect`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// world.entity_named("a").set(Health(10));
+    /// let b = world.entity_named("b").set(Health(0));
+    /// world.entity_named("c").set(Health(0));
+    ///
+    /// let query = world.new_query::<&Health>();
+    ///
+    /// let mut rows = query.iter();
+    /// let mut first_dead = None;
+    /// while let Some((entity, health)) = rows.next() {
+    ///     if health.0 == 0 {
+    ///         first_dead = Some(entity);
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(first_dead, Some(b));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::iter_tables`]
+    /// * [`QueryAPI::each_entity`]
+    fn iter(&'a self) -> QueryRowIter<'a, P, T> {
+        QueryRowIter::new(self.retrieve_iter(), self.iter_next_func(), self.world())
+    }
+
+    /// Return a lending iterator over the matched tables.
+    ///
+    /// Each item is a [`TableIter`] positioned at the next table, with the same field
+    /// access as the iterator passed to [`run`](QueryAPI::run).
+    ///
+    /// # See also
+    ///
+    /// * [`QueryAPI::iter`]
+    /// * [`QueryAPI::run`]
+    fn iter_tables(&'a self) -> QueryTableIter<'a, P>
+    where
+        P: ComponentId,
+    {
+        QueryTableIter::new(self.retrieve_iter(), self.iter_next_func(), self.world())
+    }
+
     fn iter_stage(&'a self, stage: impl WorldProvider<'a>) -> QueryIter<'a, P, T> {
         QueryIter::new(self.retrieve_iter_stage(stage), self.iter_next_func())
     }
@@ -1005,7 +1061,7 @@ where
     ///
     /// # Returns
     ///
-    /// * `Some(EntityView<'_>)` if the entity was found, `None` if no entity was found.
+    /// The first entity, or [`FlecsError::QueryEmpty`] if the query matched no entities.
     ///
     /// # See also
     ///
@@ -1024,7 +1080,7 @@ where
     ///
     /// let entity = query.try_first_entity();
     ///
-    /// assert!(entity.is_none());
+    /// assert_eq!(entity, Err(FlecsError::QueryEmpty));
     ///
     /// let ent = world.entity().set(Position { x: 10, y: 20 });
     ///
@@ -1040,21 +1096,21 @@ where
     /// * [`Query::first`]
     /// * [`Query::try_first_only`]
     /// * [`Query::first_only`]
-    fn try_first_entity(&self) -> Option<EntityView<'a>> {
+    fn try_first_entity(&self) -> Result<EntityView<'a>, FlecsError> {
         let it = &mut self.retrieve_iter();
 
         if self.iter_next(it) {
             let ent = if it.count > 0 {
-                Some(EntityView::new_from(self.world(), unsafe {
+                Ok(EntityView::new_from(self.world(), unsafe {
                     *it.entities.add(0)
                 }))
             } else {
-                None
+                Err(FlecsError::QueryEmpty)
             };
             unsafe { sys::ecs_iter_fini(it) };
             ent
         } else {
-            None
+            Err(FlecsError::QueryEmpty)
         }
     }
 
@@ -13,8 +13,41 @@ where
     T: QueryTuple,
     P: ComponentId,
 {
-    /// Set context
-    fn set_context(&mut self, context: *mut c_void) -> &mut Self;
+    /// Set a context value that is owned by the system or observer.
+    ///
+    /// The value is dropped when the system or observer is deleted, when it is
+    /// replaced by another call to `set_context`, or when the builder is dropped without
+    /// being built. Read it back from callbacks with [`TableIter::context()`].
+    ///
+    /// Callbacks of multithreaded systems read the context from worker threads, so it must
+    /// be `Send + Sync`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// struct Gravity(f32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world
+    ///     .system::<()>()
+    ///     .set_context(Gravity(9.81))
+    ///     .run(|mut it| {
+    ///         while it.next() {
+    ///             let gravity = it.context::<Gravity>().unwrap();
+    ///             assert_eq!(gravity.0, 9.81);
+    ///         }
+    ///     });
+    ///
+    /// world.progress();
+    /// ```
+    fn set_context<C: Send + Sync + 'static>(&mut self, context: C) -> &mut Self {
+        let (ctx, ctx_free) = context_into_raw(context);
+        self.set_desc_context(ctx, ctx_free);
+        self
+    }
 
     /// Each iterator for systems.
     ///
@@ -1033,17 +1066,19 @@ macro_rules! implement_reactor_api {
             fn set_desc_run(&mut self, callback: Option<crate::core::utility::ExternIterFn>) {
                 self.desc.run = callback;
             }
-        }
 
-        impl<'a, T> SystemAPI<'a, $param, T> for $type
-        where
-            T: QueryTuple,
-        {
-            fn set_context(&mut self, context: *mut c_void) -> &mut Self {
-                self.desc.ctx = context;
-                self
+            fn set_desc_context(
+                &mut self,
+                ctx: *mut c_void,
+                ctx_free: flecs_ecs_sys::ecs_ctx_free_t,
+            ) {
+                free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+                self.desc.ctx = ctx;
+                self.desc.ctx_free = ctx_free;
             }
         }
+
+        impl<'a, T> SystemAPI<'a, $param, T> for $type where T: QueryTuple {}
     };
     ($type:ty) => {
         impl<'a, P, T> internal_SystemAPI<'a, P, T> for $type
@@ -1088,6 +1123,16 @@ macro_rules! implement_reactor_api {
             fn set_desc_run(&mut self, callback: Option<crate::core::utility::ExternIterFn>) {
                 self.desc.run = callback;
             }
+
+            fn set_desc_context(
+                &mut self,
+                ctx: *mut c_void,
+                ctx_free: flecs_ecs_sys::ecs_ctx_free_t,
+            ) {
+                free_desc_context(&mut self.desc.ctx, &mut self.desc.ctx_free);
+                self.desc.ctx = ctx;
+                self.desc.ctx_free = ctx_free;
+            }
         }
 
         impl<'a, P, T> SystemAPI<'a, P, T> for $type
@@ -1095,10 +1140,6 @@ macro_rules! implement_reactor_api {
             T: QueryTuple,
             P: ComponentId,
         {
-            fn set_context(&mut self, context: *mut c_void) -> &mut Self {
-                self.desc.ctx = context;
-                self
-            }
         }
     };
 }
@@ -35,6 +35,151 @@ impl World {
         Component::<T::UnderlyingType>::new_named(self, name)
     }
 
+    /// Find or register component, returning an error instead of aborting when the component
+    /// cannot be registered.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component type.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Component`] if a component with the same symbol was already registered
+    /// with a different size or alignment, for example by C code.
+    pub fn try_component<T: ComponentId>(
+        &self,
+    ) -> Result<Component<'_, T::UnderlyingType>, FlecsError> {
+        self.check_component_registration::<T>(None)?;
+        Ok(self.component::<T>())
+    }
+
+    /// Find or register component with a name, returning an error instead of aborting when the
+    /// component cannot be registered.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The component type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the component.
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Component`] if a component with the same symbol was already registered
+    /// with a different size or alignment, or if `name` is used by another component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// world.component_named::<Position>("Movement");
+    ///
+    /// let err = world.try_component_named::<Velocity>("Movement").unwrap_err();
+    /// assert_eq!(err.code(), FlecsErrorCode::NameInUse);
+    /// ```
+    pub fn try_component_named<'a, T: ComponentId>(
+        &'a self,
+        name: &str,
+    ) -> Result<Component<'a, T::UnderlyingType>, FlecsError> {
+        self.check_component_registration::<T>(Some(name))?;
+        Ok(self.component_named::<T>(name))
+    }
+
+    /// Checks the conditions under which flecs would abort when registering `T`.
+    fn check_component_registration<T: ComponentId>(
+        &self,
+        name: Option<&str>,
+    ) -> Result<(), FlecsError> {
+        let world = self.ptr_mut();
+        let type_name = core::any::type_name::<T>();
+        let symbol = crate::core::type_name_cstring::<T>();
+
+        let error = |code, message| FlecsError::Component {
+            name: name.unwrap_or(type_name).to_string(),
+            code,
+            message,
+        };
+
+        let prev_scope = unsafe { sys::ecs_set_scope(world, 0) };
+        let existing = unsafe { sys::ecs_lookup_symbol(world, symbol.as_ptr(), false, false) };
+        let named = name.map_or(0, |name| {
+            let name = compact_str::format_compact!("{}\0", name);
+            unsafe {
+                sys::ecs_lookup_path_w_sep(
+                    world,
+                    0,
+                    name.as_ptr() as *const _,
+                    SEPARATOR.as_ptr(),
+                    SEPARATOR.as_ptr(),
+                    false,
+                )
+            }
+        });
+        unsafe { sys::ecs_set_scope(world, prev_scope) };
+
+        if existing != 0 {
+            let (size, alignment) = unsafe {
+                let ti = sys::ecs_get_type_info(world, existing);
+                if ti.is_null() {
+                    (0, 0)
+                } else {
+                    ((*ti).size as usize, (*ti).alignment as usize)
+                }
+            };
+            let expected_size = core::mem::size_of::<T>();
+            if size != expected_size {
+                return Err(error(
+                    FlecsErrorCode::InvalidComponentSize,
+                    format!(
+                        "`{type_name}` is registered with size {size}, expected {expected_size}"
+                    ),
+                ));
+            }
+            let expected_alignment = if expected_size != 0 {
+                core::mem::align_of::<T>()
+            } else {
+                0
+            };
+            if alignment != expected_alignment {
+                return Err(error(
+                    FlecsErrorCode::InvalidComponentAlignment,
+                    format!(
+                        "`{type_name}` is registered with alignment {alignment}, expected {expected_alignment}"
+                    ),
+                ));
+            }
+        }
+
+        if named != 0 && named != existing && self.entity_from_id(named).has(flecs::Component::ID) {
+            return Err(error(
+                FlecsErrorCode::NameInUse,
+                format!(
+                    "`{}` is already used by component `{}`",
+                    name.unwrap_or_default(),
+                    self.entity_from_id(named).symbol()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Create new untyped component.
     pub fn component_untyped(&self) -> UntypedComponent<'_> {
         UntypedComponent::new(self)
@@ -24,11 +24,13 @@ mod operations;
 mod pipeline;
 mod query;
 mod singleton;
+mod snapshot;
 #[cfg(feature = "flecs_system")]
 mod system;
 mod world;
 
 pub use singleton::*;
+pub use snapshot::*;
 pub use world::*;
 
 /// An entity id range created with [`World::entity_range_new()`].
@@ -1401,7 +1401,7 @@ impl World {
     #[inline(always)]
     pub fn lookup_recursive(&self, name: &str) -> EntityView<'_> {
         self.try_lookup_recursive(name)
-            .unwrap_or_else(|| EntityView::new_from(self, Entity(0)))
+            .unwrap_or_else(|_| EntityView::new_from(self, Entity(0)))
     }
 
     /// Lookup an entity by name.
@@ -1429,11 +1429,12 @@ impl World {
     #[inline(always)]
     pub fn lookup(&self, name: &str) -> EntityView<'_> {
         self.try_lookup_recursive(name)
-            .unwrap_or_else(|| EntityView::new_from(self, Entity(0)))
+            .unwrap_or_else(|_| EntityView::new_from(self, Entity(0)))
     }
 
     /// Helper function for [`World::try_lookup()`] and [`World::try_lookup_recursive()`].
-    fn try_lookup_impl(&self, name: &str, recursively: bool) -> Option<EntityView<'_>> {
+    fn try_lookup_impl(&self, name: &str, recursively: bool) -> Result<EntityView<'_>, FlecsError> {
+        let path = name;
         let name = compact_str::format_compact!("{}\0", name);
 
         let entity_id = unsafe {
@@ -1447,9 +1448,11 @@ impl World {
             )
         };
         if entity_id == 0 {
-            None
+            Err(FlecsError::EntityNotFound {
+                name: path.to_string(),
+            })
         } else {
-            Some(EntityView::new_from(self, entity_id))
+            Ok(EntityView::new_from(self, entity_id))
         }
     }
 
@@ -1463,7 +1466,7 @@ impl World {
     ///
     /// # Returns
     ///
-    /// The entity if found, otherwise `None`.
+    /// The entity if found, otherwise [`FlecsError::EntityNotFound`].
     ///
     /// # See also
     ///
@@ -1472,7 +1475,7 @@ impl World {
     /// * [`World::set_lookup_path()`]
     /// * [`World::try_lookup()`]
     #[inline(always)]
-    pub fn try_lookup_recursive(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup_recursive(&self, name: &str) -> Result<EntityView<'_>, FlecsError> {
         self.try_lookup_impl(name, true)
     }
 
@@ -1484,7 +1487,7 @@ impl World {
     ///
     /// # Returns
     ///
-    /// The entity if found, otherwise `None`.
+    /// The entity if found, otherwise [`FlecsError::EntityNotFound`].
     ///
     /// # See also
     ///
@@ -1493,7 +1496,7 @@ impl World {
     /// * [`World::set_lookup_path()`]
     /// * [`World::try_lookup_recursive()`]
     #[inline(always)]
-    pub fn try_lookup(&self, name: &str) -> Option<EntityView<'_>> {
+    pub fn try_lookup(&self, name: &str) -> Result<EntityView<'_>, FlecsError> {
         self.try_lookup_impl(name, false)
     }
 
@@ -2062,7 +2065,7 @@ impl World {
     ///
     /// The entity with the current generation. If the entity is not alive, this
     /// function will return an Entity of 0. Use `try_get_alive` if you want to
-    /// return an `Option<EntityView>`.
+    /// return a `Result<EntityView, FlecsError>`.
     pub fn get_alive(&self, entity: impl Into<Entity>) -> EntityView<'_> {
         // SAFETY: raw_world is a valid, live world pointer.
         let entity = unsafe { sys::ecs_get_alive(self.raw_world.as_ptr(), *entity.into()) };
@@ -2079,14 +2082,15 @@ impl World {
     /// # Returns
     ///
     /// The entity with the current generation.
-    /// If the entity is not alive, this function will return `None`.
-    pub fn try_get_alive(&self, entity: impl Into<Entity>) -> Option<EntityView<'_>> {
+    /// If the entity is not alive, this function will return [`FlecsError::EntityNotAlive`].
+    pub fn try_get_alive(&self, entity: impl Into<Entity>) -> Result<EntityView<'_>, FlecsError> {
+        let requested = entity.into();
         // SAFETY: raw_world is a valid, live world pointer.
-        let entity = unsafe { sys::ecs_get_alive(self.raw_world.as_ptr(), *entity.into()) };
+        let entity = unsafe { sys::ecs_get_alive(self.raw_world.as_ptr(), *requested) };
         if entity == 0 {
-            None
+            Err(FlecsError::EntityNotAlive { entity: requested })
         } else {
-            Some(EntityView::new_from(self, entity))
+            Ok(EntityView::new_from(self, entity))
         }
     }
 
@@ -1,6 +1,9 @@
 use core::ffi::CStr;
 
-use crate::addons::pipeline::PipelineBuilder;
+use crate::addons::pipeline::{
+    FixedTime, PipelineBuilder, PipelineSchedule, fixed_update_enabled, init_fixed_update_pipeline,
+    run_fixed_steps,
+};
 
 use super::*;
 
@@ -85,6 +88,74 @@ impl World {
         })
     }
 
+    /// Returns the systems a pipeline runs, in order, and the sync points between them.
+    ///
+    /// Builds the schedule like the next world update would, so it can't be called while the
+    /// world is progressing. The schedule can be exported with
+    /// [`PipelineSchedule::to_dot()`] and [`PipelineSchedule::to_json()`].
+    ///
+    /// # Errors
+    ///
+    /// [`FlecsError::Schedule`] if `pipeline` is not a pipeline, or if the sync points could
+    /// not be matched with the operations of the pipeline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is called while a frame is in progress, such as from a system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.entity().set(Position { x: 0.0, y: 0.0 });
+    /// world.system_named::<&mut Position>("Move").each(|_| {});
+    ///
+    /// let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    /// let system = schedule.systems().next().unwrap();
+    /// let position: Id = world.component_id::<Position>().into();
+    /// assert_eq!(system.phase(), Some(Entity::new(flecs::pipeline::OnUpdate::ID)));
+    /// assert_eq!(system.writes(), [position]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`Pipeline::schedule()`](crate::addons::pipeline::Pipeline::schedule)
+    pub fn pipeline_schedule(
+        &self,
+        pipeline: impl IntoEntity,
+    ) -> Result<PipelineSchedule<'_>, FlecsError> {
+        PipelineSchedule::new(self, pipeline.into_entity(self))
+    }
+
+    /// Returns the system set with the given name, creating it if it doesn't exist.
+    ///
+    /// Systems are added to a set with
+    /// [`SystemBuilder::in_set()`](crate::addons::system::SystemBuilder::in_set). Passing the
+    /// set to [`SystemBuilder::before()`](crate::addons::system::SystemBuilder::before) or
+    /// [`SystemBuilder::after()`](crate::addons::system::SystemBuilder::after) orders a system
+    /// relative to all systems of the set, including systems that are added to it later.
+    ///
+    /// Sets are created in the `flecs::rust::SystemSets` scope, so a set never shares an entity
+    /// with a system or another entity of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the set.
+    pub fn system_set(&self, name: &str) -> EntityView<'_> {
+        let scope = self.entity_named("flecs::rust::SystemSets");
+        let set = self.entity_named_child_of(scope, name);
+        self.world_ctx().system_order.borrow_mut().add_set(set.id());
+        set
+    }
+
     /// Progress world one tick.
     ///
     /// Progresses the world by running all enabled and periodic systems
@@ -128,9 +199,21 @@ impl World {
     /// # See also
     ///
     /// * [`World::progress()`]
+    /// * [`World::set_fixed_time_step()`]
     /// * C API: `ecs_progress`
     #[inline(always)]
     pub fn progress_time(&self, delta_time: f32) -> bool {
+        if fixed_update_enabled(self) {
+            // runs the fixed steps once the frame has begun, before the pipeline
+            return unsafe {
+                sys::ecs_rust_progress(
+                    self.raw_world.as_ptr(),
+                    delta_time,
+                    Some(run_fixed_steps),
+                    core::ptr::null_mut(),
+                )
+            };
+        }
         unsafe { sys::ecs_progress(self.raw_world.as_ptr(), delta_time) }
     }
 
@@ -196,6 +279,8 @@ impl World {
 
     /// Set time scale. Increase or decrease simulation speed by the provided multiplier.
     ///
+    /// While the world is paused, the time scale applies once the world is resumed.
+    ///
     /// # Arguments
     ///
     /// * `mul` - The multiplier to set the time scale to.
@@ -203,8 +288,13 @@ impl World {
     /// # See also
     ///
     /// * [`World::get_time_scale()`]
+    /// * [`World::pause()`]
     #[inline(always)]
     pub fn set_time_scale(&self, mul: FTime) {
+        if let Some(paused) = self.world_ctx().paused.borrow_mut().as_mut() {
+            paused.time_scale = mul;
+            return;
+        }
         unsafe {
             sys::ecs_set_time_scale(self.raw_world.as_ptr(), mul);
         }
@@ -217,6 +307,9 @@ impl World {
     /// real-time, values greater than 1.0 speed up the simulation, and values
     /// less than 1.0 slow it down.
     ///
+    /// While the world is paused, this is the time scale that applies once the world is
+    /// resumed.
+    ///
     /// # Returns
     ///
     /// The current time scale as a floating point number.
@@ -226,9 +319,194 @@ impl World {
     /// * [`World::set_time_scale()`]
     #[inline(always)]
     pub fn get_time_scale(&self) -> FTime {
+        if let Some(paused) = self.world_ctx().paused.borrow().as_ref() {
+            return paused.time_scale;
+        }
         self.info().time_scale
     }
 
+    /// Pause gameplay time.
+    ///
+    /// While the world is paused, world time doesn't advance and the delta time of systems is
+    /// zero, so gameplay stops without bookkeeping in the systems themselves. Fixed updates
+    /// don't run, and timers are stopped, including timers that are created or set while the
+    /// world is paused. A timer started with `TimerAPI::start()` while the world is paused
+    /// runs. Systems created with
+    /// [`SystemBuilder::unscaled_time()`](crate::addons::system::SystemBuilder::unscaled_time)
+    /// still see the real delta time, and their timers keep running, which is meant for UI,
+    /// menus and debug tools.
+    ///
+    /// Systems keep running with a delta time of zero. Systems that shouldn't run at all can
+    /// check [`World::is_paused()`] in a run condition.
+    ///
+    /// When called while the world is progressing, for example from a system that opens a pause
+    /// menu, the world is paused at the end of the frame. Pausing a paused world has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.pause();
+    /// world.progress_time(0.5);
+    /// assert_eq!(world.info().world_time_total, 0.0);
+    ///
+    /// world.resume();
+    /// world.progress_time(0.5);
+    /// assert_eq!(world.info().world_time_total, 0.5);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::resume()`]
+    /// * [`World::is_paused()`]
+    pub fn pause(&self) {
+        if self.real_world().is_readonly() {
+            self.run_post_frame(|world| world.pause());
+            return;
+        }
+        if self.is_paused() {
+            return;
+        }
+
+        let time_scale = self.info().time_scale;
+        let mut timers = Vec::new();
+        #[cfg(feature = "flecs_timer")]
+        let timer_observer = {
+            self.query::<&mut flecs::timer::Timer>()
+                .without(crate::addons::system::UnscaledTime::id())
+                .build()
+                .each_entity(|entity, timer| {
+                    if timer.active {
+                        timer.active = false;
+                        timers.push(entity.id());
+                    }
+                });
+
+            // stops the timers that are created or set while the world is paused
+            let observer = self
+                .observer::<flecs::OnSet, &mut flecs::timer::Timer>()
+                .without(crate::addons::system::UnscaledTime::id())
+                .each_entity(|entity, timer| {
+                    if !timer.active {
+                        return;
+                    }
+                    timer.active = false;
+                    if let Some(paused) = entity.world().world_ctx().paused.borrow_mut().as_mut()
+                        && !paused.timers.contains(&entity.id())
+                    {
+                        paused.timers.push(entity.id());
+                    }
+                });
+            Some(observer.id())
+        };
+        #[cfg(not(feature = "flecs_timer"))]
+        let timer_observer = None;
+
+        unsafe { sys::ecs_set_time_scale(self.real_world().ptr_mut(), 0.0) };
+        *self.world_ctx().paused.borrow_mut() = Some(PausedTime {
+            time_scale,
+            timers,
+            timer_observer,
+        });
+    }
+
+    /// Resume gameplay time after [`World::pause()`].
+    ///
+    /// Restores the time scale, and restarts the timers that were stopped by the pause where
+    /// they left off. When called while the world is progressing, the world is resumed at the
+    /// end of the frame. Resuming a world that is not paused has no effect.
+    ///
+    /// # See also
+    ///
+    /// * [`World::pause()`]
+    /// * [`World::is_paused()`]
+    pub fn resume(&self) {
+        if self.real_world().is_readonly() {
+            self.run_post_frame(|world| world.resume());
+            return;
+        }
+        let Some(paused) = self.world_ctx().paused.borrow_mut().take() else {
+            return;
+        };
+
+        unsafe { sys::ecs_set_time_scale(self.real_world().ptr_mut(), paused.time_scale) };
+        if let Some(observer) = paused.timer_observer {
+            self.entity_from_id(observer).destruct();
+        }
+        #[cfg(feature = "flecs_timer")]
+        for timer in paused.timers {
+            if self.is_alive(timer) {
+                self.entity_from_id(timer)
+                    .try_get::<&mut flecs::timer::Timer>(|timer| timer.active = true);
+            }
+        }
+    }
+
+    /// Whether the world is paused by [`World::pause()`].
+    ///
+    /// # See also
+    ///
+    /// * [`World::pause()`]
+    /// * [`World::resume()`]
+    pub fn is_paused(&self) -> bool {
+        self.world_ctx().paused.borrow().is_some()
+    }
+
+    /// Enable fixed updates with the provided time step.
+    ///
+    /// Systems in the [`FixedUpdate`](crate::addons::pipeline::FixedUpdate) phase then run
+    /// once for every `step` seconds of world time, zero or more times per frame, before the
+    /// other systems. Inside them, [`TableIter::delta_time()`] is `step`. The accumulated time
+    /// that has not been consumed is kept when the step changes.
+    ///
+    /// The step, accumulator and number of steps of the last frame are stored in the
+    /// [`FixedTime`] singleton, and fixed updates are disabled by removing it. The
+    /// [`FixedAlpha`](crate::addons::pipeline::FixedAlpha) singleton holds the interpolation
+    /// factor for rendering.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - Duration of a fixed step in seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_fixed_max_steps()`]
+    pub fn set_fixed_time_step(&self, step: FTime) {
+        assert!(step > 0.0, "fixed time step must be positive");
+        let fixed = self
+            .try_get::<&FixedTime>(|fixed| FixedTime { step, ..*fixed })
+            .unwrap_or_else(|| FixedTime::new(step));
+        init_fixed_update_pipeline(self);
+        self.set(fixed);
+    }
+
+    /// Set the maximum number of fixed steps in a frame.
+    ///
+    /// When a frame needs more steps to catch up, the remaining full steps are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_steps` - The maximum number of fixed steps in a frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fixed updates are not enabled.
+    ///
+    /// # See also
+    ///
+    /// * [`World::set_fixed_time_step()`]
+    pub fn set_fixed_max_steps(&self, max_steps: u32) {
+        self.try_get::<&mut FixedTime>(|fixed| fixed.max_steps = max_steps)
+            .expect("fixed updates are not enabled, see World::set_fixed_time_step");
+    }
+
     /// Get target frames per second (FPS).
     ///
     /// Retrieves the target FPS for the world. This value is used to calculate
@@ -345,6 +623,7 @@ impl World {
     /// # See also
     ///
     /// * [`World::using_task_threads()`]
+    /// * [`set_task_pool`](crate::core::set_task_pool) - Run the task threads on a Rust executor
     #[inline(always)]
     pub fn set_task_threads(&self, task_threads: i32) {
         unsafe {
@@ -0,0 +1,571 @@
+//! In-memory snapshots of the entities in a world.
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use core::alloc::Layout;
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+use hashbrown::{HashMap, HashSet};
+
+use super::*;
+
+/// A copy of the entities and component values of a world, created with
+/// [`World::snapshot()`] or [`World::snapshot_query()`].
+///
+/// Component values are copied with the copy hooks of the component, so they are
+/// independent from the world: the world can keep changing while the snapshot is
+/// held, and the snapshot can be restored any number of times with
+/// [`World::restore()`].
+///
+/// Unlike [`World::to_json_world()`], snapshots do not require reflection data, and
+/// contain the values of all components.
+///
+/// # Limitations
+///
+/// - Components must implement `Clone`. Snapshotting an entity with a component that
+///   does not panics, the same way duplicating the entity would.
+/// - Components, modules, systems, observers, queries and builtin entities are not part
+///   of a snapshot, nor are their children and the entities they are scoped in. This
+///   includes singletons, which are stored on their component entity.
+/// - Entity names are restored, but symbols and aliases are not.
+/// - Components that do not fragment tables (see [`flecs::DontFragment`]) are not
+///   part of a snapshot.
+pub struct Snapshot {
+    world: *const sys::ecs_world_t,
+    /// Whether the snapshot contains all entities of the world, in which case entities
+    /// created after the snapshot are deleted when it is restored.
+    full: bool,
+    tables: Vec<SnapshotTable>,
+}
+
+/// Entities from consecutive rows of the same table.
+struct SnapshotTable {
+    /// Table type, without identifier pairs. Sorted, since table types are sorted.
+    ids: Vec<u64>,
+    entities: Vec<u64>,
+    names: Vec<Option<String>>,
+    columns: Vec<SnapshotColumn>,
+}
+
+/// Copied values of one component for the entities of a [`SnapshotTable`].
+struct SnapshotColumn {
+    id: u64,
+    type_info: sys::ecs_type_info_t,
+    data: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+}
+
+impl Snapshot {
+    /// Number of entities in the snapshot.
+    pub fn len(&self) -> usize {
+        self.tables.iter().map(|table| table.entities.len()).sum()
+    }
+
+    /// Returns true if the snapshot contains no entities.
+    pub fn is_empty(&self) -> bool {
+        self.tables.iter().all(|table| table.entities.is_empty())
+    }
+
+    /// Returns true if the snapshot contains `entity`, including its generation.
+    pub fn contains(&self, entity: impl Into<Entity>) -> bool {
+        let entity = *entity.into();
+        self.tables
+            .iter()
+            .any(|table| table.entities.contains(&entity))
+    }
+
+    /// The entities in the snapshot.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entities.iter().map(|&entity| Entity::new(entity)))
+    }
+
+    fn new(world: &World, entities: impl IntoIterator<Item = u64>, full: bool) -> Self {
+        let world_ptr = world.ptr_mut();
+
+        let mut internal = InternalEntities::new(world);
+
+        // group the entities by table, in the order the tables are first seen
+        let mut table_index: HashMap<*mut sys::ecs_table_t, usize> = HashMap::new();
+        let mut rows: Vec<(*mut sys::ecs_table_t, Vec<(i32, u64)>)> = Vec::new();
+        for entity in entities {
+            let record = unsafe { sys::ecs_record_find(world_ptr, entity) };
+            if record.is_null() || internal.contains(entity) {
+                continue;
+            }
+            let (table, row) = unsafe { ((*record).table, ecs_record_to_row((*record).row)) };
+            let index = *table_index.entry(table).or_insert_with(|| {
+                rows.push((table, Vec::new()));
+                rows.len() - 1
+            });
+            rows[index].1.push((row, entity));
+        }
+
+        let mut tables = Vec::new();
+        for (table, mut table_rows) in rows {
+            table_rows.sort_unstable_by_key(|&(row, _)| row);
+            for run in table_rows.chunk_by(|a, b| a.0 + 1 == b.0) {
+                tables.push(SnapshotTable::new(world_ptr, table, run));
+            }
+        }
+
+        Self {
+            world: world_ptr,
+            full,
+            tables,
+        }
+    }
+}
+
+impl core::fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("full", &self.full)
+            .field("entities", &self.len())
+            .finish()
+    }
+}
+
+impl SnapshotTable {
+    /// Copy the entities in `run`, which are in consecutive rows of `table`.
+    fn new(world: *mut sys::ecs_world_t, table: *mut sys::ecs_table_t, run: &[(i32, u64)]) -> Self {
+        let entities: Vec<u64> = run.iter().map(|&(_, entity)| entity).collect();
+        let names = entities
+            .iter()
+            .map(|&entity| {
+                unsafe { EntityView::new_from(WorldRef::from_ptr(world), entity) }.get_name()
+            })
+            .collect();
+
+        let mut ids = Vec::new();
+        let mut columns = Vec::new();
+        let type_ = unsafe { sys::ecs_table_get_type(table) };
+        let type_ids: &[u64] = if type_.is_null() || unsafe { (*type_).count } == 0 {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts((*type_).array, (*type_).count as usize) }
+        };
+
+        for (type_index, &id) in type_ids.iter().enumerate() {
+            if is_identifier(world, id) {
+                continue;
+            }
+            ids.push(id);
+
+            let type_info = unsafe { sys::ecs_get_type_info(world, id) };
+            if type_info.is_null() || unsafe { (*type_info).size } == 0 {
+                continue;
+            }
+
+            let mut column = SnapshotColumn::new(id, unsafe { *type_info }, entities.len());
+            let column_index =
+                unsafe { sys::ecs_table_type_to_column_index(table, type_index as i32) };
+            if column_index >= 0 {
+                let src = unsafe { sys::ecs_table_get_column(table, column_index, run[0].0) };
+                unsafe { column.push(src, entities.len()) };
+            } else {
+                // sparse components are not stored in table columns
+                for &entity in &entities {
+                    let src = unsafe { sys::ecs_get_id(world, entity, id) };
+                    unsafe { column.push(src, 1) };
+                }
+            }
+            columns.push(column);
+        }
+
+        Self {
+            ids,
+            entities,
+            names,
+            columns,
+        }
+    }
+
+    /// Restore the components of the entities, which must be alive.
+    fn restore(&self, world: *mut sys::ecs_world_t) {
+        for (row, &entity) in self.entities.iter().enumerate() {
+            let current: Vec<u64> = unsafe {
+                let type_ = sys::ecs_get_type(world, entity);
+                if type_.is_null() || (*type_).count == 0 {
+                    Vec::new()
+                } else {
+                    core::slice::from_raw_parts((*type_).array, (*type_).count as usize).to_vec()
+                }
+            };
+            for id in current {
+                if !is_identifier(world, id) && self.ids.binary_search(&id).is_err() {
+                    unsafe { sys::ecs_remove_id(world, entity, id) };
+                }
+            }
+
+            let mut columns = self.columns.iter().peekable();
+            for &id in &self.ids {
+                match columns.next_if(|column| column.id == id) {
+                    Some(column) => unsafe { column.restore(world, entity, row) },
+                    None => unsafe { sys::ecs_add_id(world, entity, id) },
+                }
+            }
+        }
+    }
+}
+
+impl SnapshotColumn {
+    fn new(id: u64, type_info: sys::ecs_type_info_t, capacity: usize) -> Self {
+        let layout = Self::layout(&type_info, capacity);
+        let data =
+            NonNull::new(unsafe { alloc(layout) }).unwrap_or_else(|| handle_alloc_error(layout));
+        Self {
+            id,
+            type_info,
+            data,
+            len: 0,
+            capacity,
+        }
+    }
+
+    fn layout(type_info: &sys::ecs_type_info_t, capacity: usize) -> Layout {
+        Layout::from_size_align(
+            type_info.size as usize * capacity,
+            type_info.alignment as usize,
+        )
+        .expect("invalid component layout")
+    }
+
+    fn get(&self, index: usize) -> *mut c_void {
+        unsafe { self.data.as_ptr().add(index * self.type_info.size as usize) as *mut c_void }
+    }
+
+    /// Copy `count` values from `src` to the end of the column.
+    ///
+    /// # Safety
+    ///
+    /// `src` must point to `count` valid values of the column's component.
+    unsafe fn push(&mut self, src: *const c_void, count: usize) {
+        assert!(self.len + count <= self.capacity);
+        let dst = self.get(self.len);
+        match self.type_info.hooks.copy_ctor {
+            Some(copy_ctor) => unsafe { copy_ctor(dst, src, count as i32, &self.type_info) },
+            None => unsafe {
+                core::ptr::copy_nonoverlapping(
+                    src as *const u8,
+                    dst as *mut u8,
+                    count * self.type_info.size as usize,
+                );
+            },
+        }
+        // only count the values once they are copied, a panicking copy hook leaves the
+        // column with only initialized values
+        self.len += count;
+    }
+
+    /// Assign the value at `row` to the component of `entity`.
+    ///
+    /// # Safety
+    ///
+    /// `entity` must be alive and `row` must be in bounds.
+    unsafe fn restore(&self, world: *mut sys::ecs_world_t, entity: u64, row: usize) {
+        let src = self.get(row);
+        let size = self.type_info.size as usize;
+        let res = unsafe { sys::ecs_rust_set(world, entity, self.id, src, size) };
+        assert!(
+            !res.ptr.is_null(),
+            "restore failed: entity is not alive or the world is invalid"
+        );
+
+        // the component is not constructed when it is new, so it has to be copy constructed
+        let hook = if res.is_new {
+            self.type_info.hooks.copy_ctor
+        } else {
+            self.type_info.hooks.copy
+        };
+        match hook {
+            Some(hook) => unsafe { hook(res.ptr, src, 1, &self.type_info) },
+            None => unsafe {
+                core::ptr::copy_nonoverlapping(src as *const u8, res.ptr as *mut u8, size);
+            },
+        }
+
+        if res.call_modified {
+            unsafe { sys::ecs_modified_id(world, entity, self.id) };
+        }
+    }
+}
+
+impl Drop for SnapshotColumn {
+    fn drop(&mut self) {
+        if let Some(dtor) = self.type_info.hooks.dtor
+            && self.len > 0
+        {
+            unsafe {
+                dtor(
+                    self.data.as_ptr() as *mut c_void,
+                    self.len as i32,
+                    &self.type_info,
+                );
+            };
+        }
+        unsafe {
+            dealloc(
+                self.data.as_ptr(),
+                Self::layout(&self.type_info, self.capacity),
+            );
+        }
+    }
+}
+
+/// Identifier pairs (names, symbols and aliases) are not copied, names are restored
+/// through [`EntityView::set_name()`] so the name index stays valid.
+fn is_identifier(world: *mut sys::ecs_world_t, id: u64) -> bool {
+    ecs_is_pair(id) && ecs_first(id, unsafe { WorldRef::from_ptr(world) }) == ECS_IDENTIFIER
+}
+
+/// Entities that are part of the world's infrastructure rather than its data: builtin,
+/// module, component and poly (system, observer, query) entities, their children, and
+/// the parents that scope them, such as `flecs`.
+struct InternalEntities {
+    world: *mut sys::ecs_world_t,
+    cache: HashMap<u64, bool>,
+}
+
+impl InternalEntities {
+    fn new(world: &World) -> Self {
+        let mut internal = Self {
+            world: world.ptr_mut(),
+            cache: HashMap::new(),
+        };
+        world.each_alive_entity(|entity| {
+            let world = internal.world;
+            let entity = *entity.id();
+            if !is_internal_table(world, unsafe { sys::ecs_get_table(world, entity) }) {
+                return;
+            }
+            let mut parent = unsafe { sys::ecs_get_parent(world, entity) };
+            while parent != 0 && internal.cache.insert(parent, true) != Some(true) {
+                parent = unsafe { sys::ecs_get_parent(world, parent) };
+            }
+        });
+        internal
+    }
+
+    fn contains(&mut self, entity: u64) -> bool {
+        if let Some(&internal) = self.cache.get(&entity) {
+            return internal;
+        }
+        let internal = is_internal_table(self.world, unsafe {
+            sys::ecs_get_table(self.world, entity)
+        }) || {
+            let parent = unsafe { sys::ecs_get_parent(self.world, entity) };
+            parent != 0 && self.contains(parent)
+        };
+        self.cache.insert(entity, internal);
+        internal
+    }
+}
+
+fn is_internal_table(world: *mut sys::ecs_world_t, table: *mut sys::ecs_table_t) -> bool {
+    if table.is_null() {
+        return false;
+    }
+    unsafe {
+        sys::ecs_table_has_flags(table, sys::EcsTableHasBuiltins)
+            || sys::ecs_table_has_flags(table, sys::EcsTableHasModule)
+            || sys::ecs_table_has_flags(table, sys::EcsTableNotQueryable)
+            || sys::ecs_table_has_id(world, table, ECS_COMPONENT)
+            || sys::ecs_table_has_id(world, table, ECS_MODULE)
+            || sys::ecs_table_has_id(world, table, ecs_pair(ECS_POLY, ECS_WILDCARD))
+    }
+}
+
+impl World {
+    /// Take a snapshot of all entities in the world.
+    ///
+    /// Restoring the snapshot with [`World::restore()`] brings back the entities, their
+    /// components, component values and names, and deletes the entities that were
+    /// created after the snapshot was taken.
+    ///
+    /// See [`Snapshot`] for which entities and components are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let player = world.entity_named("player").set(Health(100));
+    ///
+    /// let snapshot = world.snapshot();
+    ///
+    /// player.set(Health(20));
+    /// let enemy = world.entity_named("enemy");
+    ///
+    /// world.restore(&snapshot);
+    ///
+    /// player.get::<&Health>(|health| assert_eq!(health.0, 100));
+    /// assert!(!enemy.is_alive());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::snapshot_query()`]
+    /// * [`World::restore()`]
+    pub fn snapshot(&self) -> Snapshot {
+        let entities = unsafe { sys::ecs_get_entities(self.raw_world.as_ptr()) };
+        let ids: &[u64] = if entities.alive_count == 0 {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(entities.ids, entities.alive_count as usize) }
+        };
+        Snapshot::new(self, ids.to_vec(), true)
+    }
+
+    /// Take a snapshot of the entities matched by `query`.
+    ///
+    /// Restoring the snapshot with [`World::restore()`] brings back the matched entities,
+    /// their components, component values and names. Other entities are not changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flecs_ecs::prelude::*;
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// let world = World::new();
+    /// let e = world.entity().set(Position { x: 1.0, y: 2.0 });
+    ///
+    /// let query = world.new_query::<&Position>();
+    /// let snapshot = world.snapshot_query(&query);
+    ///
+    /// e.set(Position { x: 5.0, y: 6.0 });
+    /// world.restore(&snapshot);
+    ///
+    /// e.get::<&Position>(|pos| assert_eq!(pos.x, 1.0));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`World::snapshot()`]
+    /// * [`World::restore()`]
+    pub fn snapshot_query<'a, P, T: QueryTuple>(
+        &self,
+        query: &impl QueryAPI<'a, P, T>,
+    ) -> Snapshot {
+        let mut entities = Vec::new();
+        let mut seen = HashSet::new();
+        let mut iter = query.retrieve_iter();
+        while query.iter_next(&mut iter) {
+            if iter.entities.is_null() {
+                continue;
+            }
+            let matched =
+                unsafe { core::slice::from_raw_parts(iter.entities, iter.count as usize) };
+            entities.extend(
+                matched
+                    .iter()
+                    .copied()
+                    .filter(|&entity| seen.insert(entity)),
+            );
+        }
+        Snapshot::new(self, entities, false)
+    }
+
+    /// Restore the entities in `snapshot` to the state they had when it was taken.
+    ///
+    /// Entities that were deleted since the snapshot are recreated with the same id.
+    /// Components and tags that were added since are removed, and the component values
+    /// and names of the snapshot are assigned, which invokes `OnAdd`, `OnRemove` and
+    /// `OnSet` hooks and observers as usual.
+    ///
+    /// If the snapshot was created with [`World::snapshot()`], entities that were created
+    /// after the snapshot are deleted.
+    ///
+    /// # Panics
+    ///
+    /// - If the snapshot was taken from a different world.
+    /// - If the world is deferred.
+    ///
+    /// # See also
+    ///
+    /// * [`World::snapshot()`]
+    /// * [`World::snapshot_query()`]
+    pub fn restore(&self, snapshot: &Snapshot) {
+        let world = self.ptr_mut();
+        assert!(
+            core::ptr::eq(snapshot.world, world),
+            "cannot restore a snapshot that was taken from a different world"
+        );
+        assert!(
+            !self.is_deferred(),
+            "cannot restore a snapshot while the world is deferred"
+        );
+
+        if snapshot.full {
+            let keep: HashSet<u64> = snapshot.entities().map(|entity| *entity).collect();
+            let mut internal = InternalEntities::new(self);
+            let mut created = Vec::new();
+            self.each_alive_entity(|entity| {
+                let entity = *entity.id();
+                if !keep.contains(&entity) && !internal.contains(entity) {
+                    created.push(entity);
+                }
+            });
+            for entity in created {
+                // deleting a parent also deletes its children
+                if unsafe { sys::ecs_is_alive(world, entity) } {
+                    unsafe { sys::ecs_delete(world, entity) };
+                }
+            }
+        }
+
+        for entity in snapshot.entities() {
+            let entity = *entity;
+            unsafe {
+                if sys::ecs_is_alive(world, entity) {
+                    continue;
+                }
+                // the id was recycled by an entity that is not part of the snapshot
+                let recycled = sys::ecs_get_alive(world, entity);
+                if recycled != 0 {
+                    sys::ecs_delete(world, recycled);
+                }
+                sys::ecs_make_alive(world, entity);
+            }
+        }
+
+        for table in &snapshot.tables {
+            table.restore(world);
+        }
+
+        // clear names that changed first, so names can be swapped between entities
+        let names = || {
+            snapshot.tables.iter().flat_map(|table| {
+                table
+                    .entities
+                    .iter()
+                    .zip(&table.names)
+                    .map(|(&entity, name)| (self.entity_from_id(entity), name.as_deref()))
+            })
+        };
+        for (entity, name) in names() {
+            if entity.get_name().as_deref() != name {
+                entity.remove_name();
+            }
+        }
+        for (entity, name) in names() {
+            if let Some(name) = name
+                && entity.get_name().is_none()
+            {
+                entity.set_name(name);
+            }
+        }
+    }
+}
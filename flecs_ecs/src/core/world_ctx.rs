@@ -22,6 +22,27 @@ pub(crate) struct WorldCtx {
     // a handle dropping on another thread takes the lock so its refcount
     // release can never interleave with `ecs_fini` freeing query memory.
     world_dead: Arc<Mutex<bool>>,
+    #[cfg(feature = "flecs_rest")]
+    pub(crate) rest_routes: core::cell::RefCell<alloc::vec::Vec<crate::addons::rest::RestRoute>>,
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) system_order: core::cell::RefCell<crate::addons::pipeline::SystemOrder>,
+    #[cfg(feature = "flecs_pipeline")]
+    pub(crate) paused: core::cell::RefCell<Option<PausedTime>>,
+    /// Change ticks of the singletons watched by `resource_changed` conditions, by component.
+    #[cfg(feature = "flecs_system")]
+    pub(crate) resource_ticks:
+        core::cell::RefCell<hashbrown::HashMap<u64, alloc::rc::Rc<core::cell::Cell<u64>>>>,
+}
+
+/// State saved by `World::pause()`, and restored by `World::resume()`.
+#[cfg(feature = "flecs_pipeline")]
+pub(crate) struct PausedTime {
+    /// Time scale to restore.
+    pub(crate) time_scale: super::FTime,
+    /// Timers that were stopped by the pause.
+    pub(crate) timers: alloc::vec::Vec<super::Entity>,
+    /// Observer that stops the timers that are created while the world is paused.
+    pub(crate) timer_observer: Option<super::Entity>,
 }
 
 impl WorldCtx {
@@ -33,6 +54,14 @@ impl WorldCtx {
             is_panicking: core::sync::atomic::AtomicBool::new(false),
             owning_thread: std::thread::current().id(),
             world_dead: Arc::new(Mutex::new(false)),
+            #[cfg(feature = "flecs_rest")]
+            rest_routes: Default::default(),
+            #[cfg(feature = "flecs_pipeline")]
+            system_order: Default::default(),
+            #[cfg(feature = "flecs_pipeline")]
+            paused: Default::default(),
+            #[cfg(feature = "flecs_system")]
+            resource_ticks: Default::default(),
         }
     }
 
@@ -32,11 +32,11 @@ fn script_builder_reuse_file_then_code() {
 
     let from_file = builder.build_from_file(path.to_str().unwrap());
     assert_ne!(*from_file.id(), 0);
-    assert!(world.try_lookup("file_ent").is_some());
+    assert!(world.try_lookup("file_ent").is_ok());
 
     let from_code = builder.build_from_code("code_ent {}");
     assert_ne!(*from_code.id(), 0);
-    assert!(world.try_lookup("code_ent").is_some());
+    assert!(world.try_lookup("code_ent").is_ok());
 
     std::fs::remove_file(&path).ok();
 }
@@ -55,11 +55,11 @@ fn script_builder_reuse_code_then_file() {
 
     let from_code = builder.build_from_code("code_ent2 {}");
     assert_ne!(*from_code.id(), 0);
-    assert!(world.try_lookup("code_ent2").is_some());
+    assert!(world.try_lookup("code_ent2").is_ok());
 
     let from_file = builder.build_from_file(path.to_str().unwrap());
     assert_ne!(*from_file.id(), 0);
-    assert!(world.try_lookup("file_ent2").is_some());
+    assert!(world.try_lookup("file_ent2").is_ok());
 
     std::fs::remove_file(&path).ok();
 }
@@ -71,10 +71,10 @@ fn alert_builder_try_build() {
     world.component::<Position>();
 
     let valid = world.alert::<&Position>().try_build();
-    assert!(valid.is_some());
+    assert!(valid.is_ok());
 
     let invalid = world.alert::<()>().expr("invalid syntax!!!").try_build();
-    assert!(invalid.is_none());
+    assert!(invalid.is_err());
 }
 
 #[test]
@@ -90,7 +90,7 @@ fn system_builder_try_build() {
     let valid = world
         .system_builder_from_desc::<&Position>(desc)
         .try_build();
-    assert!(valid.is_some());
+    assert!(valid.is_ok());
 
     let desc = flecs_ecs::sys::ecs_system_desc_t {
         callback: Some(noop_iter),
@@ -100,7 +100,7 @@ fn system_builder_try_build() {
         .system_builder_from_desc::<()>(desc)
         .expr("invalid syntax!!!")
         .try_build();
-    assert!(invalid.is_none());
+    assert!(invalid.is_err());
 }
 
 #[test]
@@ -113,12 +113,12 @@ fn observer_builder_try_build() {
 
     let mut valid_builder = world.observer::<flecs::OnSet, &Position>();
     valid_builder.set_desc_callback(Some(noop_iter));
-    assert!(valid_builder.try_build().is_some());
+    assert!(valid_builder.try_build().is_ok());
 
     let mut invalid_builder = world.observer::<flecs::OnSet, ()>();
     invalid_builder.expr("invalid syntax!!!");
     invalid_builder.set_desc_callback(Some(noop_iter));
-    assert!(invalid_builder.try_build().is_none());
+    assert!(invalid_builder.try_build().is_err());
 }
 
 #[test]
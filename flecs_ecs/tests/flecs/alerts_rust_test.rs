@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+use core::cell::RefCell;
+
+use alloc::rc::Rc;
+
+fn alert_world() -> World {
+    let world = World::new();
+    world.import::<AlertsModule>();
+    world.component::<Position>();
+    world.component::<Velocity>();
+    world
+}
+
+#[test]
+fn alert_instances_expose_source_severity_and_message() {
+    let world = alert_world();
+
+    let alert = world
+        .alert_named::<()>("missing_velocity")
+        .with(Position::id())
+        .without(Velocity::id())
+        .message("$this has Position but not Velocity")
+        .severity(Warning)
+        .build();
+
+    let e = world.entity_named("e").set(Position { x: 1, y: 2 });
+    world
+        .entity_named("ok")
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 1, y: 1 });
+
+    world.progress_time(1.0);
+    world.progress_time(1.0);
+
+    let instances = world.alert_instances();
+    assert_eq!(instances.len(), 1);
+
+    let instance = instances[0];
+    assert_eq!(instance.source(), e);
+    assert_eq!(instance.alert().id(), alert.id());
+    assert_eq!(instance.severity(), AlertSeverity::Warning);
+    assert_eq!(
+        instance.message().as_deref(),
+        Some("e has Position but not Velocity")
+    );
+    assert!(instance.duration() > 0.0);
+
+    let mut count = 0;
+    alert.each_instance(|instance| {
+        assert_eq!(instance.source(), e);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn alert_instances_clear_when_condition_stops_matching() {
+    let world = alert_world();
+
+    world
+        .alert::<()>()
+        .with(Position::id())
+        .without(Velocity::id())
+        .build();
+
+    let e = world.entity().set(Position { x: 1, y: 2 });
+    world.progress_time(1.0);
+    assert_eq!(world.alert_instances().len(), 1);
+    assert_eq!(world.alert_instances()[0].severity(), AlertSeverity::Error);
+
+    e.set(Velocity { x: 1, y: 1 });
+    world.progress_time(1.0);
+    assert!(world.alert_instances().is_empty());
+}
+
+#[test]
+fn on_alert_reports_raise_and_clear() {
+    let world = alert_world();
+
+    let events: Rc<RefCell<Vec<(u64, AlertTransition)>>> = Rc::default();
+    let events_cb = events.clone();
+    world.on_alert(move |instance, transition| {
+        events_cb
+            .borrow_mut()
+            .push((*instance.source().id(), transition));
+    });
+
+    world
+        .alert::<()>()
+        .with(Position::id())
+        .without(Velocity::id())
+        .severity(Critical)
+        .build();
+
+    let e = world.entity().set(Position { x: 1, y: 2 });
+    world.progress_time(1.0);
+    assert_eq!(*events.borrow(), vec![(*e.id(), AlertTransition::Raised)]);
+
+    e.set(Velocity { x: 1, y: 1 });
+    world.progress_time(1.0);
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            (*e.id(), AlertTransition::Raised),
+            (*e.id(), AlertTransition::Cleared)
+        ]
+    );
+}
@@ -0,0 +1,245 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+#[derive(Component, Debug, PartialEq)]
+struct Name(String);
+
+#[derive(Bundle)]
+struct Movable {
+    position: Position,
+    velocity: Velocity,
+}
+
+#[derive(Bundle)]
+struct NamedMovable {
+    #[flecs(bundle)]
+    movable: Movable,
+    name: Name,
+    tag: Tag,
+}
+
+/// Counts how often it is dropped.
+#[derive(Component)]
+struct Tracked(Rc<Cell<u32>>, u32);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[derive(Bundle)]
+struct TrackedMovable {
+    #[flecs(bundle)]
+    movable: Movable,
+    tracked: Tracked,
+}
+
+#[derive(Bundle)]
+struct DuplicatePosition {
+    #[flecs(bundle)]
+    movable: Movable,
+    position: Position,
+}
+
+fn movable(x: i32) -> Movable {
+    Movable {
+        position: Position { x, y: x * 2 },
+        velocity: Velocity { x: 1, y: 1 },
+    }
+}
+
+#[test]
+fn bundle_set() {
+    let world = World::new();
+
+    let e = world.entity().set_bundle(NamedMovable {
+        movable: movable(1),
+        name: Name("one".to_string()),
+        tag: Tag,
+    });
+
+    assert!(e.has(Tag));
+    e.get::<(&Position, &Velocity, &Name)>(|(pos, vel, name)| {
+        assert_eq!(pos.x, 1);
+        assert_eq!(pos.y, 2);
+        assert_eq!(vel.x, 1);
+        assert_eq!(name.0, "one");
+    });
+}
+
+#[test]
+fn bundle_set_single_table_move() {
+    let world = World::new();
+
+    // if the components were added one at a time, the entity would not have all of them
+    // when the first one is added
+    let complete = Rc::new(Cell::new(0));
+    let complete_ = complete.clone();
+    world
+        .observer::<flecs::OnAdd, ()>()
+        .with(Position::id())
+        .each_entity(move |e, _| {
+            if e.has(Velocity::id()) && e.has(Name::id()) && e.has(Tag) {
+                complete_.set(complete_.get() + 1);
+            }
+        });
+
+    world.entity().set_bundle(NamedMovable {
+        movable: movable(1),
+        name: Name("one".to_string()),
+        tag: Tag,
+    });
+
+    assert_eq!(complete.get(), 1);
+}
+
+#[test]
+fn bundle_set_assigns_existing_components() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 10, y: 10 })
+        .set(Name("old".to_string()));
+
+    e.set_bundle(NamedMovable {
+        movable: movable(3),
+        name: Name("new".to_string()),
+        tag: Tag,
+    });
+
+    e.get::<(&Position, &Name)>(|(pos, name)| {
+        assert_eq!(pos.x, 3);
+        assert_eq!(name.0, "new");
+    });
+}
+
+#[test]
+fn bundle_set_overrides_inherited_component() {
+    let world = World::new();
+
+    let prefab_drops = Rc::new(Cell::new(0));
+    let instance_drops = Rc::new(Cell::new(0));
+
+    world
+        .component::<Tracked>()
+        .add_trait::<(flecs::OnInstantiate, flecs::Inherit)>();
+
+    let prefab = world.prefab().set(Tracked(prefab_drops.clone(), 1));
+    let e = world.entity().is_a(prefab);
+    assert!(e.has(Tracked::id()));
+    assert!(!e.owns(Tracked::id()));
+
+    // the inherited component gets storage of its own, which must not be dropped before it
+    // is written
+    e.set_bundle(TrackedMovable {
+        movable: movable(1),
+        tracked: Tracked(instance_drops.clone(), 2),
+    });
+
+    assert!(e.owns(Tracked::id()));
+    e.get::<&Tracked>(|tracked| assert_eq!(tracked.1, 2));
+    prefab.get::<&Tracked>(|tracked| assert_eq!(tracked.1, 1));
+    assert_eq!(prefab_drops.get(), 0);
+    assert_eq!(instance_drops.get(), 0);
+
+    e.destruct();
+    assert_eq!(instance_drops.get(), 1);
+    assert_eq!(prefab_drops.get(), 0);
+}
+
+#[test]
+#[should_panic(expected = "more than once")]
+fn bundle_set_duplicate_component() {
+    let world = World::new();
+
+    world.entity().set_bundle(DuplicatePosition {
+        movable: movable(1),
+        position: Position { x: 2, y: 2 },
+    });
+}
+
+#[test]
+fn bundle_set_while_deferred() {
+    let world = World::new();
+
+    let e = world.entity();
+    world.defer(|| {
+        e.set_bundle(movable(5));
+        assert!(!e.has(Position::id()));
+    });
+
+    e.get::<&Position>(|pos| assert_eq!(pos.x, 5));
+    assert!(e.has(Velocity::id()));
+}
+
+#[test]
+fn bundle_remove_from() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set_bundle(NamedMovable {
+            movable: movable(1),
+            name: Name("one".to_string()),
+            tag: Tag,
+        })
+        .set(Mass { value: 1 });
+
+    movable(0).remove_from(e);
+
+    assert!(!e.has(Position::id()));
+    assert!(!e.has(Velocity::id()));
+    assert!(e.has(Name::id()));
+    assert!(e.has(Mass::id()));
+
+    NamedMovable {
+        movable: movable(0),
+        name: Name(String::new()),
+        tag: Tag,
+    }
+    .remove_from(e);
+
+    assert!(!e.has(Name::id()));
+    assert!(!e.has(Tag));
+    assert!(e.has(Mass::id()));
+}
+
+#[test]
+fn bundle_bulk_set_bundle_iter() {
+    let world = World::new();
+
+    let entities = world
+        .entity_bulk(5)
+        .add::<Mass>()
+        .set_bundle_iter((0..5).map(|i| NamedMovable {
+            movable: movable(i),
+            name: Name(format!("e{i}")),
+            tag: Tag,
+        }))
+        .build();
+
+    assert_eq!(entities.len(), 5);
+    for (i, e) in entities.iter().enumerate() {
+        let e = world.entity_from_id(*e);
+        assert!(e.has(Mass::id()));
+        assert!(e.has(Tag));
+        e.get::<(&Position, &Name)>(|(pos, name)| {
+            assert_eq!(pos.x, i as i32);
+            assert_eq!(name.0, format!("e{i}"));
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "bundle count must be equal to count of entities")]
+fn bundle_bulk_set_bundle_iter_mismatched_length() {
+    let world = World::new();
+    world
+        .entity_bulk(3)
+        .set_bundle_iter((0..2).map(movable))
+        .build();
+}
@@ -0,0 +1,239 @@
+#![allow(dead_code)]
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use flecs_ecs::core::*;
+
+use crate::common_test::*;
+
+/// Contexts must be `Send + Sync`, so the drops are counted atomically.
+#[derive(Default, Clone)]
+struct Drops(Arc<AtomicU32>);
+
+impl Drops {
+    fn get(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+struct DropCounter(Drops);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn system_context_in_run() {
+    let world = World::new();
+    world.entity().set(Position { x: 10, y: 20 });
+
+    let sum = Rc::new(Cell::new(0));
+    let sum_c = sum.clone();
+
+    world
+        .system::<&Position>()
+        .set_context(5i32)
+        .run(move |mut it| {
+            while it.next() {
+                let ctx = it.context::<i32>().unwrap();
+                sum_c.set(sum_c.get() + *ctx * it.count() as i32);
+            }
+        });
+
+    world.progress();
+    assert_eq!(sum.get(), 5);
+}
+
+#[test]
+fn system_context_type_mismatch() {
+    let world = World::new();
+
+    let checked = Rc::new(Cell::new(false));
+    let checked_c = checked.clone();
+
+    let sys = world.system::<()>().set_context(5i32).run(move |mut it| {
+        while it.next() {}
+        assert!(it.context::<u32>().is_none());
+        assert!(it.context::<i32>().is_some());
+        checked_c.set(true);
+    });
+
+    assert!(sys.context::<String>().is_none());
+    assert_eq!(sys.context::<i32>(), Some(&5));
+
+    sys.run();
+    assert!(checked.get());
+}
+
+#[test]
+fn system_context_dropped_with_system() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    let sys = world
+        .system::<()>()
+        .set_context(DropCounter(drops.clone()))
+        .run(|mut it| while it.next() {});
+
+    assert_eq!(drops.get(), 0);
+    sys.destruct();
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn system_context_replaced_is_dropped() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    let invoked = Rc::new(Cell::new(0));
+    let invoked_c = invoked.clone();
+
+    let mut sys = world
+        .system::<()>()
+        .set_context(DropCounter(drops.clone()))
+        .run(move |mut it| {
+            while it.next() {}
+            invoked_c.set(invoked_c.get() + 1);
+        });
+
+    sys.set_context(String::from("replaced"));
+    assert_eq!(drops.get(), 1);
+    assert_eq!(sys.context::<String>().unwrap(), "replaced");
+
+    // the callback survives replacing the context
+    sys.run();
+    assert_eq!(invoked.get(), 1);
+}
+
+#[test]
+fn system_builder_context_set_twice() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    let sys = world
+        .system::<()>()
+        .set_context(DropCounter(drops.clone()))
+        .set_context(7u8)
+        .run(|mut it| while it.next() {});
+
+    assert_eq!(drops.get(), 1);
+    assert_eq!(sys.context::<u8>(), Some(&7));
+}
+
+#[test]
+fn observer_context_in_each_iter() {
+    let world = World::new();
+
+    let value = Rc::new(Cell::new(0));
+    let value_c = value.clone();
+
+    let mut observer = world
+        .observer::<flecs::OnSet, &Position>()
+        .set_context(3i32)
+        .each_iter(move |it, _, p| {
+            value_c.set(p.x * it.context::<i32>().unwrap());
+        });
+
+    world.entity().set(Position { x: 10, y: 20 });
+    assert_eq!(value.get(), 30);
+
+    observer.set_context(4i32);
+    world.entity().set(Position { x: 10, y: 20 });
+    assert_eq!(value.get(), 40);
+}
+
+#[test]
+fn observer_context_dropped_with_observer() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    let observer = world
+        .observer::<flecs::OnSet, &Position>()
+        .set_context(DropCounter(drops.clone()))
+        .each(|_| {});
+
+    observer.entity().destruct();
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn query_context() {
+    let world = World::new();
+    world.entity().set(Position { x: 10, y: 20 });
+
+    let query = world
+        .query::<&Position>()
+        .set_context(String::from("ctx"))
+        .build();
+
+    assert_eq!(query.context::<String>().unwrap(), "ctx");
+    assert!(query.context::<i32>().is_none());
+
+    let mut count = 0;
+    query.run(|mut it| {
+        while it.next() {
+            assert_eq!(it.context::<String>().unwrap(), "ctx");
+            count += it.count();
+        }
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_context_dropped_with_query() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    let query = world
+        .query::<&Position>()
+        .set_context(DropCounter(drops.clone()))
+        .build();
+
+    assert_eq!(drops.get(), 0);
+    drop(query);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn context_none_when_unset() {
+    let world = World::new();
+
+    let sys = world.system::<()>().run(|mut it| {
+        while it.next() {}
+        assert!(it.context::<i32>().is_none());
+    });
+
+    assert!(sys.context::<i32>().is_none());
+    sys.run();
+}
+
+#[test]
+fn context_dropped_with_unbuilt_builder() {
+    let world = World::new();
+    let drops = Drops::default();
+
+    world.system::<()>().set_context(DropCounter(drops.clone()));
+    assert_eq!(drops.get(), 1);
+
+    world
+        .observer::<flecs::OnSet, &Position>()
+        .set_context(DropCounter(drops.clone()));
+    assert_eq!(drops.get(), 2);
+
+    world
+        .query::<&Position>()
+        .set_context(DropCounter(drops.clone()));
+    assert_eq!(drops.get(), 3);
+
+    // a built query owns the context, the builder no longer drops it
+    let mut builder = world.query::<&Position>();
+    let query = builder.set_context(DropCounter(drops.clone())).build();
+    drop(builder);
+    assert_eq!(drops.get(), 3);
+    drop(query);
+    assert_eq!(drops.get(), 4);
+}
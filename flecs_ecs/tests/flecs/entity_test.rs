@@ -2164,7 +2164,7 @@ fn with_self_w_name() {
         });
     });
     let tier2 = world.try_lookup_recursive("Tier2");
-    assert!(tier2.is_some());
+    assert!(tier2.is_ok());
     let tier2 = tier2.unwrap();
     assert!(tier2.has(tier1));
 }
@@ -2207,13 +2207,13 @@ fn with_scope() {
     });
 
     // Ensure entities are created in correct scope
-    assert!(world.try_lookup_recursive("C1").is_none());
-    assert!(world.try_lookup_recursive("C2").is_none());
-    assert!(world.try_lookup_recursive("C3").is_none());
+    assert!(world.try_lookup_recursive("C1").is_err());
+    assert!(world.try_lookup_recursive("C2").is_err());
+    assert!(world.try_lookup_recursive("C3").is_err());
 
-    assert!(parent.try_lookup_recursive("C1").is_some());
-    assert!(parent.try_lookup_recursive("C2").is_some());
-    assert!(parent.try_lookup_recursive("C3").is_some());
+    assert!(parent.try_lookup_recursive("C1").is_ok());
+    assert!(parent.try_lookup_recursive("C2").is_ok());
+    assert!(parent.try_lookup_recursive("C3").is_ok());
 
     assert_eq!(
         world.lookup_recursive("P::C1"),
@@ -2267,9 +2267,9 @@ fn with_scope_nested() {
         assert_eq!(world.lookup_recursive("::P::C"), child);
     });
 
-    assert!(world.try_lookup_recursive("C").is_none());
-    assert!(world.try_lookup_recursive("GC").is_none());
-    assert!(world.try_lookup_recursive("C::GC").is_none());
+    assert!(world.try_lookup_recursive("C").is_err());
+    assert!(world.try_lookup_recursive("GC").is_err());
+    assert!(world.try_lookup_recursive("C::GC").is_err());
 
     let child = world.lookup_recursive("P::C");
     assert!(child.has((flecs::ChildOf::ID, parent)));
@@ -2293,8 +2293,8 @@ fn with_scope_nested_same_name_as_parent() {
         assert_eq!(world.lookup_recursive("::P::C"), child);
     });
 
-    assert!(world.try_lookup_recursive("C").is_none());
-    assert!(world.try_lookup_recursive("C::C").is_none());
+    assert!(world.try_lookup_recursive("C").is_err());
+    assert!(world.try_lookup_recursive("C::C").is_err());
 
     let child = world.lookup_recursive("P::C");
     assert!(child.has((flecs::ChildOf::ID, parent)));
@@ -2312,8 +2312,8 @@ fn no_recursive_lookup() {
     let gc = world.entity_named("GC").child_of(c);
 
     assert_eq!(c.lookup("GC"), gc);
-    assert!(c.try_lookup("C").is_none());
-    assert!(c.try_lookup("P").is_none());
+    assert!(c.try_lookup("C").is_err());
+    assert!(c.try_lookup("P").is_err());
 }
 
 #[test]
@@ -3182,7 +3182,7 @@ fn create_named_twice_deferred() {
 
     assert_eq!(e1.path().unwrap(), "::e");
     assert_eq!(f1.path().unwrap(), "::p::f");
-    assert!(world.try_lookup_recursive("::q::g").is_some());
+    assert!(world.try_lookup_recursive("::q::g").is_ok());
 
     assert_eq!(e1, e2);
     assert_eq!(f1, f2);
@@ -7063,7 +7063,7 @@ fn entity_w_childof_w_name() {
     assert!(e.has((flecs::ChildOf::ID, p)));
     assert_eq!(e.name(), "Foo");
 
-    assert!(world.try_lookup("Foo").is_none());
+    assert!(world.try_lookup("Foo").is_err());
     assert_eq!(world.lookup("Parent::Foo"), e);
 }
 
@@ -7113,7 +7113,7 @@ fn entity_w_parent_w_name() {
     });
     assert_eq!(e.name(), "Foo");
 
-    assert!(world.try_lookup("Foo").is_none());
+    assert!(world.try_lookup("Foo").is_err());
     assert_eq!(world.lookup("Parent::Foo"), e);
 }
 
@@ -7177,7 +7177,7 @@ fn prefab_w_childof_w_name() {
     assert!(e.has((flecs::ChildOf::ID, p)));
     assert_eq!(e.name(), "Foo");
 
-    assert!(world.try_lookup("Foo").is_none());
+    assert!(world.try_lookup("Foo").is_err());
     assert_eq!(world.lookup("Parent::Foo"), e);
 }
 
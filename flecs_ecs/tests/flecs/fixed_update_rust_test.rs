@@ -0,0 +1,186 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+type Log = Rc<RefCell<Vec<FTime>>>;
+
+/// Run callback that logs the delta time of each run.
+fn log_delta_time(log: &Log) -> impl FnMut(TableIter) + 'static {
+    let log = log.clone();
+    move |it| log.borrow_mut().push(it.delta_time())
+}
+
+fn fixed_time(world: &World) -> FixedTime {
+    world.get::<&FixedTime>(|fixed| *fixed)
+}
+
+fn alpha(world: &World) -> FTime {
+    world.get::<&FixedAlpha>(|alpha| alpha.0)
+}
+
+#[test]
+fn fixed_update_runs_per_step() {
+    let world = World::new();
+    world.set_fixed_time_step(0.25);
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    world.progress_time(0.5);
+    assert_eq!(*log.borrow(), [0.25, 0.25]);
+    assert_eq!(fixed_time(&world).steps, 2);
+
+    world.progress_time(0.1);
+    assert_eq!(log.borrow().len(), 2);
+    assert_eq!(fixed_time(&world).steps, 0);
+}
+
+#[test]
+fn fixed_update_accumulates_frames() {
+    let world = World::new();
+    world.set_fixed_time_step(1.0);
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    let mut runs = Vec::new();
+    let mut alphas = Vec::new();
+    for _ in 0..4 {
+        world.progress_time(0.5);
+        runs.push(log.borrow().len());
+        alphas.push(alpha(&world));
+    }
+    assert_eq!(runs, [0, 1, 1, 2]);
+    assert_eq!(alphas, [0.5, 0.0, 0.5, 0.0]);
+}
+
+#[test]
+fn fixed_update_max_steps() {
+    let world = World::new();
+    world.set_fixed_time_step(0.25);
+    world.set_fixed_max_steps(3);
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    world.progress_time(2.125);
+    assert_eq!(log.borrow().len(), 3);
+    // the steps that didn't run are dropped, the rest of a step is kept
+    let fixed = fixed_time(&world);
+    assert_eq!((fixed.steps, fixed.accumulator), (3, 0.125));
+    assert!((alpha(&world) - 0.5).abs() < FTime::EPSILON);
+
+    world.progress_time(0.125);
+    assert_eq!(log.borrow().len(), 4);
+}
+
+#[test]
+fn fixed_update_runs_before_pipeline() {
+    let world = World::new();
+    world.set_fixed_time_step(0.5);
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let update_log = log.clone();
+    world
+        .system::<()>()
+        .kind(flecs::pipeline::OnUpdate)
+        .run(move |it| update_log.borrow_mut().push(("update", it.delta_time())));
+    let fixed_log = log.clone();
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(move |it| fixed_log.borrow_mut().push(("fixed", it.delta_time())));
+
+    world.progress_time(1.0);
+    assert_eq!(
+        *log.borrow(),
+        [("fixed", 0.5), ("fixed", 0.5), ("update", 1.0)]
+    );
+}
+
+#[test]
+fn fixed_update_not_run_by_pipeline() {
+    let world = World::new();
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    // fixed updates are not enabled
+    world.progress_time(1.0);
+    assert!(log.borrow().is_empty());
+    assert!(!world.has(FixedAlpha::id()));
+
+    world.set_fixed_time_step(1.0);
+    world.progress_time(1.0);
+    assert_eq!(*log.borrow(), [1.0]);
+
+    world.remove(FixedTime::id());
+    world.progress_time(1.0);
+    assert_eq!(log.borrow().len(), 1);
+}
+
+#[test]
+fn fixed_update_disabled_system() {
+    let world = World::new();
+    world.set_fixed_time_step(1.0);
+    let log = Log::default();
+
+    let system = world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    system.disable_self();
+    world.progress_time(1.0);
+    assert!(log.borrow().is_empty());
+
+    system.enable_self();
+    world.progress_time(1.0);
+    assert_eq!(log.borrow().len(), 1);
+}
+
+#[test]
+fn fixed_update_time_scale() {
+    let world = World::new();
+    world.set_fixed_time_step(0.5);
+    world.set_time_scale(0.5);
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    // the fixed steps consume scaled time, the step itself is not scaled
+    world.progress_time(2.0);
+    assert_eq!(*log.borrow(), [0.5, 0.5]);
+}
+
+#[test]
+fn fixed_update_app() {
+    let world = World::new();
+    world.set_fixed_time_step(0.5);
+    let log = Log::default();
+
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    world.app().set_frames(2).set_delta_time(1.0).run();
+    assert_eq!(log.borrow().len(), 4);
+}
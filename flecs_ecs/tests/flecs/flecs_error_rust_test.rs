@@ -0,0 +1,171 @@
+#![allow(dead_code)]
+use flecs_ecs::prelude::*;
+
+#[derive(Debug, Default, Component)]
+#[flecs(meta)]
+struct ErrPosition {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+struct ErrVelocity {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn flecs_error_lookup_not_found() {
+    let world = World::new();
+    let parent = world.entity_named("parent");
+    world.entity_named("child").child_of(parent);
+
+    assert_eq!(
+        world.try_lookup("missing").unwrap_err(),
+        FlecsError::EntityNotFound {
+            name: "missing".to_string()
+        }
+    );
+    assert!(world.try_lookup_recursive("parent::child").is_ok());
+
+    let err = parent.try_lookup("other").unwrap_err();
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert_eq!(err.to_string(), "entity `other` not found");
+}
+
+#[test]
+fn flecs_error_get_alive() {
+    let world = World::new();
+    let e = world.entity();
+    let id = e.id();
+    e.destruct();
+
+    assert_eq!(
+        world.try_get_alive(id).unwrap_err(),
+        FlecsError::EntityNotAlive { entity: id }
+    );
+}
+
+#[test]
+fn flecs_error_first_entity() {
+    let world = World::new();
+    let q = world.new_query::<&ErrPosition>();
+
+    let err = q.try_first_entity().unwrap_err();
+    assert_eq!(err, FlecsError::QueryEmpty);
+    assert_eq!(err.code(), FlecsErrorCode::InvalidOperation);
+
+    let e = world.entity().set(ErrPosition::default());
+    assert_eq!(q.try_first_entity(), Ok(e));
+}
+
+#[test]
+fn flecs_error_query_build_message() {
+    let world = World::new();
+
+    let err = world
+        .query::<()>()
+        .expr("DoesNotExist")
+        .try_build()
+        .unwrap_err();
+
+    assert!(matches!(err, FlecsError::Build { kind: "query", .. }));
+    assert!(
+        err.message().contains("DoesNotExist"),
+        "unexpected message: {}",
+        err.message()
+    );
+    // color codes are stripped from the captured message
+    assert!(!err.message().contains('\u{1b}'));
+}
+
+#[test]
+fn flecs_error_capture_is_scoped() {
+    let world = World::new();
+
+    let first = world.query::<()>().expr("First!!").try_build().unwrap_err();
+    let second = world
+        .query::<()>()
+        .expr("Second!!")
+        .try_build()
+        .unwrap_err();
+
+    // errors of an earlier operation don't leak into the next one
+    assert!(!second.message().contains("First"));
+    assert_ne!(first.message(), second.message());
+
+    assert!(world.query::<&ErrPosition>().try_build().is_ok());
+}
+
+#[test]
+fn flecs_error_script() {
+    let world = World::new();
+
+    world.try_run_code("ok", "e {}").unwrap();
+    assert!(world.try_lookup("e").is_ok());
+
+    let err = world.try_run_code("broken", "e {").unwrap_err();
+    let FlecsError::Script { name, message } = &err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(name, "broken");
+    assert!(!message.is_empty());
+    assert_eq!(err.code(), FlecsErrorCode::OperationFailed);
+
+    let err = world.try_run_file("does/not/exist.flecs").unwrap_err();
+    assert!(matches!(err, FlecsError::Script { .. }));
+}
+
+#[test]
+fn flecs_error_json() {
+    let world = World::new();
+    let e = world.entity();
+
+    e.try_set_json(ErrPosition::id(), r#"{"x": 1, "y": 2}"#, None)
+        .unwrap();
+    e.get::<&ErrPosition>(|p| assert_eq!((p.x, p.y), (1.0, 2.0)));
+
+    let err = e
+        .try_set_json(ErrPosition::id(), r#"{"x": "abc"}"#, None)
+        .unwrap_err();
+    let FlecsError::Json { component, message } = &err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert!(component.as_deref().unwrap().contains("ErrPosition"));
+    assert!(
+        message.contains("expected number"),
+        "unexpected message: {message}"
+    );
+
+    let tag = world.entity();
+    let err = e.try_set_json(tag, "{}", None).unwrap_err();
+    assert_eq!(err.message(), "id is not a type");
+
+    let mut value = ErrPosition::default();
+    assert!(
+        world
+            .try_from_json::<ErrPosition>(&mut value, "{", None)
+            .is_err()
+    );
+    assert!(e.try_from_json("{").is_err());
+    assert!(world.try_from_json_world("{", None).is_err());
+}
+
+#[test]
+fn flecs_error_component_name_in_use() {
+    let world = World::new();
+
+    world.component_named::<ErrPosition>("Shared");
+    world.try_component_named::<ErrPosition>("Shared").unwrap();
+
+    let err = world
+        .try_component_named::<ErrVelocity>("Shared")
+        .unwrap_err();
+    let FlecsError::Component { name, code, .. } = &err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(name, "Shared");
+    assert_eq!(*code, FlecsErrorCode::NameInUse);
+
+    assert!(world.try_component::<ErrVelocity>().is_ok());
+}
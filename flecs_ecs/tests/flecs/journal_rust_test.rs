@@ -0,0 +1,151 @@
+#![allow(dead_code)]
+#![allow(clippy::float_cmp)]
+use crate::common_test::*;
+
+fn journal_world() -> World {
+    let world = World::new();
+    world.component::<Point>();
+    world.component::<Position>();
+    world.component::<Likes>();
+    world.component::<Tag>();
+    world
+}
+
+fn ops(journal: &Journal) -> Vec<JournalOp> {
+    journal.entries().iter().map(|entry| entry.op).collect()
+}
+
+#[test]
+fn journal_records_add_set_remove() {
+    let world = journal_world();
+
+    let recorder = world.record_journal();
+    let e = world.entity().add(Tag).set(Point::new(1.0, 2.0));
+    e.remove(Tag);
+    let journal = recorder.finish();
+
+    assert_eq!(
+        ops(&journal),
+        vec![
+            JournalOp::New,
+            JournalOp::Add,
+            JournalOp::Add,
+            JournalOp::Set,
+            JournalOp::Remove
+        ]
+    );
+    assert!(
+        journal
+            .entries()
+            .iter()
+            .all(|entry| entry.entity == *e.id())
+    );
+
+    let set = &journal.entries()[3];
+    assert_eq!(set.value.as_deref(), Some("{\"x\":1, \"y\":2}"));
+    assert_eq!(
+        set.id,
+        Some(JournalId {
+            first: JournalRef::Path(world.component::<Point>().path().unwrap()),
+            second: None,
+        })
+    );
+}
+
+#[test]
+fn journal_records_clear_and_delete() {
+    let world = journal_world();
+
+    let a = world.entity().add(Tag).set(Position { x: 1, y: 2 });
+    let b = world.entity().add(Tag);
+
+    let recorder = world.record_journal();
+    a.clear();
+    b.destruct();
+    let journal = recorder.finish();
+
+    assert_eq!(
+        ops(&journal),
+        vec![
+            JournalOp::New,
+            JournalOp::Clear,
+            JournalOp::New,
+            JournalOp::Delete
+        ]
+    );
+    assert_eq!(journal.entries()[1].entity, *a.id());
+    assert_eq!(journal.entries()[3].entity, *b.id());
+}
+
+#[test]
+fn journal_ignores_components_and_observers() {
+    let world = journal_world();
+
+    let recorder = world.record_journal();
+    world.component::<Velocity>();
+    world.observer::<flecs::OnSet, &Position>().each(|_| {});
+    world.new_query::<&Position>();
+    let journal = recorder.finish();
+
+    assert!(journal.entries().is_empty());
+}
+
+#[test]
+fn journal_replays_into_new_world() {
+    let world = journal_world();
+
+    let recorder = world.record_journal();
+    let alice = world.entity_named("alice").set(Point::new(3.0, 4.0));
+    let bob = world.entity_named("bob").add((Likes, alice));
+    world.entity().child_of(bob).add(Tag);
+    world.entity().add(Tag).destruct();
+    let journal = recorder.finish();
+
+    let replay_world = journal_world();
+    journal.replay(&replay_world).unwrap();
+
+    let alice = replay_world.lookup("alice");
+    alice.get::<&Point>(|p| assert_eq!(p.y, 4.0));
+
+    let bob = replay_world.lookup("bob");
+    assert!(bob.has((Likes, alice)));
+
+    let mut children = 0;
+    bob.each_child(|child| {
+        assert!(child.has(Tag));
+        children += 1;
+    });
+    assert_eq!(children, 1);
+
+    assert_eq!(replay_world.count(Tag), 1);
+}
+
+#[test]
+fn journal_replay_reports_missing_component() {
+    let world = journal_world();
+
+    let recorder = world.record_journal();
+    world.entity().add(Tag);
+    let journal = recorder.finish();
+
+    let replay_world = World::new();
+    let err = journal.replay(&replay_world).unwrap_err();
+    assert!(matches!(err, FlecsError::EntityNotFound { .. }));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn journal_serde_roundtrip() {
+    let world = journal_world();
+
+    let recorder = world.record_journal();
+    let alice = world.entity_named("alice").set(Point::new(3.0, 4.0));
+    world.entity().add((Likes, alice)).destruct();
+    let journal = recorder.finish();
+
+    let json = serde_json::to_string(&journal).unwrap();
+    let replayed: Journal = serde_json::from_str(&json).unwrap();
+    assert_eq!(replayed, journal);
+
+    assert!(serde_json::from_str::<Journal>(r#"[{"op": "explode", "entity": 1}]"#).is_err());
+}
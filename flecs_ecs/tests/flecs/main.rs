@@ -8,14 +8,17 @@ pub mod common_test;
 
 mod abi_test;
 mod addons_misc_test;
+mod alerts_rust_test;
 mod aliasing_test;
 #[cfg(feature = "flecs_app")]
 mod app_test;
+mod bundle_rust_test;
 mod clone_default_impl_test;
 mod component_index_growth_test;
 mod component_lifecycle_test;
 mod component_test;
 mod component_traits_test;
+mod context_rust_test;
 mod derive_attr_component_traits;
 mod entity_bulk_rust_test;
 mod entity_rust_test;
@@ -25,11 +28,16 @@ mod enum_test;
 mod eq_test;
 mod event_test;
 mod field_safety_rust_test;
+#[cfg(feature = "flecs_pipeline")]
+mod fixed_update_rust_test;
 mod flecs_docs_test;
+mod flecs_error_rust_test;
 mod flecs_ids;
 mod implicit_components_test;
 mod is_ref_test;
 mod iterable_test;
+#[cfg(feature = "flecs_journal")]
+mod journal_rust_test;
 mod meta_macro_test;
 mod meta_test;
 mod meta_trait_test;
@@ -40,20 +48,47 @@ mod observer_test;
 mod ordered_children_test;
 mod pairs_test;
 mod paths_test;
+#[cfg(feature = "flecs_pipeline")]
+mod pause_rust_test;
+#[cfg(feature = "flecs_pipeline")]
+mod pipeline_schedule_rust_test;
 mod pretty_function_test;
 mod query_builder_test;
+mod query_data_rust_test;
+mod query_iter_rust_test;
 mod query_rust_test;
 mod query_test;
 mod refs_test;
+#[cfg(feature = "flecs_rest")]
+mod rest_rust_test;
+#[cfg(feature = "flecs_pipeline")]
+mod run_condition_rust_test;
+#[cfg(feature = "flecs_rust_os_api")]
+mod rust_os_api_rust_test;
 #[cfg(feature = "flecs_query_rust_traits")]
 mod rust_trait_test;
 #[cfg(feature = "flecs_safety_locks")]
 mod safety;
+#[cfg(feature = "flecs_script")]
+mod script_expr_rust_test;
+#[cfg(feature = "flecs_script")]
+mod script_function_rust_test;
+#[cfg(feature = "flecs_script")]
+mod script_vars_rust_test;
+#[cfg(all(feature = "flecs_script", feature = "flecs_system"))]
+mod script_watcher_rust_test;
+#[cfg(feature = "serde")]
+mod serde_rust_test;
 mod singleton_test;
+mod snapshot_rust_test;
 mod soundness_test;
+#[cfg(feature = "flecs_stats")]
+mod stats_rust_test;
 #[cfg(feature = "flecs_safety_locks")]
 mod sys_bindings_test;
 mod system_builder_test;
+#[cfg(feature = "flecs_pipeline")]
+mod system_order_rust_test;
 mod system_test;
 mod table_test;
 mod union_test;
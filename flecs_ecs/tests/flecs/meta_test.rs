@@ -1549,11 +1549,11 @@ fn meta_ser_deser_alias() {
     let parent = world.entity();
     world.entity().child_of(parent).set_alias("child");
     let str = world.to_json_world(None);
-    assert!(world.try_lookup("child").is_some());
+    assert!(world.try_lookup("child").is_ok());
 
     let world2 = World::new();
     world2.from_json_world(str.as_str(), None);
-    assert!(world2.try_lookup("child").is_some());
+    assert!(world2.try_lookup("child").is_ok());
 }
 
 // ── type_w_std_vector ──
@@ -417,7 +417,7 @@ fn module_register_w_root_name() {
     assert_eq!(m.id(), m_lookup.id());
 
     let ns_lookup = world.try_lookup("::ns::NamedModule");
-    assert!(ns_lookup.is_none());
+    assert!(ns_lookup.is_err());
 }
 
 #[test]
@@ -514,7 +514,7 @@ fn module_lookup_module_after_reparent() {
     assert_eq!(m.path(), Some("::p::NestedModule".to_string()));
     assert_eq!(world.lookup("::p::NestedModule").id(), m.id());
 
-    assert_eq!(world.try_lookup("::ns::NestedModule"), None);
+    assert!(world.try_lookup("::ns::NestedModule").is_err());
 
     let e = world.entity_named("::ns::NestedModule");
     assert_ne!(e.id(), m.id());
@@ -654,13 +654,13 @@ fn module_rename_namespace_shorter() {
     let m = ecs.import::<ns_parent::ShorterParent>();
     assert!(m.has(flecs::Module::ID));
     assert_eq!(m.path(), Some("::ns::ShorterParent".to_string()));
-    assert!(ecs.try_lookup("::ns_parent").is_none());
-    assert!(ecs.try_lookup("::ns_parent::ShorterParent").is_none());
+    assert!(ecs.try_lookup("::ns_parent").is_err());
+    assert!(ecs.try_lookup("::ns_parent::ShorterParent").is_err());
     assert!(
         ecs.try_lookup("::ns_parent::ShorterParent::NsType")
-            .is_none()
+            .is_err()
     );
-    assert!(ecs.try_lookup("::ns::ShorterParent::NsType").is_some());
+    assert!(ecs.try_lookup("::ns::ShorterParent::NsType").is_ok());
 
     let ns = ecs.lookup("::ns");
     assert_ne!(ns.id(), 0);
@@ -677,15 +677,12 @@ fn module_rename_namespace_longer() {
         m.path(),
         Some("::ns_parent_namespace::LongerParent".to_string())
     );
-    assert!(ecs.try_lookup("::ns_parent").is_none());
-    assert!(ecs.try_lookup("::ns_parent::LongerParent").is_none());
-    assert!(
-        ecs.try_lookup("::ns_parent::LongerParent::NsType")
-            .is_none()
-    );
+    assert!(ecs.try_lookup("::ns_parent").is_err());
+    assert!(ecs.try_lookup("::ns_parent::LongerParent").is_err());
+    assert!(ecs.try_lookup("::ns_parent::LongerParent::NsType").is_err());
     assert!(
         ecs.try_lookup("::ns_parent_namespace::LongerParent::NsType")
-            .is_some()
+            .is_ok()
     );
 
     let ns = ecs.lookup("::ns_parent_namespace");
@@ -701,14 +698,14 @@ fn module_rename_namespace_nested() {
     assert!(m.has(flecs::Module::ID));
 
     assert_eq!(m.path(), Some("::ns::child::Nested".to_string()));
-    assert!(ecs.try_lookup("::ns::child::Nested::NsType").is_some());
+    assert!(ecs.try_lookup("::ns::child::Nested::NsType").is_ok());
     assert!(
         ecs.try_lookup("::ns_parent::ns_child::Nested::NsType")
-            .is_none()
+            .is_err()
     );
-    assert!(ecs.try_lookup("::ns_parent::ns_child::Nested").is_none());
-    assert!(ecs.try_lookup("::ns_parent::ns_child").is_none());
-    assert!(ecs.try_lookup("::ns_parent").is_none());
+    assert!(ecs.try_lookup("::ns_parent::ns_child::Nested").is_err());
+    assert!(ecs.try_lookup("::ns_parent::ns_child").is_err());
+    assert!(ecs.try_lookup("::ns_parent").is_err());
 
     let ns = ecs.lookup("::ns");
     assert_ne!(ns.id(), 0);
@@ -779,7 +776,7 @@ fn module_delete_module_w_implicit_component_and_system() {
 
     let m = world.import::<SystemAndImplicitComponent>();
 
-    assert!(m.try_lookup("Velocity").is_none());
+    assert!(m.try_lookup("Velocity").is_err());
     assert_ne!(world.lookup("Velocity").id(), 0);
     assert_ne!(m.lookup("VelocitySys").id(), 0);
 
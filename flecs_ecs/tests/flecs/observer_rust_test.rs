@@ -61,7 +61,7 @@ fn observer_context_on_non_observer_entity_panics() {
 
     let entity = world.entity();
     let observer = world.observer_from(entity);
-    let _ = observer.context();
+    let _ = observer.context::<i32>();
 }
 
 #[test]
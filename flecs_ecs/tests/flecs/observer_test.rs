@@ -1541,11 +1541,10 @@ fn lookup_and_update_ctx() {
     assert!(*e.id() != 0);
 
     let mut o = world.observer_from(world.entity_named("Test"));
-    assert!(o.context().is_null());
+    assert!(o.context::<i32>().is_none());
 
-    let mut my_ctx: i32 = 42;
-    o.set_context(&mut my_ctx as *mut i32 as *mut core::ffi::c_void);
-    assert!(o.context() == &mut my_ctx as *mut i32 as *mut core::ffi::c_void);
+    o.set_context(42i32);
+    assert_eq!(o.context::<i32>(), Some(&42));
 }
 
 #[test]
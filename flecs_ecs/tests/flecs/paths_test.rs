@@ -25,7 +25,7 @@ fn paths_path_depth_1() {
     assert_eq!(e.name(), "bar");
     assert_eq!(e.path().unwrap(), "::foo::bar");
 
-    assert!(world.try_lookup("bar").is_none());
+    assert!(world.try_lookup("bar").is_err());
 
     let e_world = world.lookup("foo::bar");
     assert_eq!(e.id(), e_world.id());
@@ -42,7 +42,7 @@ fn paths_path_depth_2() {
     assert_eq!(e.name(), "hello");
     assert_eq!(e.path().unwrap(), "::foo::bar::hello");
 
-    assert!(world.try_lookup("hello").is_none());
+    assert!(world.try_lookup("hello").is_err());
 
     let e_world = world.lookup("foo::bar::hello");
     assert_eq!(e.id(), e_world.id());
@@ -119,7 +119,7 @@ fn paths_entity_lookup_from_0() {
     assert_eq!(world.lookup("foo").id(), foo.id());
 
     let dummy = world.entity_from_id(0u64);
-    dummy.try_lookup("foo"); // triggers ecs_assert id != 0 -> abort -> panic
+    let _ = dummy.try_lookup("foo"); // triggers ecs_assert id != 0 -> abort -> panic
 }
 
 #[test]
@@ -133,7 +133,7 @@ fn paths_entity_lookup_from_0_w_world() {
     assert_eq!(world.lookup("foo").id(), foo.id());
 
     let dummy = world.entity_from_id(0u64);
-    dummy.try_lookup("foo"); // triggers ecs_assert id != 0 -> abort -> panic
+    let _ = dummy.try_lookup("foo"); // triggers ecs_assert id != 0 -> abort -> panic
 }
 
 #[test]
@@ -187,7 +187,7 @@ fn paths_alias_scoped_component_w_name() {
 
     assert_eq!(e.id(), a.id());
     assert_eq!(e.id(), f.id());
-    assert!(c.is_none());
+    assert!(c.is_err());
 }
 
 #[test]
@@ -238,20 +238,20 @@ fn paths_alias_entity_empty() {
     let child = world.entity_named("child").child_of(parent);
 
     // "child" without qualifier - can't be looked up from root since it's a child
-    assert!(world.try_lookup("child").is_none());
+    assert!(world.try_lookup("child").is_err());
 
     // set alias with empty string = use entity's own short name
     world.set_alias_entity(child, "");
 
-    assert!(world.try_lookup("child").is_some());
+    assert!(world.try_lookup("child").is_ok());
 
     // override with a different alias
     world.set_alias_entity(child, "FooAlias");
 
     // now "child" alias is gone (replaced by "FooAlias")
-    assert!(world.try_lookup("child").is_none());
+    assert!(world.try_lookup("child").is_err());
 
-    assert!(world.try_lookup("FooAlias").is_some());
+    assert!(world.try_lookup("FooAlias").is_ok());
 }
 
 #[test]
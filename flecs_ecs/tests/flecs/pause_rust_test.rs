@@ -0,0 +1,255 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+type Log = Rc<RefCell<Vec<FTime>>>;
+
+/// Run callback that logs the delta time of each run.
+fn log_delta_time(log: &Log) -> impl FnMut(TableIter) + 'static {
+    let log = log.clone();
+    move |it| log.borrow_mut().push(it.delta_time())
+}
+
+fn approx_eq(a: FTime, b: FTime) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn pause_stops_time() {
+    let world = World::new();
+    let log = Log::default();
+    world.system::<()>().run(log_delta_time(&log));
+
+    world.progress_time(1.0);
+    world.pause();
+    assert!(world.is_paused());
+    world.progress_time(1.0);
+    assert!(approx_eq(world.info().world_time_total as FTime, 1.0));
+
+    world.resume();
+    assert!(!world.is_paused());
+    world.progress_time(1.0);
+    assert_eq!(*log.borrow(), [1.0, 0.0, 1.0]);
+    assert!(approx_eq(world.info().world_time_total as FTime, 2.0));
+}
+
+#[test]
+fn pause_unscaled_time() {
+    let world = World::new();
+    let scaled = Log::default();
+    let unscaled = Log::default();
+    world.system::<()>().run(log_delta_time(&scaled));
+    let system = world
+        .system::<()>()
+        .unscaled_time()
+        .run(log_delta_time(&unscaled));
+    assert!(system.has(UnscaledTime));
+
+    world.set_time_scale(0.5);
+    world.progress_time(1.0);
+    world.pause();
+    world.progress_time(1.0);
+
+    assert_eq!(*scaled.borrow(), [0.5, 0.0]);
+    assert_eq!(*unscaled.borrow(), [1.0, 1.0]);
+}
+
+#[test]
+fn pause_unscaled_time_each() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+    let log = Log::default();
+
+    let each_log = log.clone();
+    world
+        .system::<&Position>()
+        .unscaled_time()
+        .run_if(World::is_paused)
+        .each_iter(move |it, _, _| each_log.borrow_mut().push(it.delta_time()));
+
+    world.progress_time(1.0);
+    world.pause();
+    world.progress_time(1.0);
+    assert_eq!(*log.borrow(), [1.0]);
+}
+
+#[test]
+fn pause_time_scale_while_paused() {
+    let world = World::new();
+    let log = Log::default();
+    world.system::<()>().run(log_delta_time(&log));
+
+    world.pause();
+    world.set_time_scale(2.0);
+    assert!(approx_eq(world.get_time_scale(), 2.0));
+    world.progress_time(1.0);
+
+    world.resume();
+    world.progress_time(1.0);
+    assert_eq!(*log.borrow(), [0.0, 2.0]);
+}
+
+#[test]
+fn pause_from_system() {
+    let world = World::new();
+    let log = Log::default();
+    world.system::<()>().run(log_delta_time(&log));
+    world
+        .system::<()>()
+        .unscaled_time()
+        .run(|it| it.world().pause());
+
+    // takes effect at the end of the frame
+    world.progress_time(1.0);
+    assert!(world.is_paused());
+    world.progress_time(1.0);
+    assert_eq!(*log.borrow(), [1.0, 0.0]);
+}
+
+#[test]
+fn pause_stops_fixed_update() {
+    let world = World::new();
+    world.set_fixed_time_step(0.5);
+    let log = Log::default();
+    world
+        .system::<()>()
+        .kind(FixedUpdate)
+        .run(log_delta_time(&log));
+
+    world.pause();
+    world.progress_time(1.0);
+    assert!(log.borrow().is_empty());
+
+    world.resume();
+    world.progress_time(1.0);
+    assert_eq!(log.borrow().len(), 2);
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn pause_stops_timers() {
+    let world = World::new();
+    let gameplay = Log::default();
+    let menu = Log::default();
+    world
+        .system::<()>()
+        .set_interval(1.0)
+        .run(log_delta_time(&gameplay));
+    world
+        .system::<()>()
+        .unscaled_time()
+        .set_interval(1.0)
+        .run(log_delta_time(&menu));
+
+    world.progress_time(0.5);
+    world.pause();
+    world.progress_time(0.5);
+    world.progress_time(0.5);
+    assert!(gameplay.borrow().is_empty());
+    assert_eq!(menu.borrow().len(), 1);
+
+    // the timer continues where it left off
+    world.resume();
+    world.progress_time(0.5);
+    assert_eq!(gameplay.borrow().len(), 1);
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn timer_elapsed_remaining() {
+    let world = World::new();
+    let timer = world.timer().set_interval(1.0);
+
+    world.progress_time(0.25);
+    assert!(approx_eq(timer.elapsed(), 0.25));
+    assert!(approx_eq(timer.remaining(), 0.75));
+
+    world.progress_time(1.0);
+    assert!(approx_eq(timer.elapsed(), 0.25));
+
+    world.pause();
+    world.progress_time(0.5);
+    assert!(approx_eq(timer.remaining(), 0.75));
+    world.resume();
+
+    // an entity without a timer
+    assert!(approx_eq(world.timer().remaining(), 0.0));
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn timer_timeout_remaining() {
+    let world = World::new();
+    let timer = world.timer().set_timeout(1.0);
+
+    world.progress_time(0.5);
+    assert!(approx_eq(timer.remaining(), 0.5));
+
+    world.progress_time(0.5);
+    assert!(approx_eq(timer.remaining(), 0.0));
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn timer_reset() {
+    let world = World::new();
+    let timer = world.timer().set_interval(1.0);
+
+    world.progress_time(0.75);
+    timer.reset();
+    assert!(approx_eq(timer.elapsed(), 0.0));
+
+    world.progress_time(0.75);
+    assert!(approx_eq(timer.elapsed(), 0.75));
+
+    // a stopped timer stays stopped
+    timer.stop();
+    timer.reset();
+    world.progress_time(0.5);
+    assert!(approx_eq(timer.elapsed(), 0.0));
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn pause_stops_timers_created_while_paused() {
+    let world = World::new();
+    world.pause();
+
+    let gameplay = Log::default();
+    world
+        .system::<()>()
+        .set_interval(1.0)
+        .run(log_delta_time(&gameplay));
+    let timer = world.timer().set_timeout(0.5);
+    assert!(!timer.get::<&flecs::timer::Timer>(|timer| timer.active));
+
+    world.progress_time(1.0);
+    world.progress_time(1.0);
+    assert!(gameplay.borrow().is_empty());
+    assert!(approx_eq(timer.remaining(), 0.5));
+
+    world.resume();
+    world.progress_time(1.0);
+    assert_eq!(gameplay.borrow().len(), 1);
+    assert!(approx_eq(timer.remaining(), 0.0));
+}
+
+#[cfg(feature = "flecs_timer")]
+#[test]
+fn pause_keeps_unscaled_timers_created_while_paused() {
+    let world = World::new();
+    world.pause();
+
+    let menu = Log::default();
+    world
+        .system::<()>()
+        .unscaled_time()
+        .set_interval(1.0)
+        .run(log_delta_time(&menu));
+
+    world.progress_time(0.5);
+    world.progress_time(0.5);
+    assert_eq!(menu.borrow().len(), 1);
+}
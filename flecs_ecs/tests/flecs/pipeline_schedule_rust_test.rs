@@ -0,0 +1,264 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[derive(Component)]
+struct Tag;
+
+#[test]
+fn pipeline_schedule_systems_in_order() {
+    let world = World::new();
+    world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let post = world
+        .system_named::<&Position>("Print")
+        .kind(flecs::pipeline::PostUpdate::ID)
+        .each(|_| {});
+    let update = world
+        .system_named::<(&mut Position, &Velocity)>("Move")
+        .each(|_| {});
+
+    let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    assert_eq!(schedule.pipeline(), world.get_pipeline());
+    assert_eq!(schedule.stages().len(), 1);
+    assert_eq!(schedule.sync_point_count(), 0);
+    assert_eq!(schedule.stages()[0].sync_reason(), None);
+
+    let systems: Vec<_> = schedule.systems().collect();
+    assert_eq!(systems.len(), 2);
+    assert_eq!(systems[0].entity(), update.id());
+    assert_eq!(systems[1].entity(), post.id());
+    assert_eq!(
+        systems[0].phase(),
+        Some(flecs::pipeline::OnUpdate::ID.into())
+    );
+    assert_eq!(
+        systems[1].phase(),
+        Some(flecs::pipeline::PostUpdate::ID.into())
+    );
+
+    let position: Id = world.component_id::<Position>().into();
+    let velocity: Id = world.component_id::<Velocity>().into();
+    assert_eq!(systems[0].reads(), [position, velocity]);
+    assert_eq!(systems[0].writes(), [position]);
+    assert_eq!(systems[1].reads(), [position]);
+    assert!(systems[1].writes().is_empty());
+}
+
+#[test]
+fn pipeline_schedule_staged_sync_point() {
+    let world = World::new();
+    world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(Velocity { x: 1, y: 1 });
+
+    // writes Velocity through commands
+    world
+        .system_named::<&Position>("Spawn")
+        .write(Velocity::id())
+        .each(|_| {});
+    // reads Velocity from the main storage, so the commands must be merged first
+    world
+        .system_named::<&Velocity>("Read")
+        .kind(flecs::pipeline::PostUpdate::ID)
+        .each(|_| {});
+
+    let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    assert_eq!(schedule.stages().len(), 2);
+    assert_eq!(schedule.sync_point_count(), 1);
+
+    let velocity: Id = world.component_id::<Velocity>().into();
+    assert_eq!(schedule.stages()[0].systems()[0].writes(), [velocity]);
+    assert_eq!(
+        schedule.stages()[1].sync_reason(),
+        Some(&SyncReason::Staged(vec![velocity]))
+    );
+}
+
+#[test]
+fn pipeline_schedule_mode_sync_points() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+
+    world.system_named::<&Position>("Single").each(|_| {});
+    world
+        .system_named::<&Position>("Parallel")
+        .kind(flecs::pipeline::PostUpdate::ID)
+        .par_each(|_| {});
+    world
+        .system_named::<&Position>("SingleAgain")
+        .kind(flecs::pipeline::PreStore::ID)
+        .each(|_| {});
+    world
+        .system_named::<&Position>("Immediate")
+        .kind(flecs::pipeline::OnStore::ID)
+        .immediate(true)
+        .each(|_| {});
+
+    let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    let stages = schedule.stages();
+    assert_eq!(stages.len(), 4);
+
+    assert!(!stages[0].multi_threaded());
+    assert!(stages[1].multi_threaded());
+    assert_eq!(stages[1].sync_reason(), Some(&SyncReason::MultiThreaded));
+    assert!(stages[1].systems()[0].multi_threaded());
+    assert!(!stages[2].multi_threaded());
+    assert_eq!(stages[2].sync_reason(), Some(&SyncReason::MultiThreaded));
+
+    assert!(stages[3].immediate());
+    assert_eq!(stages[3].sync_reason(), Some(&SyncReason::Immediate));
+    assert!(stages[3].systems()[0].immediate());
+}
+
+#[test]
+fn pipeline_schedule_custom_pipeline() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+
+    let tagged = world
+        .system_named::<&Position>("Tagged")
+        .kind(Tag)
+        .each(|_| {});
+    world.system_named::<&Position>("Untagged").each(|_| {});
+
+    let pipeline = world
+        .pipeline()
+        .with(flecs::system::System::id())
+        .with(Tag::id())
+        .build();
+
+    let schedule = pipeline.schedule().unwrap();
+    let systems: Vec<_> = schedule.systems().collect();
+    assert_eq!(systems.len(), 1);
+    assert_eq!(systems[0].entity(), tagged.id());
+    // Tag is not a phase
+    assert_eq!(systems[0].phase(), None);
+}
+
+#[test]
+fn pipeline_schedule_to_json() {
+    let world = World::new();
+    world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(Velocity { x: 1, y: 1 });
+
+    world
+        .system_named::<&Position>("Spawn")
+        .write(Velocity::id())
+        .each(|_| {});
+    world.system_named::<&Velocity>("Read").each(|_| {});
+
+    let json = world
+        .pipeline_schedule(world.get_pipeline())
+        .unwrap()
+        .to_json();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value["pipeline"], "flecs.pipeline.BuiltinPipeline");
+    let stages = value["stages"].as_array().unwrap();
+    assert_eq!(stages.len(), 2);
+    assert!(stages[0]["sync"].is_null());
+    assert_eq!(stages[1]["sync"]["reason"], "staged");
+
+    let spawn = &stages[0]["systems"][0];
+    assert_eq!(spawn["name"], "Spawn");
+    assert_eq!(spawn["phase"], "flecs.pipeline.OnUpdate");
+    assert_eq!(spawn["multi_threaded"], false);
+    assert!(spawn["writes"][0].as_str().unwrap().ends_with("Velocity"));
+    assert_eq!(stages[1]["systems"][0]["name"], "Read");
+    assert_eq!(stages[1]["sync"]["components"][0], spawn["writes"][0]);
+}
+
+#[test]
+fn pipeline_schedule_to_dot() {
+    let world = World::new();
+    world
+        .entity()
+        .set(Position { x: 0, y: 0 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let spawn = world
+        .system_named::<&Position>("Spawn")
+        .write(Velocity::id())
+        .each(|_| {});
+    let read = world.system_named::<&Velocity>("Read").each(|_| {});
+
+    let dot = world
+        .pipeline_schedule(world.get_pipeline())
+        .unwrap()
+        .to_dot();
+    assert!(dot.starts_with("digraph \"flecs.pipeline.BuiltinPipeline\" {"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("subgraph cluster_0"));
+    assert!(dot.contains("subgraph cluster_1"));
+    assert!(dot.contains(&format!("system_{} [label=\"Spawn", spawn.id())));
+    assert!(dot.contains("sync_1 [shape=diamond, label=\"sync\\nstaged: "));
+    assert!(dot.contains(&format!("system_{} -> sync_1;", spawn.id())));
+    assert!(dot.contains(&format!("sync_1 -> system_{};", read.id())));
+}
+
+#[test]
+fn pipeline_schedule_not_a_pipeline() {
+    let world = World::new();
+    let entity = world.entity();
+    let err = world.pipeline_schedule(entity).unwrap_err();
+    assert!(matches!(err, FlecsError::Schedule { .. }));
+    assert_eq!(err.code(), FlecsErrorCode::InvalidParameter);
+    assert!(err.to_string().contains("is not a pipeline"));
+}
+
+#[test]
+#[should_panic(expected = "while a frame is in progress")]
+fn pipeline_schedule_in_frame() {
+    let world = World::new();
+    world.frame_begin(0.0);
+    let _ = world.pipeline_schedule(world.get_pipeline());
+}
+
+#[test]
+#[should_panic(expected = "while a frame is in progress")]
+fn pipeline_schedule_in_system() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+    world.system::<&Position>().run(|mut it| {
+        while it.next() {}
+        let _ = it.world().pipeline_schedule(it.world().get_pipeline());
+    });
+    world.progress();
+}
+
+#[test]
+fn pipeline_schedule_sync_point_of_inactive_system() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+
+    world
+        .system_named::<&Position>("Spawn")
+        .write(Velocity::id())
+        .each(|_| {});
+    // matches no entities, but still merges the commands of Spawn
+    world
+        .system_named::<&Velocity>("Inactive")
+        .with(Tag)
+        .kind(flecs::pipeline::PostUpdate::ID)
+        .each(|_| {});
+    world
+        .system_named::<&Position>("Print")
+        .kind(flecs::pipeline::OnStore::ID)
+        .each(|_| {});
+
+    let schedule = world.pipeline_schedule(world.get_pipeline()).unwrap();
+    assert_eq!(schedule.stages().len(), 2);
+    assert_eq!(schedule.systems().count(), 2);
+
+    let velocity: Id = world.component_id::<Velocity>().into();
+    assert_eq!(
+        schedule.stages()[1].sync_reason(),
+        Some(&SyncReason::Staged(vec![velocity]))
+    );
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
+use alloc::rc::Rc;
 use core::cell::Cell;
-use core::ffi::c_void;
 
 use crate::common_test::*;
 use flecs_ecs::sys;
@@ -2105,27 +2105,12 @@ fn n16_terms() {
     assert_eq!(count, 1);
 }
 
-#[extern_abi]
-unsafe fn group_by_first_id(
-    _world: *mut sys::ecs_world_t,
-    table: *mut sys::ecs_table_t,
-    _id: u64,
-    _ctx: *mut c_void,
-) -> u64 {
-    unsafe {
-        let table_type: *const sys::ecs_type_t = sys::ecs_table_get_type(table);
-        *(*table_type).array.add(0)
-    }
+fn group_by_first_id(_world: &World, table: Table, _id: Id) -> u64 {
+    *table.archetype().as_slice()[0]
 }
 
-#[extern_abi]
-unsafe fn group_by_first_id_negated(
-    world: *mut sys::ecs_world_t,
-    table: *mut sys::ecs_table_t,
-    id: u64,
-    ctx: *mut c_void,
-) -> u64 {
-    !group_by_first_id(world, table, id, ctx)
+fn group_by_first_id_negated(world: &World, table: Table, id: Id) -> u64 {
+    !group_by_first_id(world, table, id)
 }
 
 #[test]
@@ -2140,13 +2125,13 @@ fn group_by_raw() {
     let q = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(world.entity_from::<TagX>(), Some(group_by_first_id))
+        .group_by(world.entity_from::<TagX>(), group_by_first_id)
         .build();
 
     let q_reverse = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(world.entity_from::<TagX>(), Some(group_by_first_id_negated))
+        .group_by(world.entity_from::<TagX>(), group_by_first_id_negated)
         .build();
 
     let e1 = world.entity().add(TagX::id()).add(TagA::id());
@@ -2203,13 +2188,13 @@ fn group_by_template() {
     let q = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(TagX::id(), Some(group_by_first_id))
+        .group_by(TagX::id(), group_by_first_id)
         .build();
 
     let q_reverse = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(TagX::id(), Some(group_by_first_id_negated))
+        .group_by(TagX::id(), group_by_first_id_negated)
         .build();
 
     let e1 = world.entity().add(TagX::id()).add(TagA::id());
@@ -2266,14 +2251,14 @@ fn group_by_raw_ordered() {
     let q = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(world.entity_from::<TagX>(), Some(group_by_first_id))
+        .group_by(world.entity_from::<TagX>(), group_by_first_id)
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
     let q_reverse = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(world.entity_from::<TagX>(), Some(group_by_first_id_negated))
+        .group_by(world.entity_from::<TagX>(), group_by_first_id_negated)
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
@@ -2331,14 +2316,14 @@ fn group_by_template_ordered() {
     let q = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(TagX::id(), Some(group_by_first_id))
+        .group_by(TagX::id(), group_by_first_id)
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
     let q_reverse = world
         .query::<()>()
         .with(&TagX::id())
-        .group_by_fn(TagX::id(), Some(group_by_first_id_negated))
+        .group_by(TagX::id(), group_by_first_id_negated)
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
@@ -2384,21 +2369,20 @@ fn group_by_template_ordered() {
     assert_eq!(count, 3);
 }
 
-#[extern_abi]
-unsafe fn group_by_rel(
-    world: *mut sys::ecs_world_t,
-    table: *mut sys::ecs_table_t,
-    id: u64,
-    _ctx: *mut c_void,
-) -> u64 {
-    unsafe {
-        let mut id_matched: u64 = 0;
-        let ref_id = &mut id_matched;
-        if sys::ecs_search(world, table, ecs_pair(id, *flecs::Wildcard), ref_id) != -1 {
-            return *ecs_second(id_matched, WorldRef::from_ptr(world));
-        }
-        0
+fn group_by_rel(world: &World, table: Table, id: Id) -> u64 {
+    let mut id_matched: u64 = 0;
+    if unsafe {
+        sys::ecs_search(
+            world.world_ptr_mut(),
+            table.raw_table_ptr(),
+            ecs_pair(*id, *flecs::Wildcard),
+            &mut id_matched,
+        )
+    } != -1
+    {
+        return *ecs_second(id_matched, world);
     }
+    0
 }
 
 #[test]
@@ -2422,7 +2406,7 @@ fn group_by_iter_one() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by_fn(rel, Some(group_by_rel))
+        .group_by(rel, group_by_rel)
         .build();
 
     let mut e2_found = false;
@@ -2466,7 +2450,7 @@ fn group_by_iter_one_template() {
     let q = world
         .query::<()>()
         .with((Rel::id(), *flecs::Wildcard))
-        .group_by_fn(Rel::id(), Some(group_by_rel))
+        .group_by(Rel::id(), group_by_rel)
         .build();
 
     let mut e2_found = false;
@@ -2516,7 +2500,7 @@ fn group_by_iter_one_all_groups() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by_fn(rel, Some(group_by_rel))
+        .group_by(rel, group_by_rel)
         .build();
 
     let group_id = Cell::new(0u64);
@@ -2593,7 +2577,7 @@ fn group_by_default_func_w_id() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by(rel)
+        .group_by_target(rel)
         .build();
 
     let mut e1_found = false;
@@ -2653,7 +2637,7 @@ fn group_by_default_func_w_id_ordered() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by(rel)
+        .group_by_target(rel)
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
@@ -2713,7 +2697,7 @@ fn group_by_default_func_w_type() {
     let q = world
         .query::<()>()
         .with((Rel::id(), id::<flecs::Wildcard>()))
-        .group_by(Rel::id())
+        .group_by_target(Rel::id())
         .build();
 
     let mut e1_found = false;
@@ -2772,7 +2756,7 @@ fn group_by_default_func_w_type_ordered() {
     let q = world
         .query::<()>()
         .with((Rel::id(), id::<flecs::Wildcard>()))
-        .group_by(Rel::id())
+        .group_by_target(Rel::id())
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
@@ -2817,41 +2801,24 @@ fn group_by_default_func_w_type_ordered() {
     assert!(e3_found);
 }
 
-#[extern_abi]
-fn callback_group_create(
-    world: *mut sys::ecs_world_t,
-    group_id: u64,
-    group_by_ctx: *mut c_void,
-) -> *mut c_void {
-    assert_ne!(world, core::ptr::null_mut());
-    assert_ne!(group_id, 0);
-    assert_ne!(group_by_ctx, core::ptr::null_mut());
-    let cell_count = unsafe { &mut *(group_by_ctx as *mut Cell<u64>) };
-    assert_eq!(cell_count.get(), 5);
-    let group_id = Box::new(group_id);
-    Box::into_raw(group_id) as *mut c_void
-}
-#[extern_abi]
-fn callback_group_delete(
-    world: *mut sys::ecs_world_t,
-    group_id: u64,
-    ctx: *mut c_void,
-    group_by_ctx: *mut c_void,
-) {
-    assert_ne!(world, core::ptr::null_mut());
-    assert_ne!(group_id, 0);
-    assert_ne!(group_by_ctx, core::ptr::null_mut());
-    let cell_count = unsafe { &mut *(group_by_ctx as *mut Cell<u64>) };
-    assert_eq!(cell_count.get(), 5);
-    assert_ne!(ctx, core::ptr::null_mut());
-    let group_id_ctx = unsafe { *(ctx as *mut u64) };
-    assert_eq!(group_id_ctx, group_id);
-    let _box = unsafe { Box::from_raw(ctx as *mut u64) };
+fn callback_group_create(cell_count: Rc<Cell<u64>>) -> impl Fn(&World, u64) -> u64 {
+    move |_world, group_id| {
+        assert_ne!(group_id, 0);
+        assert_eq!(cell_count.get(), 5);
+        group_id
+    }
+}
+
+fn callback_group_delete(cell_count: Rc<Cell<u64>>) -> impl Fn(&World, u64) {
+    move |_world, group_id| {
+        assert_ne!(group_id, 0);
+        assert_eq!(cell_count.get(), 5);
+    }
 }
 
 #[test]
 fn group_by_callbacks() {
-    let cell_count_group_ctx = Cell::new(5u64);
+    let cell_count_group_ctx = Rc::new(Cell::new(5u64));
     let world = World::new();
 
     let tgt_a = world.entity();
@@ -2865,10 +2832,9 @@ fn group_by_callbacks() {
     let q = world
         .query::<()>()
         .with((Rel::id(), *flecs::Wildcard))
-        .group_by(Rel::id())
-        .group_by_ctx(cell_count_group_ctx.as_ptr() as *mut c_void, None)
-        .on_group_create(Some(callback_group_create))
-        .on_group_delete(Some(callback_group_delete))
+        .group_by_target(Rel::id())
+        .on_group_create(callback_group_create(cell_count_group_ctx.clone()))
+        .on_group_delete(callback_group_delete(cell_count_group_ctx.clone()))
         .build();
 
     let mut e1_found = false;
@@ -2886,8 +2852,8 @@ fn group_by_callbacks() {
                     assert!(e2_found);
                     assert!(e3_found);
                     e1_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 if e == e2 {
                     assert_eq!(it.group_id(), tgt_b);
@@ -2895,8 +2861,8 @@ fn group_by_callbacks() {
                     assert!(!e2_found);
                     assert!(e3_found);
                     e2_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 if e == e3 {
                     assert_eq!(it.group_id(), tgt_c);
@@ -2904,8 +2870,8 @@ fn group_by_callbacks() {
                     assert!(!e2_found);
                     assert!(!e3_found);
                     e3_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 count += 1;
             }
@@ -2920,7 +2886,7 @@ fn group_by_callbacks() {
 
 #[test]
 fn group_by_callbacks_ordered() {
-    let cell_count_group_ctx = Cell::new(5u64);
+    let cell_count_group_ctx = Rc::new(Cell::new(5u64));
     let world = World::new();
 
     let tgt_a = world.entity();
@@ -2934,11 +2900,10 @@ fn group_by_callbacks_ordered() {
     let q = world
         .query::<()>()
         .with((Rel::id(), *flecs::Wildcard))
-        .group_by(Rel::id())
+        .group_by_target(Rel::id())
         .query_flags(QueryFlags::GroupByOrdered)
-        .group_by_ctx(cell_count_group_ctx.as_ptr() as *mut c_void, None)
-        .on_group_create(Some(callback_group_create))
-        .on_group_delete(Some(callback_group_delete))
+        .on_group_create(callback_group_create(cell_count_group_ctx.clone()))
+        .on_group_delete(callback_group_delete(cell_count_group_ctx.clone()))
         .query_flags(QueryFlags::GroupByOrdered)
         .build();
 
@@ -2957,8 +2922,8 @@ fn group_by_callbacks_ordered() {
                     assert!(!e2_found);
                     assert!(!e3_found);
                     e1_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 if e == e2 {
                     assert_eq!(it.group_id(), tgt_b);
@@ -2966,8 +2931,8 @@ fn group_by_callbacks_ordered() {
                     assert!(!e2_found);
                     assert!(!e3_found);
                     e2_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 if e == e3 {
                     assert_eq!(it.group_id(), tgt_c);
@@ -2975,8 +2940,8 @@ fn group_by_callbacks_ordered() {
                     assert!(e2_found);
                     assert!(!e3_found);
                     e3_found = true;
-                    let ctx: *mut u64 = q.group_context(it.group_id()) as *mut u64;
-                    assert_eq!(unsafe { *ctx }, it.group_id());
+                    let ctx = q.group_context::<u64>(it.group_id());
+                    assert_eq!(ctx, Some(&it.group_id()));
                 }
                 count += 1;
             }
@@ -3005,7 +2970,7 @@ fn iterate_groups() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by_fn(rel, Some(group_by_rel))
+        .group_by(rel, group_by_rel)
         .build();
 
     q.run(|mut it| while it.next() {});
@@ -3015,7 +2980,7 @@ fn iterate_groups() {
     let mut c_found = false;
     let mut count = 0;
 
-    q.each_group(|group, _ctx| {
+    q.each_group(|group, _ctx: Option<&()>| {
         if group == tgt_a.id() {
             a_found = true;
         }
@@ -3043,11 +3008,11 @@ fn iterate_groups_empty() {
     let q = world
         .query::<()>()
         .with((rel, *flecs::Wildcard))
-        .group_by_fn(rel, Some(group_by_rel))
+        .group_by(rel, group_by_rel)
         .build();
 
     let mut count = 0;
-    q.each_group(|_group, _ctx| {
+    q.each_group(|_group, _ctx: Option<&()>| {
         count += 1;
     });
 
@@ -3065,7 +3030,10 @@ fn iterate_groups_w_isa() {
     world.entity().is_a(asset_a).set(Position { x: 3, y: 4 });
     world.entity().is_a(asset_b).set(Position { x: 5, y: 6 });
 
-    let q = world.query::<&Position>().group_by(*flecs::IsA).build();
+    let q = world
+        .query::<&Position>()
+        .group_by_target(*flecs::IsA)
+        .build();
 
     q.run(|mut it| while it.next() {});
 
@@ -3073,7 +3041,7 @@ fn iterate_groups_w_isa() {
     let mut b_found = false;
     let mut count = 0;
 
-    q.each_group(|group, _ctx| {
+    q.each_group(|group, _ctx: Option<&()>| {
         if group == asset_a.id() {
             a_found = true;
         }
@@ -6682,7 +6650,7 @@ fn query_builder_set_group_on_query() {
     let q = world
         .query::<()>()
         .with((rel, id::<flecs::Wildcard>()))
-        .group_by(rel)
+        .group_by_target(rel)
         .build();
 
     let mut e2_found = false;
@@ -6734,7 +6702,7 @@ fn query_builder_set_group_type_on_query() {
     let q = world
         .query::<()>()
         .with((RelSGT::id(), id::<flecs::Wildcard>()))
-        .group_by(RelSGT::id())
+        .group_by_target(RelSGT::id())
         .build();
 
     let tgt_b_raw = world.id_view_from(TgtBSGT::id());
@@ -7949,7 +7917,7 @@ fn set_group_on_query() {
     let q = world
         .query::<()>()
         .with((rel, id::<flecs::Wildcard>()))
-        .group_by(rel)
+        .group_by_target(rel)
         .build();
 
     let mut e2_found = false;
@@ -8001,7 +7969,7 @@ fn set_group_type_on_query() {
     let q = world
         .query::<()>()
         .with((RelSGT::id(), id::<flecs::Wildcard>()))
-        .group_by(RelSGT::id())
+        .group_by_target(RelSGT::id())
         .build();
 
     let tgt_b_raw = world.id_view_from(TgtBSGT::id());
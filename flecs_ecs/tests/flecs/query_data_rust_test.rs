@@ -0,0 +1,188 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[derive(QueryData)]
+struct Move<'a> {
+    pos: &'a mut Position,
+    vel: &'a Velocity,
+    mass: Option<&'a Mass>,
+}
+
+#[derive(QueryData)]
+struct ParentPosition<'a> {
+    pos: &'a Position,
+    #[flecs(up)]
+    parent: &'a Position,
+}
+
+#[derive(QueryData)]
+struct Hierarchy<'a>(&'a Position, #[flecs(cascade)] Option<&'a Position>);
+
+#[derive(QueryData)]
+struct EatsApples<'a> {
+    #[flecs(target = Apples)]
+    pos: &'a Position,
+}
+
+#[derive(QueryData)]
+struct EatsAnything<'a> {
+    #[flecs(target = flecs::Wildcard)]
+    pos: &'a Position,
+    #[flecs(filter)]
+    vel: &'a Velocity,
+}
+
+#[test]
+fn query_data_each() {
+    let world = World::new();
+
+    let e1 = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 1, y: 1 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 2, y: 2 })
+        .set(Mass { value: 10 });
+    world.entity().set(Position { x: 5, y: 6 });
+
+    let query = world.new_query::<Move>();
+    assert_eq!(query.count(), 2);
+
+    query.each(|m| {
+        let scale = m.mass.map_or(1, |mass| mass.value);
+        m.pos.x += m.vel.x * scale;
+        m.pos.y += m.vel.y * scale;
+    });
+
+    e1.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 2);
+        assert_eq!(pos.y, 3);
+    });
+    e2.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 23);
+        assert_eq!(pos.y, 24);
+    });
+}
+
+#[test]
+fn query_data_builder_and_iter() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 1, y: 1 })
+        .add(Tag);
+    world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 2, y: 2 });
+
+    let query = world.query::<Move>().with(Tag).build();
+
+    let mut entities = Vec::new();
+    let mut rows = query.iter();
+    while let Some((entity, m)) = rows.next() {
+        m.pos.x = 100;
+        entities.push(entity.id());
+    }
+    drop(rows);
+
+    assert_eq!(entities, [e.id()]);
+    e.get::<&Position>(|pos| assert_eq!(pos.x, 100));
+}
+
+#[test]
+fn query_data_up() {
+    let world = World::new();
+
+    let parent = world.entity().set(Position { x: 10, y: 20 });
+    let child = world.entity().child_of(parent).set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world.new_query::<ParentPosition>();
+
+    let mut count = 0;
+    query.each_entity(|e, data| {
+        assert_eq!(e, child);
+        assert_eq!(data.pos.x, 1);
+        assert_eq!(data.parent.x, 10);
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn query_data_cascade() {
+    let world = World::new();
+
+    let root = world.entity().set(Position { x: 1, y: 0 });
+    let child = world.entity().child_of(root).set(Position { x: 2, y: 0 });
+    let grandchild = world.entity().child_of(child).set(Position { x: 3, y: 0 });
+
+    let query = world.new_query::<Hierarchy>();
+
+    let mut visited = Vec::new();
+    query.each_entity(|e, Hierarchy(pos, parent)| {
+        visited.push((e.id(), pos.x, parent.map(|p| p.x)));
+    });
+
+    assert_eq!(
+        visited,
+        [
+            (root.id(), 1, None),
+            (child.id(), 2, Some(1)),
+            (grandchild.id(), 3, Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn query_data_pair_target() {
+    let world = World::new();
+
+    let apples = world
+        .entity()
+        .set_pair::<Position, Apples>(Position { x: 1, y: 2 });
+    let pears = world
+        .entity()
+        .set_pair::<Position, Pears>(Position { x: 3, y: 4 })
+        .set(Velocity { x: 0, y: 0 });
+    world.entity().set(Position { x: 5, y: 6 });
+
+    let query = world.new_query::<EatsApples>();
+    let mut found = Vec::new();
+    query.each_entity(|e, data| found.push((e.id(), data.pos.x)));
+    assert_eq!(found, [(apples.id(), 1)]);
+
+    let query = world.new_query::<EatsAnything>();
+    let mut found = Vec::new();
+    query.each_entity(|e, data| found.push((e.id(), data.pos.x, data.vel.x)));
+    assert_eq!(found, [(pears.id(), 3, 0)]);
+    assert_eq!(query.term(1).inout(), InOutKind::Filter);
+}
+
+#[test]
+fn query_data_system() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Velocity { x: 3, y: 4 });
+
+    world.system::<Move>().each(|m| {
+        m.pos.x += m.vel.x;
+        m.pos.y += m.vel.y;
+    });
+
+    world.progress();
+    world.progress();
+
+    e.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 7);
+        assert_eq!(pos.y, 10);
+    });
+}
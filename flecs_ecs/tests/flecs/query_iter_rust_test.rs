@@ -0,0 +1,118 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[test]
+fn query_iter_rows() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    let e2 = world
+        .entity()
+        .set(Position { x: 3, y: 4 })
+        .set(Velocity { x: 1, y: 1 });
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut entities = Vec::new();
+    let mut rows = query.iter();
+    while let Some((entity, pos)) = rows.next() {
+        pos.x += 10;
+        entities.push(entity.id());
+    }
+    drop(rows);
+
+    assert_eq!(entities, [e1.id(), e2.id()]);
+    e1.get::<&Position>(|pos| assert_eq!(pos.x, 11));
+    e2.get::<&Position>(|pos| assert_eq!(pos.x, 13));
+}
+
+#[test]
+fn query_iter_break_releases_locks() {
+    let world = World::new();
+
+    let e1 = world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+
+    let query = world.new_query::<&mut Position>();
+
+    let mut found = None;
+    let mut rows = query.iter();
+    while let Some((entity, pos)) = rows.next() {
+        if pos.x == 1 {
+            found = Some(entity);
+            break;
+        }
+    }
+    drop(rows);
+
+    // the table is no longer locked, so it can be changed
+    let found = found.unwrap();
+    assert_eq!(found, e1);
+    found.add(Tag);
+    e1.get::<&mut Position>(|pos| pos.y = 20);
+    assert!(e1.has(Tag));
+}
+
+#[test]
+fn query_iter_map_adapters() {
+    let world = World::new();
+
+    for i in 0..5 {
+        world
+            .entity()
+            .set(Position { x: i, y: i * 2 })
+            .set(Velocity { x: 1, y: 1 });
+    }
+
+    let query = world.new_query::<(&Position, &Velocity)>();
+
+    let xs: Vec<i32> = query.iter().map(|_, (pos, _)| pos.x).collect();
+    assert_eq!(xs, [0, 1, 2, 3, 4]);
+
+    let even_ys: Vec<i32> = query
+        .iter()
+        .map(|_, (pos, _)| pos.y)
+        .filter(|y| y % 4 == 0)
+        .collect();
+    assert_eq!(even_ys, [0, 4, 8]);
+
+    let taken: Vec<i32> = query
+        .iter()
+        .map(|_, (pos, vel)| pos.x + vel.x)
+        .take_while(|x| *x < 3)
+        .collect();
+    assert_eq!(taken, [1, 2]);
+
+    let zipped: Vec<(i32, i32)> = query
+        .iter()
+        .map(|_, (pos, _)| pos.x)
+        .zip(query.iter().map(|_, (pos, _)| pos.y))
+        .collect();
+    assert_eq!(zipped[4], (4, 8));
+}
+
+#[test]
+fn query_iter_tables() {
+    let world = World::new();
+
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 3, y: 4 });
+    world.entity().set(Position { x: 5, y: 6 }).add(Tag);
+
+    let query = world.new_query::<&Position>();
+
+    let mut sums = Vec::new();
+    let mut tables = query.iter_tables();
+    while let Some(it) = tables.next() {
+        let pos = it.field::<Position>(0);
+        sums.push(it.iter().map(|i| pos[i].x).sum::<i32>());
+    }
+    assert_eq!(sums, [4, 5]);
+
+    let counts: Vec<usize> = query.iter_tables().map(|it| it.count()).collect();
+    assert_eq!(counts, [2, 1]);
+
+    // stopping early finishes the iterator without leaking it
+    let mut tables = query.iter_tables();
+    assert!(tables.next().is_some());
+}
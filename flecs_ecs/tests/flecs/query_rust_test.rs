@@ -294,6 +294,130 @@ fn query_handle_drop_racing_world_drop() {
     }
 }
 
+#[test]
+fn query_par_each() {
+    let world = World::new();
+    world.set_threads(4);
+
+    let entities: Vec<_> = (0..100)
+        .map(|i| {
+            world
+                .entity()
+                .set(Position { x: i, y: 0 })
+                .set(Velocity { x: 1, y: 2 })
+        })
+        .collect();
+
+    let query = world.new_query::<(&mut Position, &Velocity)>();
+    query.par_each(|(p, v)| {
+        p.x += v.x;
+        p.y += v.y;
+    });
+
+    for (i, e) in entities.iter().enumerate() {
+        e.get::<&Position>(|p| {
+            assert_eq!(p.x, i as i32 + 1);
+            assert_eq!(p.y, 2);
+        });
+    }
+    assert!(!world.is_readonly());
+}
+
+#[test]
+fn query_par_run_worker_panic() {
+    let world = World::new();
+    world.set_threads(4);
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let query = world.new_query::<&Position>();
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        query.par_run(|mut it| {
+            while it.next() {
+                assert_eq!(it.world().stage_id(), 0, "worker stage panicked");
+            }
+        });
+    }));
+
+    let payload = result.expect_err("the panic of a worker stage is lost");
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .unwrap_or_default();
+    assert!(message.contains("worker stage panicked"));
+    assert!(!world.is_readonly());
+}
+
+#[test]
+fn query_par_each_entity_defers_commands() {
+    let world = World::new();
+    world.set_threads(4);
+    world.component::<Tag>();
+
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    world.new_query::<&Position>().par_each_entity(|e, p| {
+        if p.x % 2 == 0 {
+            e.add(Tag);
+        }
+    });
+
+    assert_eq!(world.count(Tag), 50);
+}
+
+#[test]
+fn query_par_run_splits_across_stages() {
+    let world = World::new();
+    world.set_threads(4);
+
+    for i in 0..100 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let count = core::sync::atomic::AtomicUsize::new(0);
+    let stages = std::sync::Mutex::new(std::collections::HashSet::new());
+
+    world.new_query::<&Position>().par_run(|mut it| {
+        stages.lock().unwrap().insert(it.world().stage_id());
+        while it.next() {
+            count.fetch_add(it.count(), core::sync::atomic::Ordering::Relaxed);
+        }
+    });
+
+    assert_eq!(count.into_inner(), 100);
+    assert_eq!(stages.into_inner().unwrap().len(), 4);
+}
+
+#[test]
+fn query_par_each_single_stage() {
+    let world = World::new();
+
+    for i in 0..10 {
+        world.entity().set(Position { x: i, y: 0 });
+    }
+
+    let count = core::sync::atomic::AtomicUsize::new(0);
+    world.new_query::<&Position>().par_each(|_| {
+        count.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+    });
+
+    assert_eq!(count.into_inner(), 10);
+}
+
+#[test]
+#[should_panic(expected = "readonly or deferred")]
+fn query_par_each_while_deferred_panics() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+
+    let query = world.new_query::<&Position>();
+    world.defer_begin();
+    query.par_each(|_| {});
+}
+
 #[test]
 #[should_panic(expected = "multithreaded execution phase")]
 fn entity_create_during_multithreaded_phase_panics() {
@@ -3421,7 +3545,7 @@ fn query_set_group_captured_query() {
     let tgt_a = world.entity();
     let tgt_b = world.entity();
 
-    let q = world.query::<&Position>().group_by(rel).build();
+    let q = world.query::<&Position>().group_by_target(rel).build();
 
     world
         .entity()
@@ -4085,3 +4209,50 @@ fn query_pair_with_variable_src() {
 // ─── pair_with_variable_src_no_row_fields ────────────────────────────────────
 // Similar to query_pair_with_variable_src above but with non-tag Rel component.
 // Covered by the variable src test above.
+
+#[test]
+fn query_builder_group_by_build_twice() {
+    let world = World::new();
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let grouped = alloc::rc::Rc::new(core::cell::Cell::new(0));
+    let counter = grouped.clone();
+    let mut builder = world.query::<&Position>();
+    builder.group_by(Position::id(), move |_, _, _| {
+        counter.set(counter.get() + 1);
+        1
+    });
+
+    // the first query owns the callbacks, the second one has none
+    let first = builder.build();
+    let second = builder.build();
+    drop(builder);
+    assert_eq!(first.count(), 1);
+    assert_eq!(second.count(), 1);
+    assert_eq!(grouped.get(), 1);
+
+    drop(first);
+    drop(second);
+    drop(world);
+    assert_eq!(alloc::rc::Rc::strong_count(&grouped), 1);
+}
+
+#[test]
+fn query_builder_group_by_dropped_unbuilt() {
+    let world = World::new();
+    let created = alloc::rc::Rc::new(());
+    let on_create = created.clone();
+    let on_delete = created.clone();
+
+    world
+        .query::<&Position>()
+        .group_by(Position::id(), |_, _, _| 1)
+        .on_group_create(move |_, _| {
+            let _ = &on_create;
+        })
+        .on_group_delete(move |_, _| {
+            let _ = &on_delete;
+        });
+
+    assert_eq!(alloc::rc::Rc::strong_count(&created), 1);
+}
@@ -1773,7 +1773,7 @@ fn set_group_captured_query() {
     let tgt_a = world.entity();
     let tgt_b = world.entity();
 
-    let q = world.query::<&Position>().group_by(rel).build();
+    let q = world.query::<&Position>().group_by_target(rel).build();
 
     world
         .entity()
@@ -4276,8 +4276,8 @@ fn first_empty() {
 
     let q = world.new_query::<&Position>();
 
-    // try_first_entity() returns None when no results
-    assert!(q.try_first_entity().is_none());
+    // try_first_entity() returns an error when no results
+    assert_eq!(q.try_first_entity(), Err(FlecsError::QueryEmpty));
 }
 
 #[test]
@@ -0,0 +1,110 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[derive(Component)]
+struct Player;
+
+#[test]
+fn rest_route_custom_endpoint() {
+    let world = World::new();
+    world.entity_named("alice").add(Player);
+    world.entity_named("bob").add(Player);
+
+    world.rest_route("GET", "/game/players", |req| {
+        assert_eq!(req.method(), "GET");
+        assert_eq!(req.path(), "game/players");
+        let count = req.world().query::<&Player>().build().count();
+        RestResponse::ok(format!("{{\"count\": {count}}}"))
+    });
+
+    let reply = world.rest_request("GET", "/game/players");
+    assert_eq!(reply.code(), 200);
+    assert_eq!(reply.body(), "{\"count\": 2}");
+    assert_eq!(reply.content_type(), c"application/json");
+
+    // the method is part of the route
+    assert_eq!(world.rest_request("POST", "/game/players").code(), 404);
+}
+
+#[test]
+fn rest_route_params_and_body() {
+    let world = World::new();
+
+    world.rest_route("PUT", "/game/score", |req| {
+        let player = req.param("player").unwrap_or("");
+        let body = req.body().unwrap_or("");
+        RestResponse::new(201, format!("{{\"{player}\": {body}}}")).with_content_type(c"text/plain")
+    });
+
+    let reply = world.rest_request_with_body("PUT", "/game/score?player=alice", "42");
+    assert_eq!(reply.code(), 201);
+    assert_eq!(reply.body(), "{\"alice\": 42}");
+    assert_eq!(reply.content_type(), c"text/plain");
+}
+
+#[test]
+fn rest_route_wildcard() {
+    let world = World::new();
+
+    world.rest_route("GET", "/game/*", |req| {
+        RestResponse::ok(format!("\"{}\"", req.path()))
+    });
+
+    assert_eq!(
+        world.rest_request("GET", "/game/a/b").body(),
+        "\"game/a/b\""
+    );
+    assert_eq!(world.rest_request("GET", "/other").code(), 404);
+}
+
+#[test]
+fn rest_route_modifies_world() {
+    let world = World::new();
+
+    world.rest_route("POST", "/game/spawn", |req| {
+        let name = req.param("name").unwrap();
+        req.world().entity_named(name).add(Player);
+        RestResponse::ok("true")
+    });
+
+    let reply = world.rest_request("POST", "/game/spawn?name=carol");
+    assert_eq!(reply.code(), 200);
+    assert!(world.lookup("carol").has(Player));
+}
+
+#[test]
+fn rest_route_not_found() {
+    let world = World::new();
+
+    world.rest_route("GET", "/game/item", |req| match req.param("id") {
+        Some(id) => RestResponse::ok(id),
+        None => RestResponse::not_found(),
+    });
+
+    assert_eq!(world.rest_request("GET", "/game/item?id=3").body(), "3");
+    assert_eq!(world.rest_request("GET", "/game/item").code(), 404);
+}
+
+#[test]
+fn rest_request_builtin_endpoint() {
+    let world = World::new();
+    world.entity_named("foo");
+
+    let reply = world.rest_request("GET", "/entity/foo");
+    assert_eq!(reply.code(), 200);
+    assert!(reply.body().contains("\"name\":\"foo\""));
+
+    assert_eq!(world.rest_request("GET", "/entity/missing").code(), 404);
+    assert_eq!(world.rest_request("GET", "/unknown").code(), 404);
+}
+
+#[test]
+fn rest_route_builtin_endpoints_still_served() {
+    let world = World::new();
+    world.entity_named("foo");
+
+    world.rest_route("GET", "/game/ping", |_| RestResponse::ok("\"pong\""));
+
+    assert_eq!(world.rest_request("GET", "/game/ping").body(), "\"pong\"");
+    assert_eq!(world.rest_request("GET", "/entity/foo").code(), 200);
+}
@@ -0,0 +1,337 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+use core::cell::Cell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use flecs_ecs::addons::system::run_condition::*;
+
+#[derive(Component)]
+struct Paused;
+
+#[derive(Component)]
+struct Settings {
+    volume: i32,
+}
+
+#[repr(C)]
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+enum GameState {
+    Menu,
+    Playing,
+}
+
+fn counter() -> (Rc<Cell<u32>>, impl FnMut(TableIter) + 'static) {
+    let count = Rc::new(Cell::new(0));
+    let counted = count.clone();
+    (count, move |mut it: TableIter| {
+        counted.set(counted.get() + 1);
+        while it.next() {}
+    })
+}
+
+#[test]
+fn run_condition_skips_system() {
+    let world = World::new();
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(|world| !world.has(Paused))
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.add(Paused);
+    world.progress();
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.remove(Paused);
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_each_not_iterated() {
+    let world = World::new();
+    world.entity().set(Position { x: 0, y: 0 });
+    world.entity().set(Position { x: 1, y: 0 });
+
+    let count = Rc::new(Cell::new(0));
+    let counted = count.clone();
+    world
+        .system::<&Position>()
+        .run_if(|world| !world.has(Paused))
+        .each(move |_| counted.set(counted.get() + 1));
+
+    world.progress();
+    assert_eq!(count.get(), 2);
+
+    world.add(Paused);
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_all_conditions_required() {
+    let world = World::new();
+    let (count, run) = counter();
+    let evaluated = Rc::new(Cell::new(0));
+    let second = evaluated.clone();
+
+    world
+        .system::<()>()
+        .run_if(|world| !world.has(Paused))
+        .run_if(move |_| {
+            second.set(second.get() + 1);
+            true
+        })
+        .run(run);
+
+    world.progress();
+    assert_eq!((count.get(), evaluated.get()), (1, 1));
+
+    world.add(Paused);
+    world.progress();
+    // the second condition is not evaluated once the first one fails
+    assert_eq!((count.get(), evaluated.get()), (1, 1));
+}
+
+#[test]
+fn run_condition_resource_changed() {
+    let world = World::new();
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(resource_changed::<Settings>(&world))
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 0);
+
+    world.set(Settings { volume: 1 });
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.set(Settings { volume: 2 });
+    world.progress();
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_resource_changed_needs_modified() {
+    let world = World::new();
+    world.set(Settings { volume: 1 });
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(resource_changed::<Settings>(&world))
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    // a write without `modified` is not a change
+    world.get::<&mut Settings>(|settings| settings.volume = 2);
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.modified(world.component_id::<Settings>());
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_resource_changed_ignores_other_entities() {
+    let world = World::new();
+    world.set(Settings { volume: 1 });
+    let other = world.entity().set(Settings { volume: 1 });
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(resource_changed::<Settings>(&world))
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    other.set(Settings { volume: 2 });
+    world.progress();
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn run_condition_resource_changed_sparse() {
+    let world = World::new();
+    world.component::<Settings>().add_trait::<flecs::Sparse>();
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(resource_changed::<Settings>(&world))
+        .run(run);
+
+    world.set(Settings { volume: 1 });
+    world.progress();
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.set(Settings { volume: 2 });
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_state_is() {
+    let world = World::new();
+    world
+        .component::<GameState>()
+        .add_trait::<flecs::Singleton>();
+    world.add_enum(GameState::Menu);
+
+    let (playing, run_playing) = counter();
+    let (menu, run_menu) = counter();
+    world
+        .system::<()>()
+        .run_if(state_is(GameState::Playing))
+        .run(run_playing);
+    world
+        .system::<()>()
+        .run_if(state_is(GameState::Playing).not())
+        .run(run_menu);
+
+    world.progress();
+    assert_eq!((playing.get(), menu.get()), (0, 1));
+
+    world.add_enum(GameState::Playing);
+    world.progress();
+    assert_eq!((playing.get(), menu.get()), (1, 1));
+}
+
+#[test]
+fn run_condition_combinators() {
+    let world = World::new();
+    world
+        .component::<GameState>()
+        .add_trait::<flecs::Singleton>();
+    world.add_enum(GameState::Playing);
+
+    let (count, run) = counter();
+    world
+        .system::<()>()
+        .run_if(
+            state_is(GameState::Menu)
+                .or(state_is(GameState::Playing).and(|world: &World| !world.has(Paused))),
+        )
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.add(Paused);
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    world.add_enum(GameState::Menu);
+    world.progress();
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn run_condition_on_timer() {
+    let world = World::new();
+    let (count, run) = counter();
+
+    world.system::<()>().run_if(on_timer(1.0)).run(run);
+
+    let mut runs = Vec::new();
+    for _ in 0..6 {
+        world.progress_time(0.5);
+        runs.push(count.get());
+    }
+    assert_eq!(runs, [0, 0, 1, 1, 2, 2]);
+}
+
+#[test]
+fn run_condition_any_match() {
+    let world = World::new();
+    let (count, run) = counter();
+
+    world
+        .system::<()>()
+        .run_if(any_match(world.new_query::<&Position>()))
+        .run(run);
+
+    world.progress();
+    assert_eq!(count.get(), 0);
+
+    let e = world.entity().set(Position { x: 0, y: 0 });
+    world.progress();
+    assert_eq!(count.get(), 1);
+
+    e.destruct();
+    world.progress();
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn run_condition_multi_threaded_once_per_frame() {
+    let world = World::new();
+    world.set_threads(4);
+    // components can't be registered while the system runs on multiple threads
+    world.component::<Paused>();
+    for x in 0..100 {
+        world.entity().set(Position { x, y: 0 });
+    }
+
+    let evaluated = Rc::new(Cell::new(0));
+    let condition_count = evaluated.clone();
+    let iterated = Arc::new(AtomicUsize::new(0));
+    let each_count = iterated.clone();
+    world
+        .system::<&Position>()
+        .run_if(move |world| {
+            condition_count.set(condition_count.get() + 1);
+            !world.has(Paused)
+        })
+        .par_each(move |_| {
+            each_count.fetch_add(1, Ordering::Relaxed);
+        });
+
+    world.progress();
+    assert_eq!(evaluated.get(), 1);
+    assert_eq!(iterated.load(Ordering::Relaxed), 100);
+
+    world.add(Paused);
+    world.progress();
+    world.progress();
+    assert_eq!(evaluated.get(), 3);
+    assert_eq!(iterated.load(Ordering::Relaxed), 100);
+}
+
+#[test]
+#[should_panic(expected = "condition failed")]
+fn run_condition_multi_threaded_panic_releases_stages() {
+    let world = World::new();
+    world.set_threads(4);
+    for x in 0..100 {
+        world.entity().set(Position { x, y: 0 });
+    }
+
+    world
+        .system::<&Position>()
+        .run_if(|_| panic!("condition failed"))
+        .par_each(|_| {});
+
+    world.progress();
+}
@@ -0,0 +1,173 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicBool, Ordering};
+use flecs_ecs::sys;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+#[derive(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+fn api() -> sys::ecs_os_api_t {
+    // initializes the OS API
+    drop(World::new());
+    unsafe { sys::ecs_os_get_api() }
+}
+
+type ThreadNames = Arc<Mutex<HashSet<String>>>;
+
+fn record_thread_name(names: &ThreadNames) {
+    let name = std::thread::current()
+        .name()
+        .unwrap_or_default()
+        .to_string();
+    names.lock().unwrap().insert(name);
+}
+
+#[test]
+fn rust_os_api_worker_threads() {
+    let world = World::new();
+    for i in 0..64 {
+        world.entity().set(Position {
+            x: i as f32,
+            y: 0.0,
+        });
+    }
+
+    let names = ThreadNames::default();
+    let names_system = names.clone();
+    world
+        .system::<&Position>()
+        .par_each(move |_| record_thread_name(&names_system));
+
+    world.set_threads(4);
+    world.progress();
+
+    assert!(names.lock().unwrap().contains("flecs-worker"));
+}
+
+#[test]
+fn rust_os_api_task_threads() {
+    let world = World::new();
+    for i in 0..64 {
+        world.entity().set(Position {
+            x: i as f32,
+            y: 0.0,
+        });
+    }
+
+    let names = ThreadNames::default();
+    let names_system = names.clone();
+    world
+        .system::<&Position>()
+        .par_each(move |_| record_thread_name(&names_system));
+
+    world.set_task_threads(4);
+    world.progress();
+    world.progress();
+
+    assert!(names.lock().unwrap().contains("flecs-task"));
+}
+
+#[test]
+fn rust_os_api_alloc() {
+    let api = api();
+    unsafe {
+        let ptr = api.calloc_.unwrap()(64) as *mut u8;
+        assert!((0..64).all(|i| *ptr.add(i) == 0));
+        assert_eq!(ptr as usize % 16, 0);
+
+        for i in 0..64 {
+            *ptr.add(i) = i as u8;
+        }
+        let ptr = api.realloc_.unwrap()(ptr as *mut c_void, 4096) as *mut u8;
+        assert!((0..64).all(|i| *ptr.add(i) == i as u8));
+        api.free_.unwrap()(ptr as *mut c_void);
+
+        let str = api.strdup_.unwrap()(c"flecs".as_ptr());
+        assert_eq!(core::ffi::CStr::from_ptr(str), c"flecs");
+        api.free_.unwrap()(str as *mut c_void);
+
+        api.free_.unwrap()(core::ptr::null_mut());
+    }
+}
+
+#[test]
+fn rust_os_api_mutex_cond() {
+    struct Shared {
+        mutex: sys::ecs_os_mutex_t,
+        cond: sys::ecs_os_cond_t,
+        ready: AtomicBool,
+    }
+
+    unsafe extern "C-unwind" fn signal(param: *mut c_void) -> *mut c_void {
+        let api = unsafe { sys::ecs_os_get_api() };
+        let shared = param as *mut Shared;
+        unsafe {
+            api.mutex_lock_.unwrap()((*shared).mutex);
+            (*shared).ready.store(true, Ordering::Relaxed);
+            api.cond_signal_.unwrap()((*shared).cond);
+            api.mutex_unlock_.unwrap()((*shared).mutex);
+        }
+        param
+    }
+
+    let api = api();
+    unsafe {
+        let mut shared = Shared {
+            mutex: api.mutex_new_.unwrap()(),
+            cond: api.cond_new_.unwrap()(),
+            ready: AtomicBool::new(false),
+        };
+        let shared_ptr = &mut shared as *mut Shared;
+
+        api.mutex_lock_.unwrap()(shared.mutex);
+        let thread = api.thread_new_.unwrap()(Some(signal), shared_ptr as *mut c_void);
+        while !(*shared_ptr).ready.load(Ordering::Relaxed) {
+            api.cond_wait_.unwrap()(shared.cond, shared.mutex);
+        }
+        api.mutex_unlock_.unwrap()(shared.mutex);
+
+        let result = api.thread_join_.unwrap()(thread);
+        assert_eq!(result, shared_ptr as *mut c_void);
+
+        api.cond_free_.unwrap()(shared.cond);
+        api.mutex_free_.unwrap()(shared.mutex);
+    }
+}
+
+#[test]
+fn rust_os_api_thread_panic_is_resumed_on_join() {
+    unsafe extern "C-unwind" fn panics(_: *mut c_void) -> *mut c_void {
+        panic!("panic on flecs thread");
+    }
+
+    let api = api();
+    let thread = unsafe { api.thread_new_.unwrap()(Some(panics), core::ptr::null_mut()) };
+    let result = std::panic::catch_unwind(|| unsafe { api.thread_join_.unwrap()(thread) });
+
+    let panic = result.unwrap_err();
+    assert_eq!(panic.downcast_ref::<&str>(), Some(&"panic on flecs thread"));
+}
+
+#[test]
+fn rust_os_api_time() {
+    let api = api();
+    unsafe {
+        let start = api.now_.unwrap()();
+        api.sleep_.unwrap()(0, 2_000_000);
+        let elapsed = api.now_.unwrap()() - start;
+        assert!(elapsed >= 2_000_000);
+
+        let mut time = sys::ecs_time_t { sec: 0, nanosec: 0 };
+        api.get_time_.unwrap()(&mut time);
+        assert!(time.nanosec < 1_000_000_000);
+    }
+}
@@ -1590,3 +1590,44 @@ mod dense_field_at_locks {
             });
     }
 }
+
+mod query_rows {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn row_write_view_read() {
+        let world = World::new();
+        world.entity().set(Foo(0));
+        let query = query!(world, &mut Foo).build();
+        let mut rows = query.iter();
+        while let Some((entity, _foo)) = rows.next() {
+            entity.get::<&Foo>(|_| {});
+        }
+    }
+
+    #[test]
+    fn rows_dropped_early_release_locks() {
+        let world = World::new();
+        let entity = world.entity().set(Foo(0));
+        world.entity().set(Foo(1));
+        let query = query!(world, &mut Foo).build();
+        let mut rows = query.iter();
+        let _ = rows.next();
+        drop(rows);
+        entity.get::<&mut Foo>(|foo| foo.0 = 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn table_write_view_read() {
+        let world = World::new();
+        world.entity().set(Foo(0));
+        let query = query!(world, &mut Foo).build();
+        let mut tables = query.iter_tables();
+        while let Some(it) = tables.next() {
+            let _foo = it.field_mut::<Foo>(0);
+            it.entity(0usize).get::<&Foo>(|_| {});
+        }
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(dead_code)]
+#![allow(clippy::float_cmp)]
+use crate::common_test::*;
+
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+#[flecs(meta)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+#[flecs(meta)]
+struct Label {
+    text: String,
+    size: i32,
+}
+
+#[test]
+fn script_from_expr() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let p = world.from_expr::<Point>("{x: 10, y: 2 * 3}").unwrap();
+    assert_eq!(p, Point { x: 10.0, y: 6.0 });
+
+    let p = world.from_expr::<Point>("{y: 1}").unwrap();
+    assert_eq!(p, Point { x: 0.0, y: 1.0 });
+
+    assert_eq!(world.from_expr::<f64>("1 + 2").unwrap(), 3.0);
+}
+
+#[test]
+fn script_from_expr_string_member() {
+    let world = World::new();
+    world.component::<Label>();
+
+    let label = world
+        .from_expr::<Label>("{text: \"hello\", size: 12}")
+        .unwrap();
+    assert_eq!(
+        label,
+        Label {
+            text: "hello".to_string(),
+            size: 12
+        }
+    );
+}
+
+#[test]
+fn script_from_expr_with_vars() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let mut vars = world.script_vars();
+    vars.declare("offset", 4.0f32)
+        .declare("origin", Point { x: 1.0, y: 2.0 });
+
+    let p = world
+        .from_expr_with::<Point>("{x: origin.x + 10, y: $offset * 2}", &vars)
+        .unwrap();
+    assert_eq!(p, Point { x: 11.0, y: 8.0 });
+}
+
+#[test]
+fn script_from_expr_error() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let err = world.from_expr::<Point>("{x: 10, y: }").unwrap_err();
+    assert!(matches!(err, FlecsError::Expr { .. }));
+    assert!(!err.message().is_empty());
+    assert!(err.to_string().contains("{x: 10, y: }"));
+
+    let err = world.from_expr::<Point>("{x: missing}").unwrap_err();
+    assert!(err.message().contains("missing"));
+}
+
+#[test]
+fn script_eval_expr_number() {
+    let world = World::new();
+
+    let value = world.eval_expr("10 + 2 * 3.5").unwrap();
+    assert_eq!(value.as_f64(), Some(17.0));
+    assert_eq!(value.get::<f64>(), Some(17.0));
+    assert_eq!(value.get::<f32>(), None);
+
+    let value = world.eval_expr("7 / 2").unwrap();
+    assert_eq!(value.as_f64(), Some(3.5));
+    assert_eq!(value.as_i64(), Some(3));
+}
+
+#[test]
+fn script_eval_expr_string() {
+    let world = World::new();
+
+    let value = world.eval_expr("\"hello\"").unwrap();
+    assert_eq!(value.get::<String>().as_deref(), Some("hello"));
+    assert_eq!(value.as_f64(), None);
+    assert_eq!(value.to_string(), "\"hello\"");
+}
+
+#[test]
+fn script_eval_expr_component() {
+    let world = World::new();
+    let point = world.component::<Point>();
+
+    let mut vars = world.script_vars();
+    vars.declare("origin", Point { x: 1.0, y: 2.0 });
+
+    let value = world.eval_expr_with("origin", &vars).unwrap();
+    assert_eq!(value.type_id(), point);
+    assert_eq!(value.get::<Point>(), Some(Point { x: 1.0, y: 2.0 }));
+    assert_eq!(value.as_f64(), None);
+    assert_eq!(value.to_expr(), "{x: 1, y: 2}");
+}
+
+#[test]
+fn script_eval_expr_with_vars() {
+    let world = World::new();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 16.0f64);
+
+    let value = world.eval_expr_with("1 + $x / 2", &vars).unwrap();
+    assert_eq!(value.as_f64(), Some(9.0));
+
+    vars.set("x", 4.0f64);
+    let value = world.eval_expr_with("1 + x / 2", &vars).unwrap();
+    assert_eq!(value.as_f64(), Some(3.0));
+
+    let err = world.eval_expr("1 + $x").unwrap_err();
+    assert!(err.message().contains("x"));
+}
+
+#[test]
+#[cfg(feature = "flecs_script_math")]
+fn script_eval_expr_math() {
+    let world = World::new();
+    world.import::<ScriptMath>();
+
+    let mut vars = world.script_vars();
+    vars.declare("x", 16.0f64);
+
+    let value = world
+        .eval_expr_with("1 + flecs.script.math.sqrt($x)", &vars)
+        .unwrap();
+    assert_eq!(value.as_f64(), Some(5.0));
+}
@@ -0,0 +1,170 @@
+#![allow(dead_code)]
+#![allow(clippy::float_cmp)]
+use crate::common_test::*;
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+#[derive(Component)]
+#[flecs(meta)]
+struct Value {
+    value: f32,
+}
+
+#[derive(Component, Clone)]
+#[flecs(meta)]
+struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component)]
+#[flecs(meta)]
+struct Counter {
+    value: i32,
+}
+
+fn value_of(world: &World, name: &str) -> f32 {
+    world.lookup(name).get::<&Value>(|v| v.value)
+}
+
+#[test]
+fn script_function_call() {
+    let world = World::new();
+    world.component::<Value>();
+
+    world
+        .script_function("double")
+        .arg::<f32>("x")
+        .returns::<f32>()
+        .build(|args| args.get::<f32>(0) * 2.0);
+
+    assert!(world.run_code("main", "e { Value: {double(2.5)} }"));
+    assert_eq!(value_of(&world, "e"), 5.0);
+}
+
+#[test]
+fn script_function_multiple_args() {
+    let world = World::new();
+    world.component::<Value>();
+
+    world
+        .script_function("mul_add")
+        .arg::<f32>("a")
+        .arg::<f32>("b")
+        .arg::<f32>("c")
+        .returns::<f32>()
+        .build(|args| {
+            assert_eq!(args.len(), 3);
+            args.get::<f32>(0) * args.get::<f32>(1) + args.get::<f32>(2)
+        });
+
+    assert!(world.run_code("main", "e { Value: {mul_add(2, 3, 4)} }"));
+    assert_eq!(value_of(&world, "e"), 10.0);
+}
+
+#[test]
+fn script_function_entity() {
+    let world = World::new();
+
+    let function = world
+        .script_function("answer")
+        .returns::<i32>()
+        .build(|_| 42);
+
+    assert_eq!(function.name(), "answer");
+    assert_eq!(world.lookup("answer"), function);
+}
+
+#[test]
+fn script_function_captures_state() {
+    let world = World::new();
+    world.component::<Counter>();
+
+    let calls = Cell::new(0);
+    world
+        .script_function("next")
+        .returns::<i32>()
+        .build(move |_| {
+            calls.set(calls.get() + 1);
+            calls.get()
+        });
+
+    assert!(world.run_code("main", "a { Counter: {next()} }\nb { Counter: {next()} }"));
+    world
+        .lookup("a")
+        .get::<&Counter>(|c| assert_eq!(c.value, 1));
+    world
+        .lookup("b")
+        .get::<&Counter>(|c| assert_eq!(c.value, 2));
+}
+
+#[test]
+fn script_function_reregister_drops_previous() {
+    let world = World::new();
+    world.component::<Counter>();
+
+    let state = Rc::new(());
+    let captured = state.clone();
+    world
+        .script_function("answer")
+        .returns::<i32>()
+        .build(move |_| {
+            let _ = &captured;
+            1
+        });
+    assert_eq!(Rc::strong_count(&state), 2);
+
+    // registering the same name again replaces the function and drops the old closure
+    world
+        .script_function("answer")
+        .returns::<i32>()
+        .build(|_| 2);
+    assert_eq!(Rc::strong_count(&state), 1);
+
+    assert!(world.run_code("main", "e { Counter: {answer()} }"));
+    world
+        .lookup("e")
+        .get::<&Counter>(|c| assert_eq!(c.value, 2));
+}
+
+#[test]
+fn script_method_call() {
+    let world = World::new();
+    world.component::<Value>();
+
+    world
+        .script_method::<Vec2>("dot")
+        .arg::<Vec2>("other")
+        .returns::<f32>()
+        .build(|args| {
+            let a = args.get::<Vec2>(0);
+            let b = args.get::<Vec2>(1);
+            a.x * b.x + a.y * b.y
+        });
+
+    assert!(world.run_code(
+        "main",
+        "const v = Vec2: {1, 2}\ne { Value: {v.dot(Vec2: {3, 4})} }"
+    ));
+    assert_eq!(value_of(&world, "e"), 11.0);
+}
+
+#[test]
+#[cfg(feature = "flecs_script_math")]
+fn script_function_with_math() {
+    let world = World::new();
+    world.component::<Value>();
+    world.import::<ScriptMath>();
+
+    world
+        .script_function("square")
+        .arg::<f64>("x")
+        .returns::<f64>()
+        .build(|args| args.get::<f64>(0) * args.get::<f64>(0));
+
+    assert!(world.run_code(
+        "main",
+        "using flecs.script.math\ne { Value: {sqrt(square(3))} }"
+    ));
+    assert_eq!(value_of(&world, "e"), 3.0);
+}
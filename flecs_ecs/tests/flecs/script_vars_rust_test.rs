@@ -0,0 +1,163 @@
+#![allow(dead_code)]
+#![allow(clippy::float_cmp)]
+use crate::common_test::*;
+
+#[derive(Component, Clone, Debug, PartialEq)]
+#[flecs(meta)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+// no Default, so flecs must never construct it
+#[derive(Component, Clone, Debug, PartialEq)]
+#[flecs(meta)]
+struct Spawn {
+    count: i32,
+}
+
+#[test]
+fn script_vars_declare_and_get() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let target = world.entity_named("target");
+    let mut vars = world.script_vars();
+    vars.declare("speed", 2.5f32)
+        .declare("origin", Point { x: 1.0, y: 2.0 })
+        .declare("name", String::from("spawner"))
+        .declare("target", target.id());
+
+    assert_eq!(vars.get::<f32>("speed"), Some(2.5));
+    assert_eq!(vars.get::<Point>("origin"), Some(Point { x: 1.0, y: 2.0 }));
+    assert_eq!(vars.get::<String>("name").as_deref(), Some("spawner"));
+    assert_eq!(vars.get::<Entity>("target"), Some(target.id()));
+
+    assert_eq!(vars.get::<i32>("speed"), None);
+    assert_eq!(vars.get::<f32>("missing"), None);
+}
+
+#[test]
+fn script_vars_eval_many_times() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let script = Script::parse(
+        &world,
+        "level",
+        "parent { e { Point: {origin.x + offset, origin.y} } }",
+        None,
+    )
+    .unwrap();
+
+    let mut vars = world.script_vars();
+    vars.declare("origin", Point { x: 1.0, y: 2.0 })
+        .declare("offset", 0.0f32);
+
+    for i in 0..3 {
+        vars.set("offset", i as f32 * 10.0);
+        assert!(script.eval_with(&vars));
+
+        world.lookup("parent::e").get::<&Point>(|p| {
+            assert_eq!(
+                *p,
+                Point {
+                    x: 1.0 + i as f32 * 10.0,
+                    y: 2.0
+                }
+            );
+        });
+    }
+}
+
+#[test]
+fn script_vars_drop_in_any_order() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let script = Script::parse(&world, "main", "e { Point: {x, y} }", None).unwrap();
+
+    let mut first = world.script_vars();
+    first.declare("x", 1.0f32).declare("y", 2.0f32);
+    let mut second = world.script_vars();
+    second.declare("x", 3.0f32).declare("y", 4.0f32);
+
+    // dropping the oldest set first must leave the variables of the other set intact
+    drop(first);
+    let mut third = world.script_vars();
+    third
+        .declare("x", 5.0f32)
+        .declare("y", String::from("overlap"));
+
+    assert_eq!(second.get::<f32>("x"), Some(3.0));
+    assert!(script.eval_with(&second));
+    world
+        .lookup("e")
+        .get::<&Point>(|p| assert_eq!(*p, Point { x: 3.0, y: 4.0 }));
+    assert_eq!(third.get::<String>("y").as_deref(), Some("overlap"));
+}
+
+#[test]
+fn script_vars_non_default_component() {
+    let world = World::new();
+    world.component::<Spawn>();
+
+    let script = Script::parse(&world, "spawn", "e { Spawn: {spawn.count * 2} }", None).unwrap();
+
+    let mut vars = world.script_vars();
+    vars.declare("spawn", Spawn { count: 4 });
+    assert!(script.eval_with(&vars));
+
+    world.lookup("e").get::<&Spawn>(|s| assert_eq!(s.count, 8));
+}
+
+#[test]
+fn script_vars_set_string() {
+    let world = World::new();
+
+    let mut vars = world.script_vars();
+    vars.declare("name", String::from("first"));
+    vars.set("name", String::from("second"));
+
+    assert_eq!(vars.get::<String>("name").as_deref(), Some("second"));
+}
+
+#[test]
+fn script_vars_try_eval_unresolved() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let script = Script::parse(&world, "main", "e { Point: {missing, 0} }", None);
+    let vars = world.script_vars();
+    if let Some(script) = script {
+        assert!(script.try_eval_with(&vars).is_err());
+    }
+}
+
+#[test]
+#[should_panic(expected = "script variable 'a' is already declared")]
+fn script_vars_declare_twice() {
+    let world = World::new();
+
+    let mut vars = world.script_vars();
+    vars.declare("a", 1.0f32).declare("a", 2.0f32);
+}
+
+#[test]
+fn script_get_const() {
+    let world = World::new();
+    world.component::<Point>();
+
+    assert!(world.run_code(
+        "main",
+        "export const scale = 3.5\nexport const label = \"hello\"\nexport const origin = Point: {1, 2}"
+    ));
+
+    assert_eq!(world.get_const::<f64>("scale"), Some(3.5));
+    assert_eq!(world.get_const::<String>("label").as_deref(), Some("hello"));
+    assert_eq!(
+        world.get_const::<Point>("origin"),
+        Some(Point { x: 1.0, y: 2.0 })
+    );
+    assert_eq!(world.get_const::<f64>("missing"), None);
+}
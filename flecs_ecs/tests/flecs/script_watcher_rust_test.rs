@@ -0,0 +1,177 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+use core::cell::RefCell;
+use core::time::Duration;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+extern crate alloc;
+use alloc::rc::Rc;
+
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+#[flecs(meta)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+struct ScriptFile {
+    path: PathBuf,
+    version: u64,
+}
+
+impl ScriptFile {
+    fn new(name: &str, code: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "flecs_rust_script_watcher_{name}_{}.flecs",
+            std::process::id()
+        ));
+        let mut file = ScriptFile { path, version: 0 };
+        file.write(code);
+        file
+    }
+
+    fn path(&self) -> &str {
+        self.path.to_str().unwrap()
+    }
+
+    /// Writes the file, with a modification time that is different from the previous write.
+    fn write(&mut self, code: &str) {
+        self.version += 1;
+        std::fs::write(&self.path, code).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&self.path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(self.version * 60))
+            .unwrap();
+    }
+}
+
+impl Drop for ScriptFile {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+fn point_of(world: &World, name: &str) -> Point {
+    world.lookup(name).get::<&Point>(Point::clone)
+}
+
+#[test]
+fn script_watcher_reloads_changed_file() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let mut file = ScriptFile::new("reload", "e { Point: {1, 2} }");
+    world.script().build_from_file(file.path());
+    world.script_watcher().interval(0.0).build();
+
+    world.progress();
+    assert_eq!(point_of(&world, "e"), Point { x: 1.0, y: 2.0 });
+
+    file.write("e { Point: {3, 4} }\nf { Point: {5, 6} }");
+    world.progress();
+    assert_eq!(point_of(&world, "e"), Point { x: 3.0, y: 4.0 });
+    assert_eq!(point_of(&world, "f"), Point { x: 5.0, y: 6.0 });
+
+    file.write("f { Point: {7, 8} }");
+    world.progress();
+    assert!(world.try_lookup("e").is_err());
+    assert_eq!(point_of(&world, "f"), Point { x: 7.0, y: 8.0 });
+}
+
+#[test]
+fn script_watcher_interval() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let mut file = ScriptFile::new("interval", "e { Point: {1, 2} }");
+    world.script().build_from_file(file.path());
+    world.script_watcher().interval(1.0).build();
+
+    file.write("e { Point: {3, 4} }");
+    world.progress_time(0.6);
+    assert_eq!(point_of(&world, "e"), Point { x: 1.0, y: 2.0 });
+
+    world.progress_time(0.6);
+    assert_eq!(point_of(&world, "e"), Point { x: 3.0, y: 4.0 });
+}
+
+#[test]
+fn script_watcher_watches_scripts_loaded_later() {
+    let world = World::new();
+    world.component::<Point>();
+
+    world.script_watcher().interval(0.0).build();
+    world.progress();
+
+    let mut file = ScriptFile::new("later", "e { Point: {1, 2} }");
+    world.script().build_from_file(file.path());
+    world.progress();
+
+    file.write("e { Point: {3, 4} }");
+    world.progress();
+    assert_eq!(point_of(&world, "e"), Point { x: 3.0, y: 4.0 });
+}
+
+#[test]
+fn script_watcher_parse_error() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let mut file = ScriptFile::new("parse_error", "e { Point: {1, 2} }");
+    let script = world.script().build_from_file(file.path());
+
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let errors_cb = errors.clone();
+    world
+        .script_watcher()
+        .interval(0.0)
+        .on_error(move |script, err| {
+            errors_cb.borrow_mut().push((script.id(), err.clone()));
+        })
+        .build();
+
+    file.write("e { Point: {3, 4} ");
+    world.progress();
+
+    let errors = errors.borrow();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, script.id());
+    assert!(matches!(errors[0].1, FlecsError::Script { .. }));
+    assert!(!errors[0].1.message().is_empty());
+
+    // the entities of the previous version are untouched
+    assert_eq!(point_of(&world, "e"), Point { x: 1.0, y: 2.0 });
+}
+
+#[test]
+fn script_watcher_eval_error_restores_previous() {
+    let world = World::new();
+    world.component::<Point>();
+
+    let mut file = ScriptFile::new("eval_error", "e { Point: {1, 2} }");
+    world.script().build_from_file(file.path());
+
+    let errors = Rc::new(RefCell::new(0));
+    let errors_cb = errors.clone();
+    world
+        .script_watcher()
+        .interval(0.0)
+        .on_error(move |_, _| *errors_cb.borrow_mut() += 1)
+        .build();
+
+    file.write("e { Point: {3, 4} }\nf { Point: {x: missing} }");
+    world.progress();
+
+    assert_eq!(*errors.borrow(), 1);
+    assert_eq!(point_of(&world, "e"), Point { x: 1.0, y: 2.0 });
+    assert!(world.try_lookup("f").is_err());
+
+    // a later fix is picked up again
+    file.write("e { Point: {5, 6} }");
+    world.progress();
+    assert_eq!(*errors.borrow(), 1);
+    assert_eq!(point_of(&world, "e"), Point { x: 5.0, y: 6.0 });
+}
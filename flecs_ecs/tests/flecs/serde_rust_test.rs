@@ -0,0 +1,306 @@
+#![allow(dead_code)]
+use core::mem::offset_of;
+
+use flecs_ecs::prelude::*;
+use serde::de::DeserializeSeed;
+
+#[derive(Debug, Default, Clone, PartialEq, Component)]
+#[flecs(meta)]
+struct SerdePosition {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+#[repr(C)]
+#[flecs(meta)]
+enum SerdeColor {
+    #[default]
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Component)]
+#[flecs(meta)]
+struct SerdeUnit {
+    name: String,
+    level: u8,
+    health: i64,
+    color: SerdeColor,
+    position: SerdePosition,
+    path: Vec<SerdePosition>,
+    alive: bool,
+}
+
+#[derive(Debug, Default, Component)]
+struct SerdeSlots {
+    slots: [i16; 3],
+}
+
+#[derive(Debug, Default, Component)]
+struct SerdeFlags {
+    value: u32,
+}
+
+#[derive(Component)]
+struct SerdeTag;
+
+#[derive(Component)]
+struct SerdeNotReflected {
+    value: i32,
+}
+
+fn register(world: &World) {
+    meta_register_vector_type!(world, SerdePosition::default());
+    world.component::<SerdeSlots>().member(
+        i16::id(),
+        ("slots", Count(3), offset_of!(SerdeSlots, slots)),
+    );
+}
+
+fn unit() -> SerdeUnit {
+    SerdeUnit {
+        name: "archer".to_string(),
+        level: 7,
+        health: -3,
+        color: SerdeColor::Blue,
+        position: SerdePosition { x: 1.5, y: -2.0 },
+        path: vec![
+            SerdePosition { x: 1.0, y: 2.0 },
+            SerdePosition { x: 3.0, y: 4.0 },
+        ],
+        alive: true,
+    }
+}
+
+// matches the options used by `bincode::serialize`
+fn bincode_deserializer(
+    bytes: &[u8],
+) -> bincode::Deserializer<impl bincode::BincodeRead<'_>, impl bincode::Options> {
+    use bincode::Options;
+    bincode::Deserializer::from_slice(
+        bytes,
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes(),
+    )
+}
+
+#[test]
+fn serde_value_to_json() {
+    let world = World::new();
+    register(&world);
+
+    let json = serde_json::to_value(world.serde_value(&unit())).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "name": "archer",
+            "level": 7,
+            "health": -3,
+            "color": "Blue",
+            "position": { "x": 1.5, "y": -2.0 },
+            "path": [{ "x": 1.0, "y": 2.0 }, { "x": 3.0, "y": 4.0 }],
+            "alive": true,
+        })
+    );
+}
+
+#[test]
+fn serde_value_json_roundtrip() {
+    let world = World::new();
+    register(&world);
+
+    let json = serde_json::to_string(&world.serde_value(&unit())).unwrap();
+
+    let mut value = SerdeUnit {
+        path: vec![SerdePosition::default(); 5],
+        ..Default::default()
+    };
+    world
+        .serde_seed(&mut value)
+        .deserialize(&mut serde_json::Deserializer::from_str(&json))
+        .unwrap();
+
+    assert_eq!(value, unit());
+}
+
+#[test]
+fn serde_value_bincode_roundtrip() {
+    let world = World::new();
+    register(&world);
+
+    let bytes = bincode::serialize(&world.serde_value(&unit())).unwrap();
+
+    let mut value = SerdeUnit::default();
+    world
+        .serde_seed(&mut value)
+        .deserialize(&mut bincode_deserializer(&bytes))
+        .unwrap();
+
+    assert_eq!(value, unit());
+}
+
+#[test]
+fn serde_seed_keeps_missing_members() {
+    let world = World::new();
+    register(&world);
+
+    let mut pos = SerdePosition { x: 1.0, y: 2.0 };
+    world
+        .serde_seed(&mut pos)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#"{"y": 5}"#))
+        .unwrap();
+
+    assert_eq!(pos, SerdePosition { x: 1.0, y: 5.0 });
+}
+
+#[test]
+fn serde_seed_unknown_member() {
+    let world = World::new();
+    register(&world);
+
+    let mut pos = SerdePosition::default();
+    let result = world
+        .serde_seed(&mut pos)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#"{"z": 5}"#));
+
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("unknown member 'z'"), "{err}");
+}
+
+#[test]
+fn serde_value_inline_array() {
+    let world = World::new();
+    register(&world);
+
+    let value = SerdeSlots { slots: [1, -2, 3] };
+    let json = serde_json::to_string(&world.serde_value(&value)).unwrap();
+    assert_eq!(json, r#"{"slots":[1,-2,3]}"#);
+
+    let bytes = bincode::serialize(&world.serde_value(&value)).unwrap();
+    let mut value = SerdeSlots::default();
+    world
+        .serde_seed(&mut value)
+        .deserialize(&mut bincode_deserializer(&bytes))
+        .unwrap();
+    assert_eq!(value.slots, [1, -2, 3]);
+}
+
+#[test]
+fn serde_seed_too_many_elements() {
+    let world = World::new();
+    register(&world);
+
+    let mut value = SerdeUnit::default();
+    let result = world
+        .serde_seed(&mut value)
+        .deserialize(&mut serde_json::Deserializer::from_str(
+            r#"{"slots": [1, 2, 3, 4]}"#,
+        ));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn serde_value_bitmask() {
+    let world = World::new();
+    register(&world);
+
+    world
+        .component::<SerdeFlags>()
+        .bit("a", 0x1u32)
+        .bit("b", 0x2u32)
+        .bit("c", 0x4u32);
+
+    let flags = SerdeFlags { value: 0x5 };
+    let json = serde_json::to_string(&world.serde_value(&flags)).unwrap();
+    assert_eq!(json, r#""a|c""#);
+
+    let mut flags = SerdeFlags::default();
+    world
+        .serde_seed(&mut flags)
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""b|c""#))
+        .unwrap();
+    assert_eq!(flags.value, 0x6);
+}
+
+#[test]
+fn serde_value_not_reflected() {
+    let world = World::new();
+    register(&world);
+
+    let value = SerdeNotReflected { value: 1 };
+    assert!(serde_json::to_string(&world.serde_value(&value)).is_err());
+}
+
+#[test]
+fn serde_entity_to_json() {
+    let world = World::new();
+    register(&world);
+
+    let e = world
+        .entity_named("player")
+        .set(SerdePosition { x: 1.0, y: 2.0 })
+        .set(SerdeNotReflected { value: 3 })
+        .add(SerdeTag);
+
+    let json = serde_json::to_value(e.serde_value()).unwrap();
+
+    let position = world.component::<SerdePosition>().path().unwrap();
+    let position = position.trim_start_matches("::").replace("::", ".");
+    let tag = world.component::<SerdeTag>().path().unwrap();
+    let tag = tag.trim_start_matches("::").replace("::", ".");
+
+    assert_eq!(json["name"], "player");
+    let components = json["components"].as_object().unwrap();
+    assert_eq!(components.len(), 2);
+    assert_eq!(
+        components[&position],
+        serde_json::json!({ "x": 1.0, "y": 2.0 })
+    );
+    assert_eq!(components[&tag], serde_json::Value::Null);
+}
+
+#[test]
+fn serde_entity_roundtrip() {
+    let world = World::new();
+    register(&world);
+
+    let e = world
+        .entity_named("player")
+        .set(unit())
+        .set(SerdePosition { x: 1.0, y: 2.0 })
+        .add(SerdeTag);
+
+    let json = serde_json::to_string(&e.serde_value()).unwrap();
+    let bytes = bincode::serialize(&e.serde_value()).unwrap();
+
+    for from_json in [true, false] {
+        let world = World::new();
+        register(&world);
+        register(&world);
+        world.component::<SerdeUnit>();
+        world.component::<SerdePosition>();
+        world.component::<SerdeTag>();
+
+        let e = world.entity();
+        if from_json {
+            e.serde_seed()
+                .deserialize(&mut serde_json::Deserializer::from_str(&json))
+                .unwrap();
+        } else {
+            e.serde_seed()
+                .deserialize(&mut bincode_deserializer(&bytes))
+                .unwrap();
+        }
+
+        assert_eq!(e.name(), "player");
+        assert!(e.has(SerdeTag));
+        e.get::<(&SerdeUnit, &SerdePosition)>(|(u, p)| {
+            assert_eq!(*u, unit());
+            assert_eq!(*p, SerdePosition { x: 1.0, y: 2.0 });
+        });
+    }
+}
@@ -0,0 +1,141 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+#[derive(Component, Clone, Debug, PartialEq)]
+struct Label(String);
+
+#[test]
+fn snapshot_restore_component_values() {
+    let world = World::new();
+
+    let e = world
+        .entity()
+        .set(Position { x: 1, y: 2 })
+        .set(Label("before".to_string()));
+
+    let snapshot = world.snapshot();
+    assert!(snapshot.contains(e));
+
+    e.set(Position { x: 10, y: 20 })
+        .set(Label("after".to_string()));
+
+    world.restore(&snapshot);
+
+    e.get::<(&Position, &Label)>(|(pos, label)| {
+        assert_eq!(pos.x, 1);
+        assert_eq!(pos.y, 2);
+        assert_eq!(label.0, "before");
+    });
+
+    // the snapshot can be restored more than once
+    e.set(Label("again".to_string()));
+    world.restore(&snapshot);
+    e.get::<&Label>(|label| assert_eq!(label.0, "before"));
+}
+
+#[test]
+fn snapshot_restore_added_and_removed_ids() {
+    let world = World::new();
+
+    let parent = world.entity_named("parent");
+    let e = world
+        .entity_named("child")
+        .child_of(parent)
+        .set(Position { x: 1, y: 2 })
+        .add(Tag);
+
+    let snapshot = world.snapshot();
+
+    e.remove(Position::id())
+        .remove(Tag)
+        .child_of(world.entity())
+        .set(Velocity { x: 1, y: 1 });
+
+    world.restore(&snapshot);
+
+    assert!(e.has(Position::id()));
+    assert!(e.has(Tag));
+    assert!(!e.has(Velocity::id()));
+    assert_eq!(e.parent().map(|p| p.id()), Some(parent.id()));
+    assert_eq!(world.lookup("parent::child").id(), e.id());
+}
+
+#[test]
+fn snapshot_restore_deletes_new_and_recreates_deleted_entities() {
+    let world = World::new();
+
+    let kept = world.entity_named("kept").set(Label("kept".to_string()));
+    let deleted = world.entity_named("deleted").set(Position { x: 3, y: 4 });
+
+    let snapshot = world.snapshot();
+
+    let created = world.entity_named("created").set(Position { x: 0, y: 0 });
+    deleted.destruct();
+    kept.set_name("renamed");
+
+    world.restore(&snapshot);
+
+    assert!(!world.is_alive(created));
+    assert!(world.is_alive(deleted));
+    let deleted = world.entity_from_id(deleted);
+    deleted.get::<&Position>(|pos| {
+        assert_eq!(pos.x, 3);
+        assert_eq!(pos.y, 4);
+    });
+    assert_eq!(deleted.name(), "deleted");
+    assert_eq!(kept.name(), "kept");
+    assert!(world.try_lookup("renamed").is_err());
+}
+
+#[test]
+fn snapshot_does_not_include_components_and_systems() {
+    let world = World::new();
+    world.component::<Position>();
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let snapshot = world.snapshot();
+    assert_eq!(snapshot.len(), 1);
+
+    let query = world.new_query::<&Position>();
+    let observer = world.observer::<flecs::OnSet, &Position>().each(|_| {});
+    world.restore(&snapshot);
+
+    assert!(observer.is_alive());
+    assert!(world.component::<Position>().is_alive());
+    assert_eq!(query.count(), 1);
+}
+
+#[test]
+fn snapshot_query_only_restores_matched_entities() {
+    let world = World::new();
+
+    let matched = world.entity().set(Position { x: 1, y: 1 });
+    let other = world.entity().set(Velocity { x: 1, y: 1 });
+
+    let query = world.new_query::<&Position>();
+    let snapshot = world.snapshot_query(&query);
+    assert_eq!(snapshot.len(), 1);
+    assert!(snapshot.contains(matched));
+    assert!(!snapshot.contains(other));
+
+    matched.set(Position { x: 5, y: 5 });
+    other.set(Velocity { x: 5, y: 5 });
+    let created = world.entity().set(Position { x: 0, y: 0 });
+
+    world.restore(&snapshot);
+
+    matched.get::<&Position>(|pos| assert_eq!(pos.x, 1));
+    other.get::<&Velocity>(|vel| assert_eq!(vel.x, 5));
+    assert!(created.is_alive());
+}
+
+#[test]
+#[should_panic(expected = "different world")]
+fn snapshot_restore_into_other_world_panics() {
+    let world = World::new();
+    world.entity().set(Position { x: 1, y: 2 });
+    let snapshot = world.snapshot();
+
+    let other = World::new();
+    other.restore(&snapshot);
+}
@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+#![allow(clippy::float_cmp)]
+use flecs_ecs::addons::stats::*;
+use flecs_ecs::prelude::*;
+
+use crate::common_test::*;
+
+#[test]
+fn world_stats_view_records_history() {
+    let world = World::new();
+    let mut stats = WorldStatsView::new();
+    assert_eq!(stats.sample_count(), 0);
+    assert_eq!(stats.entity_count().last(), 0.0);
+
+    for _ in 0..5 {
+        world.entity();
+        world.progress();
+        stats.record(&world);
+    }
+
+    assert_eq!(stats.sample_count(), 5);
+    let count = stats.entity_count();
+    assert_eq!(count.last() - count.min(5), 4.0);
+    assert_eq!(count.max(5), count.last());
+    assert_eq!(count.history().count(), 5);
+    let history: Vec<f32> = count.history().collect();
+    assert!(history.windows(2).all(|w| w[0] + 1.0 == w[1]));
+    assert_eq!(stats.frame_count().rate().avg(4), 1.0);
+    assert_eq!(stats.frame_count().total(), 5.0);
+}
+
+#[test]
+fn world_stats_view_window_wraps() {
+    let world = World::new();
+    let mut stats = WorldStatsView::new();
+
+    for _ in 0..(STAT_WINDOW + 10) {
+        world.progress();
+        stats.record(&world);
+    }
+
+    assert_eq!(stats.sample_count(), STAT_WINDOW);
+    assert_eq!(stats.frame_count().rate().history().count(), STAT_WINDOW);
+    assert_eq!(stats.frame_count().total(), (STAT_WINDOW + 10) as f64);
+}
+
+#[test]
+fn system_stats_view() {
+    let world = World::new();
+    world.entity().set(Position { x: 1, y: 2 });
+    world.entity().set(Position { x: 1, y: 2 });
+
+    let system = world.system::<&Position>().each(|_| {});
+    let mut stats = SystemStatsView::new(&world, system);
+
+    world.progress();
+    assert!(stats.record(&world));
+    assert_eq!(stats.matched_entity_count().last(), 2.0);
+    assert_eq!(stats.matched_table_count().last(), 1.0);
+    assert!(!stats.is_task());
+
+    let mut not_a_system = SystemStatsView::new(&world, world.entity());
+    assert!(!not_a_system.record(&world));
+    assert_eq!(not_a_system.sample_count(), 0);
+}
+
+#[test]
+fn pipeline_stats_view() {
+    let world = World::new();
+
+    let s1 = world.system::<()>().run(|mut it| while it.next() {});
+    let s2 = world
+        .system::<()>()
+        .kind(id::<flecs::pipeline::PostUpdate>())
+        .run(|mut it| while it.next() {});
+
+    world.progress();
+
+    let mut stats = PipelineStatsView::new(&world, world.get_pipeline());
+    assert!(stats.record(&world));
+
+    let systems: Vec<Entity> = stats.systems().collect();
+    assert_eq!(systems, vec![s1.id(), s2.id()]);
+    assert_eq!(stats.system_count(), 2);
+    assert!(stats.system(s1).is_some());
+    assert!(stats.system(world.entity()).is_none());
+    assert!(stats.sync_points().count() >= 1);
+}
+
+#[test]
+fn memory_stats_view() {
+    let world = World::new();
+    for _ in 0..100 {
+        world.entity().set(Position { x: 1, y: 2 });
+    }
+
+    let memory = MemoryStatsView::get(&world);
+    assert!(memory.entity_count() >= 100);
+    assert!(memory.table_component_bytes() >= 100 * core::mem::size_of::<Position>());
+    assert!(memory.entity_index_bytes() > 0);
+}
+
+#[test]
+fn system_stats_view_counts_skipped_frames() {
+    #[derive(Component)]
+    struct Paused;
+
+    let world = World::new();
+    let system = world
+        .system::<()>()
+        .run_if(|world| !world.has(Paused))
+        .run(|mut it| while it.next() {});
+    let unconditional = world.system::<()>().run(|mut it| while it.next() {});
+    let mut stats = PipelineStatsView::new(&world, world.get_pipeline());
+
+    world.progress();
+    stats.record(&world);
+    world.add(Paused);
+    for _ in 0..3 {
+        world.progress();
+        stats.record(&world);
+    }
+
+    let skipped = stats.system(system).unwrap().skipped();
+    assert_eq!(skipped.total(), 3.0);
+    assert_eq!(skipped.rate().last(), 1.0);
+    assert_eq!(stats.system(unconditional).unwrap().skipped().total(), 0.0);
+}
+
+#[test]
+fn stats_snapshot_without_module() {
+    let world = World::new();
+    world.system::<()>().run(|mut it| while it.next() {});
+    world.progress();
+
+    let snapshot = world.stats_snapshot();
+    assert_eq!(snapshot.world.sample_count(), 1);
+    assert!(snapshot.world.entity_count().last() > 0.0);
+    assert!(snapshot.pipeline.is_some());
+}
+
+#[test]
+fn stats_snapshot_with_module() {
+    let world = World::new();
+    world.import::<Stats>();
+
+    // the module measures every 1/60 s of world time, not every frame
+    for _ in 0..10 {
+        world.progress_time(0.001);
+    }
+    assert_eq!(world.stats_snapshot().world.sample_count(), 0);
+
+    // a frame of 0.25 s ends 15 intervals, the intervals without a frame repeat the last one
+    world.progress_time(0.25);
+    let snapshot = world.stats_snapshot();
+    assert_eq!(snapshot.world.sample_count(), 15);
+    let count = snapshot.world.entity_count();
+    assert!(count.last() > 0.0);
+    assert_eq!(count.min(STAT_WINDOW), count.last());
+    assert_eq!(count.avg(STAT_WINDOW), count.last());
+    assert_eq!(snapshot.world.frame_count().total(), 10.0);
+}
@@ -0,0 +1,237 @@
+#![allow(dead_code)]
+use crate::common_test::*;
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+fn run_log(log: &Log, name: &'static str) -> impl FnMut(TableIter) + 'static {
+    let log = log.clone();
+    move |_| log.borrow_mut().push(name)
+}
+
+fn schedule_names(world: &World) -> Vec<String> {
+    world
+        .pipeline_schedule(world.get_pipeline())
+        .unwrap()
+        .systems()
+        .map(|system| system.entity().entity_view(world).name())
+        .collect()
+}
+
+#[test]
+fn system_order_before() {
+    let world = World::new();
+    let log = Log::default();
+
+    let a = world.system_named::<()>("A").run(run_log(&log, "A"));
+    world.system_named::<()>("B").run(run_log(&log, "B"));
+    world
+        .system_named::<()>("C")
+        .before(a)
+        .run(run_log(&log, "C"));
+
+    // B has no constraints, and keeps its place before C
+    world.progress();
+    assert_eq!(*log.borrow(), ["B", "C", "A"]);
+    assert_eq!(schedule_names(&world), ["B", "C", "A"]);
+}
+
+#[test]
+fn system_order_after() {
+    let world = World::new();
+    let log = Log::default();
+
+    let a = world.system_named::<()>("A").run(run_log(&log, "A"));
+    let b = world
+        .system_named::<()>("B")
+        .after(a)
+        .run(run_log(&log, "B"));
+    // declared after A and B, but only constrained to run before B
+    world
+        .system_named::<()>("C")
+        .before(b)
+        .run(run_log(&log, "C"));
+    world.system_named::<()>("D").run(run_log(&log, "D"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["A", "C", "B", "D"]);
+}
+
+#[test]
+fn system_order_existing_systems_resorted() {
+    let world = World::new();
+    let log = Log::default();
+
+    let a = world.system_named::<()>("A").run(run_log(&log, "A"));
+    let b = world.system_named::<()>("B").run(run_log(&log, "B"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["A", "B"]);
+    log.borrow_mut().clear();
+
+    // forces B before A, after the pipeline was built
+    world
+        .system_named::<()>("C")
+        .after(b)
+        .before(a)
+        .run(run_log(&log, "C"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["B", "C", "A"]);
+}
+
+#[test]
+fn system_order_sets() {
+    let world = World::new();
+    let log = Log::default();
+
+    world
+        .system_named::<()>("Render")
+        .after(world.system_set("Physics"))
+        .run(run_log(&log, "Render"));
+    world
+        .system_named::<()>("Input")
+        .before(world.system_set("Physics"))
+        .run(run_log(&log, "Input"));
+    world
+        .system_named::<()>("Integrate")
+        .in_set("Physics")
+        .run(run_log(&log, "Integrate"));
+    world
+        .system_named::<()>("Collide")
+        .in_set("Physics")
+        .run(run_log(&log, "Collide"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["Input", "Integrate", "Collide", "Render"]);
+}
+
+#[test]
+fn system_order_set_named_like_system() {
+    let world = World::new();
+    let log = Log::default();
+
+    let physics = world
+        .system_named::<()>("Physics")
+        .run(run_log(&log, "Physics"));
+    let set = world.system_set("Physics");
+    assert_ne!(set.id(), physics.id());
+    assert_eq!(world.lookup("Physics"), physics.id());
+
+    // the system is not part of its namesake set
+    world
+        .system_named::<()>("Render")
+        .after(set)
+        .before(physics)
+        .run(run_log(&log, "Render"));
+    world
+        .system_named::<()>("Collide")
+        .in_set("Physics")
+        .run(run_log(&log, "Collide"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["Collide", "Render", "Physics"]);
+}
+
+#[test]
+fn system_order_only_within_phase() {
+    let world = World::new();
+    let log = Log::default();
+
+    let update = world
+        .system_named::<()>("Update")
+        .run(run_log(&log, "Update"));
+    // the phase wins over the constraint
+    world
+        .system_named::<()>("PostUpdate")
+        .kind(flecs::pipeline::PostUpdate::ID)
+        .before(update)
+        .run(run_log(&log, "PostUpdate"));
+    world
+        .system_named::<()>("PreUpdate")
+        .kind(flecs::pipeline::PreUpdate::ID)
+        .run(run_log(&log, "PreUpdate"));
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["PreUpdate", "Update", "PostUpdate"]);
+}
+
+#[test]
+fn system_order_custom_pipeline() {
+    #[derive(Component)]
+    struct Tag;
+
+    let world = World::new();
+    let log = Log::default();
+
+    let a = world
+        .system_named::<()>("A")
+        .kind(Tag)
+        .run(run_log(&log, "A"));
+    world
+        .system_named::<()>("B")
+        .kind(Tag)
+        .before(a)
+        .run(run_log(&log, "B"));
+
+    let pipeline = world
+        .pipeline()
+        .with(flecs::system::System::id())
+        .with(Tag::id())
+        .build();
+    world.set_pipeline(pipeline.entity());
+
+    world.progress();
+    assert_eq!(*log.borrow(), ["B", "A"]);
+}
+
+#[test]
+#[should_panic(expected = "system ordering cycle: A -> B -> A")]
+fn system_order_cycle_panics() {
+    let world = World::new();
+
+    let a = world.system_named::<()>("A").run(|_| {});
+    world.system_named::<()>("B").after(a).before(a).run(|_| {});
+}
+
+#[test]
+fn system_order_cycle_try_build() {
+    let world = World::new();
+    let log = Log::default();
+
+    let a = world
+        .system_named::<()>("A")
+        .in_set("Physics")
+        .run(run_log(&log, "A"));
+    let b = world
+        .system_named::<()>("B")
+        .after(world.system_set("Physics"))
+        .run(run_log(&log, "B"));
+
+    extern "C-unwind" fn noop_iter(_it: *mut flecs_ecs::sys::ecs_iter_t) {}
+
+    let desc = flecs_ecs::sys::ecs_system_desc_t {
+        entity: *world.entity_named("C").id(),
+        callback: Some(noop_iter),
+        ..Default::default()
+    };
+    let err = world
+        .system_builder_from_desc::<()>(desc)
+        .after(b)
+        .in_set("Physics")
+        .try_build()
+        .unwrap_err();
+    let FlecsError::Build { kind, message } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(kind, "system");
+    assert_eq!(message, "system ordering cycle: B -> C -> B");
+
+    // the system is not created, and the other constraints still hold
+    assert!(world.try_lookup("C").is_err());
+    world.progress();
+    assert_eq!(*log.borrow(), ["A", "B"]);
+    assert!(a.is_alive());
+}
@@ -3017,11 +3017,10 @@ fn lookup_and_update_ctx() {
     assert!(*e.id() != 0);
 
     let mut sys = world.system_from(e);
-    assert!(sys.context().is_null());
+    assert!(sys.context::<i32>().is_none());
 
-    let mut my_ctx: i32 = 42;
-    sys.set_context(&mut my_ctx as *mut i32 as *mut c_void);
-    assert!(sys.context() == &mut my_ctx as *mut i32 as *mut c_void);
+    sys.set_context(42i32);
+    assert_eq!(sys.context::<i32>(), Some(&42));
 }
 
 #[test]
@@ -3062,20 +3061,15 @@ fn set_group() {
 
     world.set(Count(0));
 
-    unsafe extern "C-unwind" fn group_by_grp_rel(
-        world: *mut flecs_ecs::sys::ecs_world_t,
-        table: *mut flecs_ecs::sys::ecs_table_t,
-        id: flecs_ecs::sys::ecs_id_t,
-        _ctx: *mut c_void,
-    ) -> u64 {
+    fn group_by_grp_rel(world: &World, table: Table, id: Id) -> u64 {
         let mut match_id: flecs_ecs::sys::ecs_id_t = 0;
-        // SAFETY: world and table pointers are valid, non-null pointers passed in by
-        // flecs from within a group_by callback invocation; ecs_search is a valid C API call.
+        // SAFETY: world and table are valid, passed in by flecs from within a group_by
+        // callback invocation; ecs_search is a valid C API call.
         unsafe {
             if flecs_ecs::sys::ecs_search(
-                world,
-                table,
-                flecs_ecs::sys::ecs_make_pair(id, flecs_ecs::sys::EcsWildcard),
+                world.world_ptr_mut(),
+                table.raw_table_ptr(),
+                flecs_ecs::sys::ecs_make_pair(*id, flecs_ecs::sys::EcsWildcard),
                 &mut match_id,
             ) != -1
             {
@@ -3090,7 +3084,7 @@ fn set_group() {
     let sys = world
         .system::<()>()
         .with((GroupRel::id(), flecs::Wildcard::ID))
-        .group_by_fn(GroupRel::id(), Some(group_by_grp_rel))
+        .group_by(GroupRel::id(), group_by_grp_rel)
         .run(move |mut it| {
             while it.next() {
                 for i in it.iter() {
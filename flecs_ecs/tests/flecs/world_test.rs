@@ -847,9 +847,9 @@ fn with_scope_nested() {
         assert_ne!(world.lookup("::P::C::GC"), 0u64);
     });
 
-    assert!(world.try_lookup("C").is_none());
-    assert!(world.try_lookup("GC").is_none());
-    assert!(world.try_lookup("C::GC").is_none());
+    assert!(world.try_lookup("C").is_err());
+    assert!(world.try_lookup("GC").is_err());
+    assert!(world.try_lookup("C::GC").is_err());
 
     let child = world.lookup("P::C");
     assert_ne!(child.id(), 0u64);
@@ -892,8 +892,8 @@ fn lookup_is_recursive_try_lookup_is_scope_only() {
     parent.scope(|world| {
         assert_eq!(world.lookup("LookupRoot"), root.id());
         assert_eq!(world.lookup_recursive("LookupRoot"), root.id());
-        assert!(world.try_lookup("LookupRoot").is_none());
-        assert!(world.try_lookup_recursive("LookupRoot").is_some());
+        assert!(world.try_lookup("LookupRoot").is_err());
+        assert!(world.try_lookup_recursive("LookupRoot").is_ok());
     });
 }
 
@@ -1020,8 +1020,8 @@ fn template_component_from_module_2_args() {
 
     let mid = m
         .try_lookup("TypeWithArgs<Foo,Bar>")
-        .or_else(|| m.try_lookup("TypeWithArgs<Foo, Bar>"));
-    assert!(mid.is_some());
+        .or_else(|_| m.try_lookup("TypeWithArgs<Foo, Bar>"));
+    assert!(mid.is_ok());
     assert_eq!(mid.unwrap().id(), tid);
 }
 
@@ -1605,7 +1605,7 @@ fn set_lookup_path() {
     });
 
     assert_eq!(world.lookup("Parent").id(), parent.id());
-    assert!(world.try_lookup("Child").is_none());
+    assert!(world.try_lookup("Child").is_err());
     assert_eq!(world.lookup("Parent::Child").id(), child_id);
 
     let old_path = world.set_lookup_path(parent.id());
@@ -1907,10 +1907,10 @@ fn world_mini() {
             COUNT.with(|c| c.set(c.get() + 1));
         });
 
-        assert!(world.try_lookup("flecs.system").is_none());
-        assert!(world.try_lookup("flecs.pipeline").is_none());
-        assert!(world.try_lookup("flecs.timer").is_none());
-        assert!(world.try_lookup("flecs.meta").is_none());
+        assert!(world.try_lookup("flecs.system").is_err());
+        assert!(world.try_lookup("flecs.pipeline").is_err());
+        assert!(world.try_lookup("flecs.timer").is_err());
+        assert!(world.try_lookup("flecs.meta").is_err());
     }
 
     assert_eq!(COUNT.with(core::cell::Cell::get), 1);
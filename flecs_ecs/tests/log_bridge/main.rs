@@ -0,0 +1,135 @@
+//! This test needs to be a separate process, since the OS API is process-global.
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+
+use flecs_ecs::prelude::*;
+use log::kv::{Key, VisitSource};
+
+struct CapturedRecord {
+    level: log::Level,
+    target: String,
+    file: Option<String>,
+    line: Option<u32>,
+    message: String,
+    flecs_level: Option<i64>,
+    indent: Option<i64>,
+}
+
+struct TestLogger(Mutex<Vec<CapturedRecord>>);
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        struct Visitor<'r> {
+            flecs_level: &'r mut Option<i64>,
+            indent: &'r mut Option<i64>,
+        }
+
+        impl<'kvs> VisitSource<'kvs> for Visitor<'_> {
+            fn visit_pair(
+                &mut self,
+                key: Key<'kvs>,
+                value: log::kv::Value<'kvs>,
+            ) -> Result<(), log::kv::Error> {
+                match key.as_str() {
+                    "flecs_level" => *self.flecs_level = value.to_i64(),
+                    "indent" => *self.indent = value.to_i64(),
+                    _ => {}
+                }
+                Ok(())
+            }
+        }
+
+        let mut flecs_level = None;
+        let mut indent = None;
+        record
+            .key_values()
+            .visit(&mut Visitor {
+                flecs_level: &mut flecs_level,
+                indent: &mut indent,
+            })
+            .unwrap();
+
+        self.0.lock().unwrap().push(CapturedRecord {
+            level: record.level(),
+            target: record.target().to_string(),
+            file: record.file().map(ToString::to_string),
+            line: record.line(),
+            message: record.args().to_string(),
+            flecs_level,
+            indent,
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+
+#[test]
+fn forward_log_to_log_crate_records() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    forward_log_to_log_crate().unwrap();
+
+    let _world = World::new();
+
+    // Hooks can no longer be installed once the OS API is initialized
+    assert_eq!(
+        forward_log_to_log_crate(),
+        Err(ecs_os_api::AddInitHookError::AlreadyInitialized)
+    );
+
+    set_log_level(1);
+    unsafe {
+        flecs_ecs::sys::ecs_log_(
+            -2,
+            c"warn.c".as_ptr(),
+            10,
+            c"%s".as_ptr(),
+            c"careful".as_ptr(),
+        );
+        flecs_ecs::sys::ecs_log_push_(0);
+        flecs_ecs::sys::ecs_log_(1, c"dbg.c".as_ptr(), 20, c"%s".as_ptr(), c"nested".as_ptr());
+        flecs_ecs::sys::ecs_log_pop_(0);
+        flecs_ecs::sys::ecs_log_(
+            -3,
+            core::ptr::null(),
+            0,
+            c"%s".as_ptr(),
+            c"#[red]failed".as_ptr(),
+        );
+    }
+    set_log_level(-1);
+
+    let records = LOGGER.0.lock().unwrap();
+    let find = |message: &str| {
+        records
+            .iter()
+            .find(|r| r.message == message)
+            .unwrap_or_else(|| panic!("no record for {message:?}"))
+    };
+
+    let warn = find("careful");
+    assert_eq!(warn.level, log::Level::Warn);
+    assert_eq!(warn.target, "flecs");
+    assert_eq!(warn.file.as_deref(), Some("warn.c"));
+    assert_eq!(warn.line, Some(10));
+    assert_eq!(warn.flecs_level, Some(-2));
+    assert_eq!(warn.indent, Some(0));
+
+    let nested = find("nested");
+    assert_eq!(nested.level, log::Level::Debug);
+    assert_eq!(nested.flecs_level, Some(1));
+    assert_eq!(nested.indent, Some(1));
+
+    // color tags are stripped, and no escape codes are emitted
+    let error = find("failed");
+    assert_eq!(error.level, log::Level::Error);
+    assert_eq!(error.file, None);
+}
@@ -0,0 +1,84 @@
+//! This test needs to be a separate process, since the task pool is process-global.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use flecs_ecs::prelude::*;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+#[derive(Component)]
+struct Updates(u32);
+
+/// Counts the tasks that flecs starts, and runs them on a [`StdTaskPool`].
+struct CountingPool {
+    pool: StdTaskPool,
+    spawned: AtomicUsize,
+}
+
+impl TaskPool for CountingPool {
+    fn spawn(&self, task: task_pool::Task) {
+        self.spawned.fetch_add(1, Ordering::SeqCst);
+        self.pool.spawn(task);
+    }
+}
+
+#[test]
+fn task_pool() {
+    let pool = Arc::new(CountingPool {
+        pool: StdTaskPool::new(3),
+        spawned: AtomicUsize::new(0),
+    });
+    assert_eq!(pool.pool.threads(), 3);
+    set_task_pool(pool.clone());
+
+    let world = World::new();
+    for _ in 0..64 {
+        world.entity().set(Updates(0));
+    }
+
+    let names = Arc::new(Mutex::new(HashSet::new()));
+    let names_system = names.clone();
+    world.system::<&mut Updates>().par_each(move |updates| {
+        updates.0 += 1;
+        let name = std::thread::current()
+            .name()
+            .unwrap_or_default()
+            .to_string();
+        names_system.lock().unwrap().insert(name);
+    });
+
+    world.set_task_threads(4);
+    world.progress();
+    world.progress();
+
+    // the main thread runs one of the stages, the pool runs the others
+    assert_eq!(pool.spawned.load(Ordering::SeqCst), 6);
+    assert!(
+        names
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|name| name.starts_with("flecs-pool-"))
+    );
+
+    let mut updated = 0;
+    world.query::<&Updates>().build().each(|updates| {
+        if updates.0 == 2 {
+            updated += 1;
+        }
+    });
+    assert_eq!(updated, 64);
+
+    // a pool installed after the OS API is initialized replaces the previous one
+    let replacement = Arc::new(CountingPool {
+        pool: StdTaskPool::new(1),
+        spawned: AtomicUsize::new(0),
+    });
+    set_task_pool(replacement.clone());
+    world.set_task_threads(2);
+    world.progress();
+    assert_eq!(replacement.spawned.load(Ordering::SeqCst), 1);
+    assert_eq!(pool.spawned.load(Ordering::SeqCst), 6);
+}
@@ -0,0 +1,127 @@
+//! This test needs to be a separate process, since the OS API is process-global.
+#![cfg(feature = "tracing")]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use flecs_ecs::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+#[derive(Default)]
+struct CapturedEvent {
+    level: Option<Level>,
+    target: String,
+    message: String,
+    file: String,
+    line: u64,
+    flecs_level: i64,
+    indent: i64,
+}
+
+impl Visit for CapturedEvent {
+    fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "file" {
+            self.file = value.to_string();
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        match field.name() {
+            "flecs_level" => self.flecs_level = value,
+            "indent" => self.indent = value,
+            _ => {}
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "line" {
+            self.line = value;
+        }
+    }
+}
+
+struct TestSubscriber(Arc<Mutex<Vec<CapturedEvent>>>);
+
+impl Subscriber for TestSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut captured = CapturedEvent {
+            level: Some(*event.metadata().level()),
+            target: event.metadata().target().to_string(),
+            ..Default::default()
+        };
+        event.record(&mut captured);
+        self.0.lock().unwrap().push(captured);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn forward_log_to_tracing_events() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::set_global_default(TestSubscriber(events.clone())).unwrap();
+
+    forward_log_to_tracing().unwrap();
+
+    let _world = World::new();
+
+    set_log_level(1);
+    unsafe {
+        flecs_ecs::sys::ecs_log_(
+            -2,
+            c"warn.c".as_ptr(),
+            10,
+            c"%s".as_ptr(),
+            c"careful".as_ptr(),
+        );
+        flecs_ecs::sys::ecs_log_push_(0);
+        flecs_ecs::sys::ecs_log_(1, c"dbg.c".as_ptr(), 20, c"%s".as_ptr(), c"nested".as_ptr());
+        flecs_ecs::sys::ecs_log_pop_(0);
+    }
+    set_log_level(-1);
+
+    let events = events.lock().unwrap();
+    let find = |message: &str| {
+        events
+            .iter()
+            .find(|e| e.message == message)
+            .unwrap_or_else(|| panic!("no event for {message:?}"))
+    };
+
+    let warn = find("careful");
+    assert_eq!(warn.level, Some(Level::WARN));
+    assert_eq!(warn.target, "flecs");
+    assert_eq!(warn.file, "warn.c");
+    assert_eq!(warn.line, 10);
+    assert_eq!(warn.flecs_level, -2);
+    assert_eq!(warn.indent, 0);
+
+    let nested = find("nested");
+    assert_eq!(nested.level, Some(Level::DEBUG));
+    assert_eq!(nested.flecs_level, 1);
+    assert_eq!(nested.indent, 1);
+}
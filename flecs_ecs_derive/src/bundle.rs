@@ -0,0 +1,89 @@
+// Expansion of the `Bundle` derive, which implements `Bundle` for a struct of components.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Member, Result};
+
+fn is_nested_bundle(field: &syn::Field) -> Result<bool> {
+    let mut nested = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("flecs") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bundle") {
+                nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported Bundle field option, expected `bundle`"))
+            }
+        })?;
+    }
+    Ok(nested)
+}
+
+pub(crate) fn expand_bundle_derive(input: DeriveInput) -> TokenStream {
+    match expand(&input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+            Fields::Unit => Vec::new(),
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "Bundle can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut ids = Vec::with_capacity(fields.len());
+    let mut inserts = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let ty = &field.ty;
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        };
+        if is_nested_bundle(field)? {
+            ids.push(quote! {
+                <#ty as flecs_ecs::core::Bundle>::for_each_id(world, func);
+            });
+            inserts.push(quote! {
+                flecs_ecs::core::Bundle::insert_into(self.#member, writer);
+            });
+        } else {
+            ids.push(quote! {
+                func(<#ty as flecs_ecs::core::ComponentId>::entity_id(world));
+            });
+            inserts.push(quote! {
+                writer.write::<#ty>(self.#member);
+            });
+        }
+    }
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics flecs_ecs::core::Bundle for #name #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn for_each_id(world: flecs_ecs::core::WorldRef<'_>, func: &mut dyn FnMut(u64)) {
+                #(#ids)*
+            }
+
+            #[allow(unused_variables)]
+            fn insert_into(self, writer: &mut flecs_ecs::core::BundleWriter<'_>) {
+                #(#inserts)*
+            }
+        }
+    })
+}
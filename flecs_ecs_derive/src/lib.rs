@@ -16,9 +16,11 @@ use crate::tuples::Tuples;
 #[cfg(feature = "flecs_query_rust_traits")]
 use syn::Ident;
 
+mod bundle;
 mod component;
 mod dsl;
 mod extern_abi;
+mod query_data;
 #[cfg(feature = "flecs_query_rust_traits")]
 mod rust_traits;
 mod tuples;
@@ -72,6 +74,131 @@ pub fn component_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
     component::expand_component_derive(input).into()
 }
 
+/// `Bundle` macro for grouping components that are set on an entity together.
+///
+/// Every field must be a component or tag. Fields annotated with `#[flecs(bundle)]` are
+/// bundles themselves, and their components are inserted as part of the outer bundle. A
+/// component may appear only once across a bundle and its nested bundles.
+///
+/// Bundles are set with `EntityView::set_bundle` or `BulkEntityBuilder::set_bundle_iter`, which
+/// move each entity to its final table in a single archetype transition, and removed with
+/// `Bundle::remove_from`.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Bundle)]
+/// struct Movable {
+///     position: Position,
+///     velocity: Velocity,
+/// }
+///
+/// #[derive(Bundle)]
+/// struct PlayerBundle {
+///     #[flecs(bundle)]
+///     movable: Movable,
+///     player: Player,
+/// }
+///
+/// let world = World::new();
+///
+/// let player = world.entity().set_bundle(PlayerBundle {
+///     movable: Movable {
+///         position: Position { x: 0.0, y: 0.0 },
+///         velocity: Velocity { x: 1.0, y: 0.0 },
+///     },
+///     player: Player,
+/// });
+///
+/// assert!(player.has(Velocity::id()));
+/// ```
+#[proc_macro_derive(Bundle, attributes(flecs))]
+pub fn bundle_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bundle::expand_bundle_derive(input).into()
+}
+
+/// `QueryData` macro for querying components through a named struct instead of a tuple.
+///
+/// The struct must have a single lifetime parameter, and every field must be a component
+/// reference that can appear in a query tuple: `&'a T`, `&'a mut T`, `Option<&'a T>` or
+/// `Option<&'a mut T>`. Fields become query terms in declaration order, and the struct can be
+/// used anywhere a query tuple can, such as `world.query::<Move>()` or `world.system::<Move>()`.
+///
+/// # Field attributes
+///
+/// - `#[flecs(up)]` / `#[flecs(up(Rel))]`: match the component on a parent, traversing
+///   `ChildOf` or the given relationship.
+/// - `#[flecs(cascade)]` / `#[flecs(cascade(Rel))]`: like `up`, but iterates results breadth
+///   first so parents are visited before their children.
+/// - `#[flecs(target = expr)]`: match the pair `(T, expr)` where `T` is the field component,
+///   for example `#[flecs(target = Apples)]` or `#[flecs(target = flecs::Wildcard)]`.
+/// - `#[flecs(filter)]`: match and read the component without it counting towards change
+///   detection.
+///
+/// # Example
+///
+/// ```
+/// use flecs_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(QueryData)]
+/// struct Move<'a> {
+///     pos: &'a mut Position,
+///     vel: &'a Velocity,
+///     #[flecs(up)]
+///     parent: Option<&'a Position>,
+/// }
+///
+/// let world = World::new();
+///
+/// let parent = world.entity().set(Position { x: 10.0, y: 0.0 });
+/// world
+///     .entity()
+///     .child_of(parent)
+///     .set(Position { x: 0.0, y: 0.0 })
+///     .set(Velocity { x: 1.0, y: 2.0 });
+///
+/// world.new_query::<Move>().each(|m| {
+///     let offset = m.parent.map_or(0.0, |p| p.x);
+///     m.pos.x += m.vel.x + offset;
+///     m.pos.y += m.vel.y;
+/// });
+/// ```
+#[proc_macro_derive(QueryData, attributes(flecs))]
+pub fn query_data_derive(input: ProcMacroTokenStream) -> ProcMacroTokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    query_data::expand_query_data_derive(input).into()
+}
+
 /// Function-like macro for defining a query with `QueryBuilder`.
 ///
 /// Usage: `query!("query_name", world, ... terms ...)`.
@@ -0,0 +1,342 @@
+// Expansion of the `QueryData` derive, which implements `QueryTuple` for a struct
+// of component references by delegating to the equivalent tuple of its field types.
+
+use proc_macro2::{Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Expr, Fields, GenericParam, Lifetime, Member, Result, Type};
+
+#[derive(Default)]
+struct FieldTerm {
+    up: bool,
+    cascade: bool,
+    trav: Option<Expr>,
+    target: Option<Expr>,
+    filter: bool,
+}
+
+impl FieldTerm {
+    fn is_default(&self) -> bool {
+        !self.up && !self.cascade && self.trav.is_none() && self.target.is_none() && !self.filter
+    }
+
+    fn parse(field: &syn::Field) -> Result<Self> {
+        let mut term = FieldTerm::default();
+        for attr in &field.attrs {
+            if !attr.path().is_ident("flecs") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("up") || meta.path.is_ident("cascade") {
+                    if meta.path.is_ident("up") {
+                        term.up = true;
+                    } else {
+                        term.cascade = true;
+                    }
+                    if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        term.trav = Some(content.parse()?);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("target") {
+                    term.target = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("filter") {
+                    term.filter = true;
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported QueryData field option, expected `up`, `cascade`, `target = ...` or `filter`",
+                    ))
+                }
+            })?;
+        }
+        if term.up && term.cascade {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`up` and `cascade` cannot be combined, `cascade` already traverses upwards",
+            ));
+        }
+        Ok(term)
+    }
+
+    fn expand(&self, world: &TokenStream) -> TokenStream {
+        let up = self.up;
+        let cascade = self.cascade;
+        let trav = match &self.trav {
+            Some(expr) => quote! { *flecs_ecs::core::IntoEntity::into_entity(#expr, #world) },
+            None => quote! { 0 },
+        };
+        let target = match &self.target {
+            Some(expr) => quote! { *flecs_ecs::core::IntoEntity::into_entity(#expr, #world) },
+            None => quote! { 0 },
+        };
+        let filter = self.filter;
+        quote! {
+            flecs_ecs::core::QueryDataTerm {
+                up: #up,
+                cascade: #cascade,
+                trav: #trav,
+                target: #target,
+                filter: #filter,
+            }
+        }
+    }
+}
+
+/// Replaces every occurrence of `lifetime` in `tokens` with `'static`.
+fn replace_lifetime(tokens: TokenStream, lifetime: &Ident) -> TokenStream {
+    let mut out = Vec::new();
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(token) = iter.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = iter.peek()
+                    && ident == lifetime
+                {
+                    let span = ident.span();
+                    iter.next();
+                    let mut apostrophe = Punct::new('\'', Spacing::Joint);
+                    apostrophe.set_span(punct.span());
+                    out.push(TokenTree::Punct(apostrophe));
+                    out.push(TokenTree::Ident(Ident::new("static", span)));
+                } else {
+                    out.push(TokenTree::Punct(punct));
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    replace_lifetime(group.stream(), lifetime),
+                );
+                new_group.set_span(group.span());
+                out.push(TokenTree::Group(new_group));
+            }
+            other => out.push(other),
+        }
+    }
+    out.into_iter().collect()
+}
+
+pub(crate) fn expand_query_data_derive(input: DeriveInput) -> TokenStream {
+    match expand(&input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "QueryData can only be derived for structs",
+            ));
+        }
+    };
+    let fields: Vec<&syn::Field> = match fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "QueryData requires at least one component field",
+        ));
+    }
+    if fields.len() > 32 {
+        return Err(syn::Error::new_spanned(
+            name,
+            "QueryData supports at most 32 component fields",
+        ));
+    }
+
+    let mut lifetimes = input.generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(param) => Ok(&param.lifetime),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "QueryData structs can only be generic over a single lifetime",
+        )),
+    });
+    let lifetime: &Lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(lifetime), None) => lifetime?,
+        (None, _) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "QueryData structs must have a lifetime parameter for their component references, e.g. `struct Move<'a>`",
+            ));
+        }
+        (Some(_), Some(other)) => {
+            other?;
+            return Err(syn::Error::new_spanned(
+                &input.generics,
+                "QueryData structs can only be generic over a single lifetime",
+            ));
+        }
+    };
+
+    let static_types: Vec<TokenStream> = fields
+        .iter()
+        .map(|field| {
+            let ty: &Type = &field.ty;
+            replace_lifetime(quote! { #ty }, &lifetime.ident)
+        })
+        .collect();
+    let terms = fields
+        .iter()
+        .map(|field| FieldTerm::parse(field))
+        .collect::<Result<Vec<_>>>()?;
+
+    let members: Vec<Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        })
+        .collect();
+    let vars: Vec<Ident> = (0..fields.len())
+        .map(|i| format_ident!("__field_{}", i))
+        .collect();
+
+    let count = fields.len();
+    let count_i32 = count as i32;
+    let tuple = quote! { (#(#static_types,)*) };
+
+    let populate_terms = static_types.iter().zip(&terms).map(|(ty, term)| {
+        let modifiers = (!term.is_default()).then(|| {
+            let term = term.expand(&quote! { query.world() });
+            quote! {
+                let modifiers = #term;
+                modifiers.apply(query.current_term_mut());
+            }
+        });
+        quote! {
+            <#ty as flecs_ecs::core::QueryTuple>::populate(query);
+            #modifiers
+        }
+    });
+
+    let register_terms = static_types.iter().zip(&terms).map(|(ty, term)| {
+        let modifiers = (!term.is_default()).then(|| {
+            let term =
+                term.expand(&quote! { unsafe { flecs_ecs::core::WorldRef::from_ptr(world) } });
+            quote! {
+                let modifiers = #term;
+                modifiers.apply(&mut terms[*index - 1]);
+            }
+        });
+        quote! {
+            <#ty as flecs_ecs::core::QueryTuple>::register_ids_descriptor_at(world, terms, index);
+            #modifiers
+        }
+    });
+
+    let (safety_param, safety_arg) = if cfg!(feature = "flecs_safety_locks") {
+        (
+            quote! { table_records: &mut [flecs_ecs::core::TableColumnSafety], },
+            quote! { table_records, },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
+    let tuple_lifetime = Lifetime::new("'__w", Span::call_site());
+
+    Ok(quote! {
+        impl flecs_ecs::core::QueryTuple for #name<'static> {
+            type Pointers = flecs_ecs::core::QueryDataPointers<Self, #count>;
+            type TupleType<#tuple_lifetime> = #name<#tuple_lifetime>;
+            const CONTAINS_ANY_TAG_TERM: bool = <#tuple as flecs_ecs::core::QueryTuple>::CONTAINS_ANY_TAG_TERM;
+            const IS_SPARSE_QUERY: bool = <#tuple as flecs_ecs::core::QueryTuple>::IS_SPARSE_QUERY;
+            const COUNT: i32 = #count_i32;
+            const COUNT_IMMUTABLE: usize = <#tuple as flecs_ecs::core::QueryTuple>::COUNT_IMMUTABLE;
+            const COUNT_MUTABLE: usize = <#tuple as flecs_ecs::core::QueryTuple>::COUNT_MUTABLE;
+            const COUNT_OPTIONAL_IMMUTABLE: usize = <#tuple as flecs_ecs::core::QueryTuple>::COUNT_OPTIONAL_IMMUTABLE;
+            const COUNT_OPTIONAL_MUTABLE: usize = <#tuple as flecs_ecs::core::QueryTuple>::COUNT_OPTIONAL_MUTABLE;
+
+            fn populate<'__a>(query: &mut impl flecs_ecs::core::QueryBuilderImpl<'__a>) {
+                #(#populate_terms)*
+            }
+
+            #[allow(clippy::not_unsafe_ptr_arg_deref)]
+            fn register_ids_descriptor_at(
+                world: *mut flecs_ecs::sys::ecs_world_t,
+                terms: &mut [flecs_ecs::sys::ecs_term_t],
+                index: &mut usize,
+            ) {
+                #(#register_terms)*
+            }
+
+            #[inline(always)]
+            fn populate_array_ptrs(
+                it: &flecs_ecs::sys::ecs_iter_t,
+                components: &mut [*mut u8],
+                is_ref: &mut [bool],
+                is_row: &mut [bool],
+                indexes: &mut [i8],
+                #safety_param
+            ) -> flecs_ecs::core::IsAnyArray {
+                <#tuple as flecs_ecs::core::QueryTuple>::populate_array_ptrs(
+                    it, components, is_ref, is_row, indexes, #safety_arg
+                )
+            }
+
+            #[inline(always)]
+            fn populate_self_array_ptrs(
+                it: &flecs_ecs::sys::ecs_iter_t,
+                components: &mut [*mut u8],
+                #safety_param
+            ) {
+                <#tuple as flecs_ecs::core::QueryTuple>::populate_self_array_ptrs(
+                    it, components, #safety_arg
+                );
+            }
+
+            #[inline(always)]
+            fn create_tuple(array_components: &[*mut u8], index: usize) -> Self::TupleType<'_> {
+                let (#(#vars,)*) =
+                    <#tuple as flecs_ecs::core::QueryTuple>::create_tuple(array_components, index);
+                #name { #(#members: #vars,)* }
+            }
+
+            #[inline(always)]
+            fn create_tuple_with_ref<'__a>(
+                array_components: &'__a [*mut u8],
+                is_ref_array_components: &[bool],
+                index: usize,
+            ) -> Self::TupleType<'__a> {
+                let (#(#vars,)*) = <#tuple as flecs_ecs::core::QueryTuple>::create_tuple_with_ref(
+                    array_components,
+                    is_ref_array_components,
+                    index,
+                );
+                #name { #(#members: #vars,)* }
+            }
+
+            #[inline(always)]
+            fn create_tuple_with_row<'__a>(
+                iter: &flecs_ecs::sys::ecs_iter_t,
+                array_components: &'__a mut [*mut u8],
+                is_ref_array_components: &[bool],
+                is_row_array_components: &[bool],
+                indexes_array_components: &[i8],
+                index_row_entity: usize,
+            ) -> Self::TupleType<'__a> {
+                let (#(#vars,)*) = <#tuple as flecs_ecs::core::QueryTuple>::create_tuple_with_row(
+                    iter,
+                    array_components,
+                    is_ref_array_components,
+                    is_row_array_components,
+                    indexes_array_components,
+                    index_row_entity,
+                );
+                #name { #(#members: #vars,)* }
+            }
+        }
+    })
+}
@@ -273,6 +273,9 @@ fn main() {
         #[cfg(feature = "flecs_script")]
         build.define("FLECS_SCRIPT", None);
 
+        #[cfg(feature = "flecs_script_math")]
+        build.define("FLECS_SCRIPT_MATH", None);
+
         #[cfg(feature = "flecs_stats")]
         build.define("FLECS_STATS", None);
 
@@ -8238,6 +8238,10 @@ unsafe extern "C-unwind" {
     #[doc = "Script module import function.\n Usage:\n @code\n ECS_IMPORT(world, FlecsScript)\n @endcode\n\n @param world The world."]
     pub fn FlecsScriptImport(world: *mut ecs_world_t);
 }
+unsafe extern "C-unwind" {
+    #[doc = "Script math import function.\n Usage:\n @code\n ECS_IMPORT(world, FlecsScriptMath)\n @endcode\n\n @param world The world."]
+    pub fn FlecsScriptMathImport(world: *mut ecs_world_t);
+}
 unsafe extern "C" {
     #[doc = "< Component ID for EcsDocDescription."]
     pub static FLECS_IDEcsDocDescriptionID_: ecs_entity_t;
@@ -9815,6 +9819,92 @@ unsafe extern "C-unwind" {
         size: usize,
     ) -> ecs_rust_set_t;
 }
+unsafe extern "C-unwind" {
+    #[doc = "Adds ids to an entity in a single table move, without constructing the new\n components. Returns false without adding anything if the world is deferred."]
+    pub fn ecs_rust_add_ids_emplace(
+        world: *mut ecs_world_t,
+        entity: ecs_entity_t,
+        ids: *const ecs_id_t,
+        count: i32,
+    ) -> bool;
+}
+unsafe extern "C-unwind" {
+    #[doc = "Same as ecs_script_vars_init, but the scope allocates from a stack and an\n allocator of its own instead of the ones of the stage, so root scopes can be\n freed in any order. Must be freed with ecs_rust_script_vars_fini."]
+    pub fn ecs_rust_script_vars_init(world: *mut ecs_world_t) -> *mut ecs_script_vars_t;
+}
+unsafe extern "C-unwind" {
+    #[doc = "Frees a root scope created with ecs_rust_script_vars_init."]
+    pub fn ecs_rust_script_vars_fini(vars: *mut ecs_script_vars_t);
+}
+#[doc = "Invoked for each REST request before the builtin endpoints. Returns true if\n the request was handled."]
+pub type ecs_rust_rest_route_action_t = ::core::option::Option<
+    unsafe extern "C-unwind" fn(
+        world: *mut ecs_world_t,
+        req: *const ecs_http_request_t,
+        reply: *mut ecs_http_reply_t,
+    ) -> bool,
+>;
+unsafe extern "C-unwind" {
+    #[doc = "Routes the requests of all REST servers of the world through action first,\n including servers that are created later."]
+    pub fn ecs_rust_rest_enable_routes(world: *mut ecs_world_t, action: ecs_rust_rest_route_action_t);
+}
+unsafe extern "C-unwind" {
+    #[doc = "Handles a REST request in-process, without opening a socket. The reply must\n be freed by the caller."]
+    pub fn ecs_rust_rest_request(
+        world: *mut ecs_world_t,
+        method: *const ::core::ffi::c_char,
+        path: *const ::core::ffi::c_char,
+        body: *const ::core::ffi::c_char,
+        reply_out: *mut ecs_http_reply_t,
+    ) -> ::core::ffi::c_int;
+}
+pub const ecs_rust_sync_reason_t_EcsRustSyncNone: ecs_rust_sync_reason_t = 0;
+#[doc = "< A system accesses ids that were written to the stage."]
+pub const ecs_rust_sync_reason_t_EcsRustSyncStaged: ecs_rust_sync_reason_t = 1;
+#[doc = "< A system switches between single and multithreaded."]
+pub const ecs_rust_sync_reason_t_EcsRustSyncMultiThreaded: ecs_rust_sync_reason_t = 2;
+#[doc = "< A system runs in immediate mode, or leaves it."]
+pub const ecs_rust_sync_reason_t_EcsRustSyncImmediate: ecs_rust_sync_reason_t = 3;
+#[doc = "Why the pipeline merges commands before an operation."]
+pub type ecs_rust_sync_reason_t = ::core::ffi::c_uint;
+#[doc = "A run of systems in a pipeline schedule. Commands are merged after each\n operation. For a staged merge, sync_id_offset and sync_id_count select the\n ids that caused it."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ecs_rust_pipeline_op_t {
+    pub offset: i32,
+    pub count: i32,
+    pub multi_threaded: bool,
+    pub immediate: bool,
+    pub sync_reason: ecs_rust_sync_reason_t,
+    pub sync_id_offset: i32,
+    pub sync_id_count: i32,
+}
+unsafe extern "C-unwind" {
+    #[doc = "Builds the schedule of a pipeline, and copies its operations, the systems\n they run and the ids that caused the merges between them. The arrays must be\n freed by the caller with ecs_os_free. Must not be called while a frame is in\n progress. Returns -1 if the entity is not a pipeline, and -2 if the reasons\n of the merges could not be matched with the operations of the pipeline."]
+    pub fn ecs_rust_pipeline_schedule(
+        world: *mut ecs_world_t,
+        pipeline: ecs_entity_t,
+        ops_out: *mut *mut ecs_rust_pipeline_op_t,
+        op_count_out: *mut i32,
+        systems_out: *mut *mut ecs_entity_t,
+        system_count_out: *mut i32,
+        sync_ids_out: *mut *mut ecs_id_t,
+        sync_id_count_out: *mut i32,
+    ) -> ::core::ffi::c_int;
+}
+#[doc = "Invoked by ecs_rust_progress once the frame has begun, before the pipeline\n runs. The world is not in readonly mode."]
+pub type ecs_rust_frame_action_t = ::core::option::Option<
+    unsafe extern "C-unwind" fn(world: *mut ecs_world_t, delta_time: f32, ctx: *mut ::core::ffi::c_void),
+>;
+unsafe extern "C-unwind" {
+    #[doc = "Same as ecs_progress, but invokes action after the startup systems of the\n first frame have run and before the pipeline runs."]
+    pub fn ecs_rust_progress(
+        world: *mut ecs_world_t,
+        user_delta_time: f32,
+        action: ecs_rust_frame_action_t,
+        ctx: *mut ::core::ffi::c_void,
+    ) -> bool;
+}
 unsafe extern "C-unwind" {
     #[doc = "Fast path for compile-time-known sparse / dont_fragment components without\n the (OnInstantiate, Inherit) trait. Mirrors ecs_get_sparse_id() but returns\n an ecs_get_ptr_t so lock-target info is available under\n FLECS_MUT_ALIAS_LOCKS."]
     pub fn ecs_rust_get_sparse_id(
@@ -389,3 +389,41 @@ impl Default for crate::ecs_iter_to_json_desc_t {
         }
     }
 }
+
+#[cfg(feature = "flecs_script")]
+impl Default for crate::ecs_function_desc_t {
+    fn default() -> Self {
+        Self {
+            name: core::ptr::null(),
+            parent: Default::default(),
+            params: [crate::ecs_script_parameter_t {
+                name: core::ptr::null(),
+                type_: 0,
+            }; crate::FLECS_SCRIPT_FUNCTION_ARGS_MAX as usize],
+            return_type: Default::default(),
+            callback: Default::default(),
+            vector_callbacks: Default::default(),
+            ctx: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[cfg(feature = "flecs_script")]
+impl Default for crate::ecs_expr_eval_desc_t {
+    fn default() -> Self {
+        Self {
+            name: core::ptr::null(),
+            expr: core::ptr::null(),
+            vars: core::ptr::null(),
+            type_: Default::default(),
+            lookup_action: Default::default(),
+            lookup_ctx: core::ptr::null_mut(),
+            disable_folding: Default::default(),
+            disable_dynamic_variable_binding: Default::default(),
+            allow_unresolved_identifiers: Default::default(),
+            runtime: core::ptr::null_mut(),
+            script_visitor: core::ptr::null_mut(),
+            unresolved_identifier_action: Default::default(),
+        }
+    }
+}
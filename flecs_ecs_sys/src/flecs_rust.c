@@ -325,6 +325,166 @@ done:
 error:
     return (ecs_rust_set_t){0};
 }
+/* Like flecs_table_move but never constructs the components that are added to
+ * the entity. The caller must initialize them before they are read. */
+static
+void flecs_rust_table_move_emplace(
+    ecs_world_t *world,
+    ecs_entity_t entity,
+    ecs_table_t *dst_table,
+    int32_t dst_index,
+    ecs_table_t *src_table,
+    int32_t src_index)
+{
+    ecs_assert(!dst_table->_->lock, ECS_LOCKED_STORAGE, FLECS_LOCKED_STORAGE_MSG("move"));
+    ecs_assert(!src_table->_->lock, ECS_LOCKED_STORAGE, FLECS_LOCKED_STORAGE_MSG("move"));
+
+    if (!((dst_table->flags | src_table->flags) & (EcsTableIsComplex|EcsTableHasIsA))) {
+        flecs_table_fast_move(dst_table, dst_index, src_table, src_index);
+        return;
+    }
+
+    flecs_table_update_overrides(world, dst_table);
+
+    flecs_table_move_bitset_columns(
+        dst_table, dst_index, src_table, src_index, 1, false);
+
+    bool use_move_dtor = ecs_table_count(src_table) == (src_index + 1);
+
+    int32_t i_new = 0, dst_column_count = dst_table->column_count;
+    int32_t i_old = 0, src_column_count = src_table->column_count;
+
+    ecs_column_t *src_columns = src_table->data.columns;
+    ecs_column_t *dst_columns = dst_table->data.columns;
+
+    for (; (i_new < dst_column_count) && (i_old < src_column_count); ) {
+        ecs_column_t *dst_column = &dst_columns[i_new];
+        ecs_column_t *src_column = &src_columns[i_old];
+        ecs_id_t dst_id = flecs_column_id(dst_table, i_new);
+        ecs_id_t src_id = flecs_column_id(src_table, i_old);
+
+        if (dst_id == src_id) {
+            ecs_type_info_t *ti = dst_column->ti;
+            int32_t size = ti->size;
+            void *dst = ECS_ELEM(dst_column->data, size, dst_index);
+            void *src = ECS_ELEM(src_column->data, size, src_index);
+
+            if (use_move_dtor || !ti->hooks.move_ctor) {
+                flecs_type_info_ctor_move_dtor(dst, src, 1, ti);
+            } else {
+                flecs_type_info_move_ctor(dst, src, 1, ti);
+            }
+        } else if (dst_id < src_id) {
+            flecs_table_invoke_add_hooks(world, dst_table,
+                i_new, &entity, dst_index, 1, false);
+        } else {
+            bool dtor = use_move_dtor || (!src_column->ti->hooks.move_ctor && src_column->ti->hooks.ctor_move_dtor);
+            flecs_table_invoke_remove_hooks(world, src_table,
+                src_column, &entity, src_index, 1, dtor);
+        }
+
+        i_new += dst_id <= src_id;
+        i_old += dst_id >= src_id;
+    }
+
+    for (; (i_new < dst_column_count); i_new ++) {
+        flecs_table_invoke_add_hooks(world, dst_table, i_new,
+            &entity, dst_index, 1, false);
+    }
+
+    for (; (i_old < src_column_count); i_old ++) {
+        ecs_column_t *src_column = &src_columns[i_old];
+        bool dtor = use_move_dtor || (!src_column->ti->hooks.move_ctor && src_column->ti->hooks.ctor_move_dtor);
+        flecs_table_invoke_remove_hooks(world, src_table, src_column,
+            &entity, src_index, 1, dtor);
+    }
+}
+
+/* Like flecs_add_ids but adds the new components WITHOUT constructor, so the
+ * entity moves to the table with all ids in a single transition. Mirrors
+ * flecs_commit/flecs_move_entity, using EcsWildcard as emplace id so sparse
+ * components aren't constructed either. Returns false without adding anything
+ * if the world is deferred. */
+bool ecs_rust_add_ids_emplace(
+    ecs_world_t *world,
+    ecs_entity_t entity,
+    const ecs_id_t *ids,
+    int32_t count)
+{
+    ecs_check(world != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(ecs_is_alive(world, entity), ECS_INVALID_PARAMETER, NULL);
+
+    ecs_stage_t *stage = flecs_stage_from_world(&world);
+    if (flecs_defer_cmd(stage)) {
+        /* Caller falls back to setting the components one by one, which get
+         * batched when the commands are flushed. */
+        return false;
+    }
+
+    ecs_record_t *r = flecs_entities_get(world, entity);
+    ecs_table_t *src_table = r->table;
+    ecs_assert(src_table != NULL, ECS_INTERNAL_ERROR, NULL);
+
+    ecs_table_diff_builder_t diff = ECS_TABLE_DIFF_INIT;
+    flecs_table_diff_builder_init(world, &diff);
+
+    ecs_table_t *dst_table = src_table;
+    int32_t i;
+    for (i = 0; i < count; i ++) {
+        ecs_id_t id = ids[i];
+        ecs_assert(id != ecs_id(EcsParent), ECS_INVALID_PARAMETER,
+            "Parent must be constructed, add it with ecs_add_id");
+
+        /* Nothing to move for non-fragmenting components, the caller sets
+         * them afterwards. */
+        ecs_component_record_t *cr = flecs_components_get(world, id);
+        if (cr && cr->flags & EcsIdDontFragment) {
+            continue;
+        }
+
+        dst_table = flecs_find_table_add(world, dst_table, id, &diff);
+    }
+
+    if (dst_table != src_table) {
+        ecs_table_diff_t table_diff;
+        flecs_table_diff_build_noalloc(&diff, &table_diff);
+
+        flecs_journal_begin(world, EcsJournalMove, entity,
+            &table_diff.added, &table_diff.removed);
+
+        int is_trav = (r->row & EcsEntityIsTraversable) != 0;
+        int32_t src_row = ECS_RECORD_TO_ROW(r->row);
+        flecs_table_traversable_add(dst_table, is_trav);
+
+        int32_t dst_row = ecs_table_count(dst_table);
+        flecs_table_append(world, dst_table, entity, false, false);
+        flecs_actions_move_remove(
+            world, src_table, dst_table, src_row, 1, &table_diff);
+        flecs_rust_table_move_emplace(
+            world, entity, dst_table, dst_row, src_table, src_row);
+        r->table = dst_table;
+        r->row = ECS_ROW_TO_RECORD(dst_row, r->row & ECS_ROW_FLAGS_MASK);
+        flecs_table_delete(world, src_table, src_row, false);
+        flecs_actions_move_add(world, dst_table, src_table, dst_row, 1,
+            &table_diff, EcsEventNoOnSet, true, EcsWildcard, true);
+
+        flecs_table_traversable_add(src_table, -is_trav);
+        if (is_trav) {
+            flecs_update_component_monitors(
+                world, &table_diff.added, &table_diff.removed);
+        }
+
+        flecs_journal_end();
+    }
+
+    flecs_table_diff_builder_fini(world, &diff);
+    flecs_defer_end(world, stage);
+
+    return true;
+error:
+    return false;
+}
+
 ecs_get_ptr_t ecs_rust_get_sparse_id(
     const ecs_world_t *world,
     ecs_entity_t entity,
@@ -381,3 +541,467 @@ size_t ecs_rust_sizeof_ecs_stack_t(void) {
 size_t ecs_rust_sizeof_ecs_stack_cursor_t(void) {
     return sizeof(ecs_stack_cursor_t);
 }
+
+#ifdef FLECS_REST
+
+static ecs_rust_rest_route_action_t flecs_rust_rest_route_action;
+
+/* Tries the routes of the application before the builtin REST endpoints. */
+static
+bool flecs_rust_rest_reply(
+    const ecs_http_request_t* req,
+    ecs_http_reply_t *reply,
+    void *ctx)
+{
+    ecs_rest_ctx_t *impl = ctx;
+    if (flecs_rust_rest_route_action &&
+        flecs_rust_rest_route_action(impl->world, req, reply))
+    {
+        return true;
+    }
+
+    if (flecs_rest_reply(req, reply, ctx)) {
+        return true;
+    }
+
+    reply->code = 404;
+    return false;
+}
+
+static
+void flecs_rust_rest_on_set(ecs_iter_t *it) {
+    EcsRest *rest = ecs_field(it, EcsRest, 0);
+
+    int32_t i;
+    for (i = 0; i < it->count; i ++) {
+        if (rest[i].impl) {
+            rest[i].impl->srv->callback = flecs_rust_rest_reply;
+        }
+    }
+}
+
+void ecs_rust_rest_enable_routes(
+    ecs_world_t *world,
+    ecs_rust_rest_route_action_t action)
+{
+    flecs_rust_rest_route_action = action;
+
+    if (ecs_lookup(world, "flecs.rest.RustRoutes")) {
+        return;
+    }
+
+    /* Servers that already exist */
+    ecs_iter_t it = ecs_each_id(world, ecs_id(EcsRest));
+    while (ecs_each_next(&it)) {
+        flecs_rust_rest_on_set(&it);
+    }
+
+    /* Servers created later. Observers run after the on_set hook that creates
+     * the server. */
+    ecs_observer(world, {
+        .entity = ecs_entity(world, { .name = "flecs.rest.RustRoutes" }),
+        .query.terms = {{ .id = ecs_id(EcsRest) }},
+        .events = { EcsOnSet },
+        .callback = flecs_rust_rest_on_set
+    });
+}
+
+int ecs_rust_rest_request(
+    ecs_world_t *world,
+    const char *method,
+    const char *path,
+    const char *body,
+    ecs_http_reply_t *reply_out)
+{
+    ecs_check(world != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(reply_out != NULL, ECS_INVALID_PARAMETER, NULL);
+
+    *reply_out = ECS_HTTP_REPLY_INIT;
+
+    /* The server is never started, so no socket is opened. */
+    ecs_http_server_t *srv = ecs_rest_server_init(world, NULL);
+    if (!srv) {
+        reply_out->code = 500;
+        return -1;
+    }
+
+    srv->callback = flecs_rust_rest_reply;
+
+    int result = ecs_http_server_request(srv, method, path, body, reply_out);
+    ecs_rest_server_fini(srv);
+    return result;
+error:
+    return -1;
+}
+
+#endif
+
+#ifdef FLECS_PIPELINE
+
+/* Collects the ids of the terms that require a merge, checking them in the same
+ * order as flecs_pipeline_check_terms. */
+static
+bool flecs_rust_pipeline_check_terms(
+    ecs_world_t *world,
+    ecs_query_t *query,
+    bool is_active,
+    ecs_write_state_t *ws,
+    ecs_vec_t *conflicts)
+{
+    ecs_allocator_t *a = &world->allocator;
+    bool needs_merge = false;
+    int32_t t, i, pass, term_count = query->term_count;
+
+    for (pass = 0; pass < 2; pass ++) {
+        for (t = 0; t < term_count; t ++) {
+            ecs_term_t *term = &query->terms[t];
+            if (ecs_term_match_this(term) != (pass == 0)) {
+                continue;
+            }
+
+            if (!flecs_pipeline_check_term(world, term, is_active, ws)) {
+                continue;
+            }
+
+            needs_merge = true;
+
+            ecs_id_t *ids = ecs_vec_first_t(conflicts, ecs_id_t);
+            int32_t count = ecs_vec_count(conflicts);
+            for (i = 0; i < count; i ++) {
+                if (ids[i] == term->id) {
+                    break;
+                }
+            }
+            if (i == count) {
+                ecs_vec_append_t(a, conflicts, ecs_id_t)[0] = term->id;
+            }
+        }
+    }
+
+    return needs_merge;
+}
+
+static
+void flecs_rust_append_ids(
+    ecs_allocator_t *a,
+    ecs_vec_t *dst,
+    const ecs_vec_t *src)
+{
+    int32_t i, count = ecs_vec_count(src);
+    const ecs_id_t *ids = ecs_vec_first_t(src, ecs_id_t);
+    for (i = 0; i < count; i ++) {
+        ecs_vec_append_t(a, dst, ecs_id_t)[0] = ids[i];
+    }
+}
+
+int ecs_rust_pipeline_schedule(
+    ecs_world_t *world,
+    ecs_entity_t pipeline,
+    ecs_rust_pipeline_op_t **ops_out,
+    int32_t *op_count_out,
+    ecs_entity_t **systems_out,
+    int32_t *system_count_out,
+    ecs_id_t **sync_ids_out,
+    int32_t *sync_id_count_out)
+{
+    ecs_check(world != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(ops_out != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(systems_out != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(sync_ids_out != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(!(ecs_world_get_flags(world) & 
+        (EcsWorldFrameInProgress | EcsWorldReadonly)), ECS_INVALID_OPERATION,
+        "cannot build a pipeline schedule while a frame is in progress");
+
+    *ops_out = NULL;
+    *op_count_out = 0;
+    *systems_out = NULL;
+    *system_count_out = 0;
+    *sync_ids_out = NULL;
+    *sync_id_count_out = 0;
+
+    world = ECS_CONST_CAST(ecs_world_t*, ecs_get_world(world));
+
+    const EcsPipeline *p = ecs_get(world, pipeline, EcsPipeline);
+    if (!p) {
+        return -1;
+    }
+
+    /* Same update as at the start of a frame, so the schedule matches what the
+     * next call to ecs_progress runs. */
+    ecs_pipeline_state_t *pq = p->state;
+    flecs_pipeline_update(world, pq, true);
+
+    /* Replay the merges of flecs_pipeline_build, and record why each merge is
+     * inserted. */
+    ecs_allocator_t *a = &world->allocator;
+    ecs_vec_t ops, systems, sync_ids, conflicts, pending_ids;
+    ecs_vec_init_t(a, &ops, ecs_rust_pipeline_op_t, 0);
+    ecs_vec_init_t(a, &systems, ecs_system_t*, 0);
+    ecs_vec_init_t(a, &sync_ids, ecs_id_t, 0);
+    ecs_vec_init_t(a, &conflicts, ecs_id_t, 0);
+    ecs_vec_init_t(a, &pending_ids, ecs_id_t, 0);
+
+    ecs_write_state_t ws = {0};
+    ecs_map_init(&ws.ids, a);
+    ecs_map_init(&ws.wildcard_ids, a);
+
+    ecs_rust_pipeline_op_t *op = NULL;
+    ecs_rust_sync_reason_t pending = EcsRustSyncNone;
+    bool multi_threaded = false;
+    bool immediate = false;
+    bool first = true;
+
+    ecs_iter_t it = ecs_query_iter(world, pq->query);
+    while (ecs_query_next(&it)) {
+        EcsPoly *poly = flecs_pipeline_term_system(&it);
+        bool is_active = ecs_table_get_type_index(
+            world, it.table, EcsEmpty) == -1;
+
+        int32_t i;
+        for (i = 0; i < it.count; i ++) {
+            ecs_system_t *sys = (ecs_system_t*)poly[i].poly;
+            ecs_query_t *q = sys->query;
+            ecs_rust_sync_reason_t reason = EcsRustSyncNone;
+
+            ecs_vec_clear(&conflicts);
+            bool needs_merge = flecs_rust_pipeline_check_terms(
+                world, q, is_active, &ws, &conflicts);
+            if (needs_merge) {
+                reason = EcsRustSyncStaged;
+            }
+
+            if (is_active) {
+                if (first) {
+                    multi_threaded = sys->multi_threaded;
+                    immediate = sys->immediate;
+                    first = false;
+                }
+
+                if (sys->multi_threaded != multi_threaded) {
+                    needs_merge = true;
+                    multi_threaded = sys->multi_threaded;
+                    if (!reason) {
+                        reason = EcsRustSyncMultiThreaded;
+                    }
+                }
+                if (sys->immediate != immediate) {
+                    needs_merge = true;
+                    immediate = sys->immediate;
+                    if (!reason) {
+                        reason = EcsRustSyncImmediate;
+                    }
+                }
+            }
+
+            if (immediate) {
+                needs_merge = true;
+                if (!reason) {
+                    reason = EcsRustSyncImmediate;
+                }
+            }
+
+            if (needs_merge) {
+                flecs_pipeline_reset_write_state(&ws);
+                if (op && op->count) {
+                    op = NULL;
+                }
+
+                /* The merge that runs before the next operation is the last
+                 * one that was inserted. */
+                pending = reason;
+                ecs_vec_clear(&pending_ids);
+                if (reason == EcsRustSyncStaged) {
+                    flecs_rust_append_ids(a, &pending_ids, &conflicts);
+                }
+
+                if (is_active) {
+                    ecs_vec_clear(&conflicts);
+                    flecs_rust_pipeline_check_terms(
+                        world, q, true, &ws, &conflicts);
+                }
+            }
+
+            if (!op) {
+                op = ecs_vec_append_t(a, &ops, ecs_rust_pipeline_op_t);
+                *op = (ecs_rust_pipeline_op_t){
+                    .offset = ecs_vec_count(&systems)
+                };
+            }
+
+            if (is_active) {
+                ecs_vec_append_t(a, &systems, ecs_system_t*)[0] = sys;
+                if (!op->count) {
+                    op->multi_threaded = multi_threaded;
+                    op->immediate = immediate;
+                    if (ecs_vec_count(&ops) > 1) {
+                        op->sync_reason = pending;
+                        op->sync_id_offset = ecs_vec_count(&sync_ids);
+                        op->sync_id_count = ecs_vec_count(&pending_ids);
+                        flecs_rust_append_ids(a, &sync_ids, &pending_ids);
+                    }
+                }
+                op->count ++;
+            }
+        }
+    }
+
+    if (op && !op->count && ecs_vec_count(&ops) > 1) {
+        ecs_vec_remove_last(&ops);
+    }
+
+    /* The operations and systems are read from the pipeline, the replay above
+     * only provides the reasons of the merges. Check that it found the same
+     * merges as flecs_pipeline_build, so the reasons belong to the operations
+     * they are reported for. */
+    int32_t op_count = ecs_vec_count(&pq->ops);
+    int32_t system_count = ecs_vec_count(&pq->systems);
+    int32_t sync_id_count = ecs_vec_count(&sync_ids);
+    ecs_pipeline_op_t *pipeline_ops = ecs_vec_first_t(
+        &pq->ops, ecs_pipeline_op_t);
+    ecs_rust_pipeline_op_t *replayed_ops = ecs_vec_first_t(
+        &ops, ecs_rust_pipeline_op_t);
+    int result = 0;
+
+    if (op_count != ecs_vec_count(&ops) ||
+        system_count != ecs_vec_count(&systems) ||
+        (system_count && ecs_os_memcmp(ecs_vec_first(&pq->systems),
+            ecs_vec_first(&systems), ECS_SIZEOF(ecs_system_t*) * system_count)))
+    {
+        result = -2;
+    }
+
+    int32_t i;
+    for (i = 0; !result && i < op_count; i ++) {
+        if (pipeline_ops[i].offset != replayed_ops[i].offset ||
+            pipeline_ops[i].count != replayed_ops[i].count)
+        {
+            result = -2;
+        }
+    }
+
+    if (!result) {
+        for (i = 0; i < op_count; i ++) {
+            replayed_ops[i].multi_threaded = pipeline_ops[i].multi_threaded;
+            replayed_ops[i].immediate = pipeline_ops[i].immediate;
+        }
+
+        if (op_count) {
+            *ops_out = ecs_os_malloc_n(ecs_rust_pipeline_op_t, op_count);
+            ecs_os_memcpy_n(*ops_out, replayed_ops,
+                ecs_rust_pipeline_op_t, op_count);
+        }
+        if (system_count) {
+            ecs_system_t **pipeline_systems = ecs_vec_first_t(
+                &pq->systems, ecs_system_t*);
+            *systems_out = ecs_os_malloc_n(ecs_entity_t, system_count);
+            for (i = 0; i < system_count; i ++) {
+                (*systems_out)[i] = pipeline_systems[i]->query->entity;
+            }
+        }
+        if (sync_id_count) {
+            *sync_ids_out = ecs_os_malloc_n(ecs_id_t, sync_id_count);
+            ecs_os_memcpy_n(*sync_ids_out, ecs_vec_first(&sync_ids),
+                ecs_id_t, sync_id_count);
+        }
+
+        *op_count_out = op_count;
+        *system_count_out = system_count;
+        *sync_id_count_out = sync_id_count;
+    }
+
+    ecs_map_fini(&ws.ids);
+    ecs_map_fini(&ws.wildcard_ids);
+    ecs_vec_fini_t(a, &ops, ecs_rust_pipeline_op_t);
+    ecs_vec_fini_t(a, &systems, ecs_system_t*);
+    ecs_vec_fini_t(a, &sync_ids, ecs_id_t);
+    ecs_vec_fini_t(a, &conflicts, ecs_id_t);
+    ecs_vec_fini_t(a, &pending_ids, ecs_id_t);
+    return result;
+error:
+    return -1;
+}
+
+bool ecs_rust_progress(
+    ecs_world_t *world,
+    ecs_ftime_t user_delta_time,
+    ecs_rust_frame_action_t action,
+    void *ctx)
+{
+    ecs_ftime_t delta_time = ecs_frame_begin(world, user_delta_time);
+
+    /* Same as ecs_progress, with the action inserted before the pipeline */
+    if (world->info.frame_count_total == 0) {
+        flecs_run_startup_systems(world);
+    }
+
+    if (ecs_using_task_threads(world)) {
+        flecs_create_worker_threads(world);
+    }
+
+    if (action) {
+        action(world, delta_time, ctx);
+    }
+
+    const EcsPipeline *p = ecs_get(world, world->pipeline, EcsPipeline);
+    ecs_check(p != NULL, ECS_INVALID_OPERATION,
+        "pipeline entity is missing flecs.pipeline.Pipeline component");
+    flecs_workers_progress(world, p->state, delta_time);
+
+    ecs_frame_end(world);
+
+    if (ecs_using_task_threads(world)) {
+        flecs_join_worker_threads(world);
+    }
+
+    return !ECS_BIT_IS_SET(world->flags, EcsWorldQuit);
+error:
+    return false;
+}
+
+#endif
+
+#ifdef FLECS_SCRIPT
+
+/* Allocators owned by a root scope created with ecs_rust_script_vars_init. The
+ * stack is the first member, so it can be found from the stack of the scope. */
+typedef struct flecs_rust_script_vars_alloc_t {
+    ecs_stack_t stack;
+    ecs_allocator_t allocator;
+} flecs_rust_script_vars_alloc_t;
+
+ecs_script_vars_t* ecs_rust_script_vars_init(
+    ecs_world_t *world)
+{
+    ecs_check(world != NULL, ECS_INVALID_PARAMETER, NULL);
+
+    flecs_rust_script_vars_alloc_t *alloc =
+        ecs_os_calloc_t(flecs_rust_script_vars_alloc_t);
+    flecs_stack_init(&alloc->stack);
+    flecs_allocator_init(&alloc->allocator);
+
+    ecs_script_vars_t *result = flecs_script_vars_push(
+        NULL, &alloc->stack, &alloc->allocator);
+    result->world = ecs_get_world(world); /* Provided world can be stage */
+    return result;
+error:
+    return NULL;
+}
+
+void ecs_rust_script_vars_fini(
+    ecs_script_vars_t *vars)
+{
+    ecs_check(vars != NULL, ECS_INVALID_PARAMETER, NULL);
+    ecs_check(vars->parent == NULL, ECS_INVALID_PARAMETER,
+        "ecs_rust_script_vars_fini can only be called on the root scope");
+
+    flecs_rust_script_vars_alloc_t *alloc =
+        (flecs_rust_script_vars_alloc_t*)vars->stack;
+    ecs_script_vars_fini(vars);
+    flecs_allocator_fini(&alloc->allocator);
+    flecs_stack_fini(&alloc->stack);
+    ecs_os_free(alloc);
+error:
+    return;
+}
+
+#endif
@@ -32,6 +32,116 @@ ecs_rust_set_t ecs_rust_set(
     const void *new_ptr,
     size_t size);
 
+/* Adds ids to an entity in a single table move, without constructing the new
+ * components. Returns false without adding anything if the world is deferred. */
+FLECS_API
+bool ecs_rust_add_ids_emplace(
+    ecs_world_t *world,
+    ecs_entity_t entity,
+    const ecs_id_t *ids,
+    int32_t count);
+
+#ifdef FLECS_SCRIPT
+
+/* Same as ecs_script_vars_init, but the scope allocates from a stack and an
+ * allocator of its own instead of the ones of the stage, so root scopes can be
+ * freed in any order. Must be freed with ecs_rust_script_vars_fini. */
+FLECS_API
+ecs_script_vars_t* ecs_rust_script_vars_init(
+    ecs_world_t *world);
+
+/* Frees a root scope created with ecs_rust_script_vars_init. */
+FLECS_API
+void ecs_rust_script_vars_fini(
+    ecs_script_vars_t *vars);
+
+#endif
+
+#ifdef FLECS_REST
+
+/* Invoked for each REST request before the builtin endpoints. Returns true if
+ * the request was handled. */
+typedef bool (*ecs_rust_rest_route_action_t)(
+    ecs_world_t *world,
+    const ecs_http_request_t *req,
+    ecs_http_reply_t *reply);
+
+/* Routes the requests of all REST servers of the world through action first,
+ * including servers that are created later. */
+FLECS_API
+void ecs_rust_rest_enable_routes(
+    ecs_world_t *world,
+    ecs_rust_rest_route_action_t action);
+
+/* Handles a REST request in-process, without opening a socket. The reply must
+ * be freed by the caller. */
+FLECS_API
+int ecs_rust_rest_request(
+    ecs_world_t *world,
+    const char *method,
+    const char *path,
+    const char *body,
+    ecs_http_reply_t *reply_out);
+
+#endif
+
+#ifdef FLECS_PIPELINE
+
+/* Why the pipeline merges commands before an operation. */
+typedef enum ecs_rust_sync_reason_t {
+    EcsRustSyncNone,
+    EcsRustSyncStaged,        /* A system accesses ids that were written to the stage. */
+    EcsRustSyncMultiThreaded, /* A system switches between single and multithreaded. */
+    EcsRustSyncImmediate      /* A system runs in immediate mode, or leaves it. */
+} ecs_rust_sync_reason_t;
+
+/* A run of systems in a pipeline schedule. Commands are merged after each
+ * operation. For a staged merge, sync_id_offset and sync_id_count select the
+ * ids that caused it. */
+typedef struct ecs_rust_pipeline_op_t {
+    int32_t offset;
+    int32_t count;
+    bool multi_threaded;
+    bool immediate;
+    ecs_rust_sync_reason_t sync_reason;
+    int32_t sync_id_offset;
+    int32_t sync_id_count;
+} ecs_rust_pipeline_op_t;
+
+/* Builds the schedule of a pipeline, and copies its operations, the systems
+ * they run and the ids that caused the merges between them. The arrays must be
+ * freed by the caller with ecs_os_free. Must not be called while a frame is in
+ * progress. Returns -1 if the entity is not a pipeline, and -2 if the reasons
+ * of the merges could not be matched with the operations of the pipeline. */
+FLECS_API
+int ecs_rust_pipeline_schedule(
+    ecs_world_t *world,
+    ecs_entity_t pipeline,
+    ecs_rust_pipeline_op_t **ops_out,
+    int32_t *op_count_out,
+    ecs_entity_t **systems_out,
+    int32_t *system_count_out,
+    ecs_id_t **sync_ids_out,
+    int32_t *sync_id_count_out);
+
+/* Invoked by ecs_rust_progress once the frame has begun, before the pipeline
+ * runs. The world is not in readonly mode. */
+typedef void (*ecs_rust_frame_action_t)(
+    ecs_world_t *world,
+    ecs_ftime_t delta_time,
+    void *ctx);
+
+/* Same as ecs_progress, but invokes action after the startup systems of the
+ * first frame have run and before the pipeline runs. */
+FLECS_API
+bool ecs_rust_progress(
+    ecs_world_t *world,
+    ecs_ftime_t user_delta_time,
+    ecs_rust_frame_action_t action,
+    void *ctx);
+
+#endif
+
 /* Fast path for compile-time-known sparse / dont_fragment components without
  * the (OnInstantiate, Inherit) trait. Mirrors ecs_get_sparse_id() but returns
  * an ecs_get_ptr_t so lock-target info is available under